@@ -0,0 +1,310 @@
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+
+//
+// The search over the non-contiguous haystack, such as the rope chunks.
+//
+// The chunk that is not shorter than the needle is searched by the per-chunk
+// routines. The matches spanning chunk boundaries are searched in the small
+// window, that is the pending bytes before the chunk and the first
+// `needle.len() - 1` bytes of the chunk. The pending bytes are the last
+// `needle.len() - 1` bytes of the chunk, and the shorter chunks are carried
+// forward in them. When the pending bytes are twice of the needle length,
+// they are searched, and only the last `needle.len() - 1` bytes are kept.
+// So each byte is searched a few times at most, and the haystack is never
+// copied as a whole.
+//
+///
+/// A haystack that is a sequence of the chunks, such as the rope.
+///
+/// The search functions find the needle spanning chunk boundaries,
+/// and return the global offset in the concatenation of the chunks.
+///
+/// Examples
+///
+/// ```rust
+/// use naive_opt::{chunked_rsearch, chunked_search, ChunkedHaystack};
+/// use std::collections::VecDeque;
+///
+/// let haystack = ["abc3", "45a", "bc901", "abc"];
+/// assert_eq!(chunked_search(&haystack, "345"), Some(3));
+/// assert_eq!(chunked_search(&haystack, "5abc9"), Some(5));
+/// assert_eq!(chunked_rsearch(&haystack, "abc"), Some(12));
+///
+/// // the ring buffer has two halves.
+/// let mut deque: VecDeque<u8> = VecDeque::with_capacity(8);
+/// deque.extend(b"xxxxxab");
+/// deque.drain(..5);
+/// deque.extend(b"c345");
+/// assert_eq!(chunked_search(&deque, "abc"), Some(0));
+///
+/// // the rope implements the trait by its chunks.
+/// struct Rope(Vec<String>);
+/// impl ChunkedHaystack for Rope {
+///     fn chunk_count(&self) -> usize {
+///         self.0.len()
+///     }
+///     fn chunk(&self, idx: usize) -> &[u8] {
+///         self.0[idx].as_bytes()
+///     }
+/// }
+/// let rope = Rope(vec!["Hello, ".to_string(), "wor".to_string(), "ld!".to_string()]);
+/// assert_eq!(chunked_search(&rope, "world"), Some(7));
+/// ```
+///
+pub trait ChunkedHaystack {
+    ///
+    /// return the number of the chunks.
+    ///
+    fn chunk_count(&self) -> usize;
+    ///
+    /// return the chunk of the index, which is less than [ChunkedHaystack::chunk_count()].
+    ///
+    fn chunk(&self, idx: usize) -> &[u8];
+    ///
+    /// return the total length of the chunks.
+    ///
+    fn total_len(&self) -> usize {
+        (0..self.chunk_count()).map(|i| self.chunk(i).len()).sum()
+    }
+}
+
+impl<T: AsRef<[u8]>> ChunkedHaystack for [T] {
+    #[inline]
+    fn chunk_count(&self) -> usize {
+        self.len()
+    }
+    #[inline]
+    fn chunk(&self, idx: usize) -> &[u8] {
+        self[idx].as_ref()
+    }
+}
+
+impl<T: AsRef<[u8]>, const N: usize> ChunkedHaystack for [T; N] {
+    #[inline]
+    fn chunk_count(&self) -> usize {
+        N
+    }
+    #[inline]
+    fn chunk(&self, idx: usize) -> &[u8] {
+        self[idx].as_ref()
+    }
+}
+
+impl<T: AsRef<[u8]>> ChunkedHaystack for Vec<T> {
+    #[inline]
+    fn chunk_count(&self) -> usize {
+        self.len()
+    }
+    #[inline]
+    fn chunk(&self, idx: usize) -> &[u8] {
+        self[idx].as_ref()
+    }
+}
+
+impl ChunkedHaystack for VecDeque<u8> {
+    #[inline]
+    fn chunk_count(&self) -> usize {
+        2
+    }
+    #[inline]
+    fn chunk(&self, idx: usize) -> &[u8] {
+        let (front, back) = self.as_slices();
+        if idx == 0 {
+            front
+        } else {
+            back
+        }
+    }
+    #[inline]
+    fn total_len(&self) -> usize {
+        self.len()
+    }
+}
+
+#[inline]
+fn search_bytes(hay_bytes: &[u8], nee_bytes: &[u8], ignore_ascii_case: bool) -> Option<usize> {
+    if ignore_ascii_case {
+        crate::naive_opt_mc_bytes_iac(hay_bytes, nee_bytes)
+    } else {
+        crate::naive_opt_mc_bytes(hay_bytes, nee_bytes)
+    }
+}
+
+#[inline]
+fn rsearch_bytes(hay_bytes: &[u8], nee_bytes: &[u8], ignore_ascii_case: bool) -> Option<usize> {
+    if ignore_ascii_case {
+        crate::naive_opt_mc_rev_bytes_iac(hay_bytes, nee_bytes)
+    } else {
+        crate::naive_opt_mc_rev_bytes(hay_bytes, nee_bytes)
+    }
+}
+
+// the pending bytes, whose start indices are not searched yet, and their global offset.
+struct Pending {
+    bytes: VecDeque<u8>,
+    offset: usize,
+}
+
+fn search_chunks<H: ?Sized + ChunkedHaystack>(
+    haystack: &H,
+    nee_bytes: &[u8],
+    ignore_ascii_case: bool,
+) -> Option<usize> {
+    let nee_len = nee_bytes.len();
+    if nee_len == 0 {
+        return Some(0);
+    }
+    let keep_len = nee_len - 1;
+    let mut pending = Pending {
+        bytes: VecDeque::with_capacity(3 * nee_len),
+        offset: 0,
+    };
+    let mut window = Vec::with_capacity(3 * nee_len);
+    let mut offset = 0;
+    for idx in 0..haystack.chunk_count() {
+        let chunk = haystack.chunk(idx);
+        if chunk.len() < nee_len {
+            // carry the short chunk forward.
+            pending.bytes.extend(chunk);
+            if pending.bytes.len() >= 2 * nee_len {
+                let bytes = pending.bytes.make_contiguous();
+                if let Some(i) = search_bytes(bytes, nee_bytes, ignore_ascii_case) {
+                    return Some(pending.offset + i);
+                }
+                let drop_len = bytes.len() - keep_len;
+                pending.bytes.drain(..drop_len);
+                pending.offset += drop_len;
+            }
+        } else {
+            // the matches that start in the pending bytes.
+            if !pending.bytes.is_empty() {
+                window.clear();
+                window.extend(pending.bytes.iter());
+                window.extend_from_slice(&chunk[..keep_len]);
+                if let Some(i) = search_bytes(&window, nee_bytes, ignore_ascii_case) {
+                    return Some(pending.offset + i);
+                }
+            }
+            if let Some(i) = search_bytes(chunk, nee_bytes, ignore_ascii_case) {
+                return Some(offset + i);
+            }
+            pending.bytes.clear();
+            pending.bytes.extend(&chunk[chunk.len() - keep_len..]);
+            pending.offset = offset + chunk.len() - keep_len;
+        }
+        offset += chunk.len();
+    }
+    let bytes = pending.bytes.make_contiguous();
+    search_bytes(bytes, nee_bytes, ignore_ascii_case).map(|i| pending.offset + i)
+}
+
+// the reverse of `search_chunks()`. the pending bytes are the first bytes of
+// the chunk, whose end indices are not searched yet, and the offset is of the end.
+fn rsearch_chunks<H: ?Sized + ChunkedHaystack>(
+    haystack: &H,
+    nee_bytes: &[u8],
+    ignore_ascii_case: bool,
+) -> Option<usize> {
+    let nee_len = nee_bytes.len();
+    let mut offset = haystack.total_len();
+    if nee_len == 0 {
+        return Some(offset);
+    }
+    let keep_len = nee_len - 1;
+    let mut pending = Pending {
+        bytes: VecDeque::with_capacity(3 * nee_len),
+        offset,
+    };
+    let mut window = Vec::with_capacity(3 * nee_len);
+    for idx in (0..haystack.chunk_count()).rev() {
+        let chunk = haystack.chunk(idx);
+        offset -= chunk.len();
+        if chunk.len() < nee_len {
+            // carry the short chunk backward.
+            for &byte in chunk.iter().rev() {
+                pending.bytes.push_front(byte);
+            }
+            if pending.bytes.len() >= 2 * nee_len {
+                let bytes = pending.bytes.make_contiguous();
+                let bytes_len = bytes.len();
+                if let Some(i) = rsearch_bytes(bytes, nee_bytes, ignore_ascii_case) {
+                    return Some(pending.offset - bytes_len + i);
+                }
+                pending.offset -= bytes_len - keep_len;
+                pending.bytes.truncate(keep_len);
+            }
+        } else {
+            // the matches that end in the pending bytes.
+            if !pending.bytes.is_empty() {
+                window.clear();
+                window.extend_from_slice(&chunk[chunk.len() - keep_len..]);
+                window.extend(pending.bytes.iter());
+                if let Some(i) = rsearch_bytes(&window, nee_bytes, ignore_ascii_case) {
+                    return Some(offset + chunk.len() - keep_len + i);
+                }
+            }
+            if let Some(i) = rsearch_bytes(chunk, nee_bytes, ignore_ascii_case) {
+                return Some(offset + i);
+            }
+            pending.bytes.clear();
+            pending.bytes.extend(&chunk[..keep_len]);
+            pending.offset = offset + keep_len;
+        }
+    }
+    let bytes = pending.bytes.make_contiguous();
+    let bytes_len = bytes.len();
+    rsearch_bytes(bytes, nee_bytes, ignore_ascii_case).map(|i| pending.offset - bytes_len + i)
+}
+
+///
+/// search the needle in the chunked haystack.
+///
+/// return the global offset of the haystack, if it found the needle. Otherwise return None.
+///
+pub fn chunked_search<H, B>(haystack: &H, needle: &B) -> Option<usize>
+where
+    H: ?Sized + ChunkedHaystack,
+    B: ?Sized + AsRef<[u8]>,
+{
+    search_chunks(haystack, needle.as_ref(), false)
+}
+
+///
+/// reverse search the needle in the chunked haystack.
+///
+/// return the global offset of the haystack, if it found the needle. Otherwise return None.
+///
+pub fn chunked_rsearch<H, B>(haystack: &H, needle: &B) -> Option<usize>
+where
+    H: ?Sized + ChunkedHaystack,
+    B: ?Sized + AsRef<[u8]>,
+{
+    rsearch_chunks(haystack, needle.as_ref(), false)
+}
+
+///
+/// search the needle in the chunked haystack, ignore ascii case.
+///
+/// return the global offset of the haystack, if it found the needle. Otherwise return None.
+///
+pub fn chunked_search_ignore_ascii_case<H, B>(haystack: &H, needle: &B) -> Option<usize>
+where
+    H: ?Sized + ChunkedHaystack,
+    B: ?Sized + AsRef<[u8]>,
+{
+    search_chunks(haystack, needle.as_ref(), true)
+}
+
+///
+/// reverse search the needle in the chunked haystack, ignore ascii case.
+///
+/// return the global offset of the haystack, if it found the needle. Otherwise return None.
+///
+pub fn chunked_rsearch_ignore_ascii_case<H, B>(haystack: &H, needle: &B) -> Option<usize>
+where
+    H: ?Sized + ChunkedHaystack,
+    B: ?Sized + AsRef<[u8]>,
+{
+    rsearch_chunks(haystack, needle.as_ref(), true)
+}
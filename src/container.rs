@@ -0,0 +1,114 @@
+//
+// The needles of the owned and the shared containers of `str` and `[u8]`.
+//
+// The haystacks are covered by the blanket impls of `Search` over `AsRef<str>`
+// and of `SearchBytes` over `AsRef<[u8]>`, but the blanket impls of the needle
+// would overlap with the impls of `char`, the set of chars and the predicate,
+// so that the needles are implemented for each container.
+//
+use crate::SearchInBytes;
+
+#[cfg(feature = "alloc")]
+use crate::SearchIn;
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::rc::Rc;
+#[cfg(feature = "alloc")]
+use alloc::sync::Arc;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+// the needle of bytes, the haystack is `$hay_bytes` of the type `$hay_ty`.
+macro_rules! impl_needle_bytes {
+    ($trait:ident, $hay_ty:ty, |$hay:ident| $hay_bytes:expr, |$nee:ident| $nee_bytes:expr,
+     $([$($gen:tt)*] $ty:ty),* $(,)?) => {
+        $(
+        impl<'a, $($gen)*> $trait<'a> for $ty {
+            #[inline]
+            fn search_in(&self, $hay: &'a $hay_ty) -> Option<usize> {
+                let $nee = self;
+                $crate::naive_opt_mc_bytes($hay_bytes, $nee_bytes)
+            }
+            #[inline]
+            fn rsearch_in(&self, $hay: &'a $hay_ty) -> Option<usize> {
+                let $nee = self;
+                $crate::naive_opt_mc_rev_bytes($hay_bytes, $nee_bytes)
+            }
+            #[inline]
+            fn search_in_ignore_ascii_case(&self, $hay: &'a $hay_ty) -> Option<usize> {
+                let $nee = self;
+                $crate::naive_opt_mc_bytes_iac($hay_bytes, $nee_bytes)
+            }
+            #[inline]
+            fn rsearch_in_ignore_ascii_case(&self, $hay: &'a $hay_ty) -> Option<usize> {
+                let $nee = self;
+                $crate::naive_opt_mc_rev_bytes_iac($hay_bytes, $nee_bytes)
+            }
+            #[inline]
+            fn count_in(&self, $hay: &'a $hay_ty) -> usize {
+                let $nee = self;
+                $crate::naive_opt_mc_count_bytes($hay_bytes, $nee_bytes, false)
+            }
+            #[inline]
+            fn count_in_overlapping(&self, $hay: &'a $hay_ty) -> usize {
+                let $nee = self;
+                $crate::naive_opt_mc_count_bytes($hay_bytes, $nee_bytes, true)
+            }
+            #[inline]
+            fn count_in_ignore_ascii_case(&self, $hay: &'a $hay_ty) -> usize {
+                let $nee = self;
+                $crate::naive_opt_mc_count_bytes_iac($hay_bytes, $nee_bytes, false)
+            }
+            #[inline]
+            fn count_in_overlapping_ignore_ascii_case(&self, $hay: &'a $hay_ty) -> usize {
+                let $nee = self;
+                $crate::naive_opt_mc_count_bytes_iac($hay_bytes, $nee_bytes, true)
+            }
+            #[inline]
+            fn max_match_len(&self) -> usize {
+                let $nee = self;
+                <[u8]>::len($nee_bytes)
+            }
+        }
+        )*
+    };
+}
+#[cfg(all(feature = "std", unix))]
+pub(crate) use impl_needle_bytes;
+
+#[cfg(feature = "alloc")]
+impl_needle_bytes! {
+    SearchIn, str, |haystack| haystack.as_bytes(), |needle| needle.as_bytes(),
+    [] &Box<str>,
+    [] &Rc<str>,
+    [] &Arc<str>,
+    [] &Cow<'_, str>,
+}
+
+#[cfg(feature = "alloc")]
+impl_needle_bytes! {
+    SearchInBytes, [u8], |haystack| haystack, |needle| needle.as_bytes(),
+    [] &Box<str>,
+    [] &Rc<str>,
+    [] &Arc<str>,
+    [] &Cow<'_, str>,
+}
+
+impl_needle_bytes! {
+    SearchInBytes, [u8], |haystack| haystack, |needle| &needle[..],
+    [const N: usize] [u8; N],
+    [const N: usize] &[u8; N],
+}
+
+#[cfg(feature = "alloc")]
+impl_needle_bytes! {
+    SearchInBytes, [u8], |haystack| haystack, |needle| &needle[..],
+    [] &Vec<u8>,
+    [] &Box<[u8]>,
+    [] &Rc<[u8]>,
+    [] &Arc<[u8]>,
+    [] &Cow<'_, [u8]>,
+}
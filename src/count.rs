@@ -0,0 +1,76 @@
+//
+// The counting by the repeated searches, for the needles that do not have
+// the own counting loop. The built-in needles count on the `memchr` loop
+// directly, see `naive_opt_mc_count_bytes()`.
+//
+// The empty needle counts 0, the same as the match iterators.
+//
+use crate::{SearchIn, SearchInBytes};
+
+pub(crate) fn count_in<'a, P: SearchIn<'a>>(
+    needle: &P,
+    haystack: &'a str,
+    overlapping: bool,
+    ignore_ascii_case: bool,
+) -> usize {
+    if needle.max_match_len() == 0 {
+        return 0;
+    }
+    let mut count = 0;
+    let mut curr_idx = 0;
+    loop {
+        let hay = &haystack[curr_idx..];
+        let r = if ignore_ascii_case {
+            needle.search_in_ignore_ascii_case(hay)
+        } else {
+            needle.search_in(hay)
+        };
+        let st = match r {
+            Some(idx) => curr_idx + idx,
+            None => break,
+        };
+        count += 1;
+        curr_idx = if overlapping {
+            let mut next_idx = st + 1;
+            while !haystack.is_char_boundary(next_idx) {
+                next_idx += 1;
+            }
+            next_idx
+        } else {
+            st + needle.match_len_at(haystack, st)
+        };
+    }
+    count
+}
+
+pub(crate) fn count_in_bytes<'a, P: SearchInBytes<'a>>(
+    needle: &P,
+    haystack: &'a [u8],
+    overlapping: bool,
+    ignore_ascii_case: bool,
+) -> usize {
+    if needle.max_match_len() == 0 {
+        return 0;
+    }
+    let mut count = 0;
+    let mut curr_idx = 0;
+    loop {
+        let hay = &haystack[curr_idx..];
+        let r = if ignore_ascii_case {
+            needle.search_in_ignore_ascii_case(hay)
+        } else {
+            needle.search_in(hay)
+        };
+        let st = match r {
+            Some(idx) => curr_idx + idx,
+            None => break,
+        };
+        count += 1;
+        curr_idx = if overlapping {
+            st + 1
+        } else {
+            st + needle.match_len_at(haystack, st)
+        };
+    }
+    count
+}
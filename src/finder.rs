@@ -0,0 +1,459 @@
+use crate::mc_generic::{pivot_rsearch, pivot_rsearch_iac, pivot_search, pivot_search_iac};
+
+//
+// The needle analysis shared by `Finder` and `FinderRev`.
+//
+// The pivot index and the pick bytes are decided once at construction,
+// so that the searching itself runs straight into the `memchr` loop.
+//
+#[derive(Debug, Clone, Copy)]
+struct Prepared<'n> {
+    needle: &'n [u8],
+    pivot: usize,
+    guard: Option<usize>,
+    // without ignore ascii case, both are the pick byte itself.
+    pick_byte_uc: u8,
+    pick_byte_lc: u8,
+    ignore_ascii_case: bool,
+}
+impl<'n> Prepared<'n> {
+    fn new(needle: &'n [u8], ignore_ascii_case: bool) -> Prepared<'n> {
+        let (pivot, guard, pick_byte) = if needle.is_empty() {
+            (0, None, 0)
+        } else {
+            let (pivot, guard) = crate::select_pivots(needle);
+            (pivot, guard, needle[pivot])
+        };
+        let (pick_byte_uc, pick_byte_lc) = if ignore_ascii_case {
+            (
+                pick_byte.to_ascii_uppercase(),
+                pick_byte.to_ascii_lowercase(),
+            )
+        } else {
+            (pick_byte, pick_byte)
+        };
+        Prepared {
+            needle,
+            pivot,
+            guard,
+            pick_byte_uc,
+            pick_byte_lc,
+            ignore_ascii_case,
+        }
+    }
+    #[inline]
+    fn find(&self, hay_bytes: &[u8]) -> Option<usize> {
+        if self.ignore_ascii_case {
+            pivot_search_iac(
+                hay_bytes,
+                self.needle,
+                self.pivot,
+                self.pick_byte_uc,
+                self.pick_byte_lc,
+                self.guard,
+            )
+        } else {
+            pivot_search(
+                hay_bytes,
+                self.needle,
+                self.pivot,
+                self.pick_byte_lc,
+                self.guard,
+            )
+        }
+    }
+    #[inline]
+    fn rfind(&self, hay_bytes: &[u8]) -> Option<usize> {
+        if self.ignore_ascii_case {
+            pivot_rsearch_iac(
+                hay_bytes,
+                self.needle,
+                self.pivot,
+                self.pick_byte_uc,
+                self.pick_byte_lc,
+                self.guard,
+            )
+        } else {
+            pivot_rsearch(
+                hay_bytes,
+                self.needle,
+                self.pivot,
+                self.pick_byte_lc,
+                self.guard,
+            )
+        }
+    }
+}
+
+///
+/// A precompiled searcher of the needle.
+///
+/// The search strategy, the pivot index and the pick bytes of the needle
+/// are decided once by [Finder::new()], and reused by every searching.
+/// This is useful when searching the same needle in many haystacks.
+///
+/// Because the needle is scanned only once, the pivot is the rarest byte
+/// of the whole needle by the stochastics, not only the 1st or the last byte.
+/// And the 2nd rarest byte is checked before the full comparison.
+///
+/// Examples
+///
+/// ```rust
+/// use naive_opt::Finder;
+///
+/// let finder = Finder::new("abc");
+/// assert_eq!(finder.find("abc345abc901abc"), Some(0));
+/// assert_eq!(finder.find_bytes(b"012abc"), Some(3));
+/// assert_eq!(finder.includes("xyz"), false);
+///
+/// let v: Vec<_> = finder.find_iter("abc345abc901abc").collect();
+/// assert_eq!(v, [(0, "abc"), (6, "abc"), (12, "abc")]);
+///
+/// let finder = Finder::new_ignore_ascii_case("abc");
+/// assert_eq!(finder.find("345aBc901"), Some(3));
+/// ```
+///
+#[derive(Debug, Clone, Copy)]
+pub struct Finder<'n> {
+    prepared: Prepared<'n>,
+}
+impl<'n> Finder<'n> {
+    ///
+    /// create a new searcher of the needle.
+    ///
+    pub fn new<B: ?Sized + AsRef<[u8]>>(needle: &'n B) -> Finder<'n> {
+        Finder {
+            prepared: Prepared::new(needle.as_ref(), false),
+        }
+    }
+    ///
+    /// create a new searcher of the needle, ignore ascii case.
+    ///
+    pub fn new_ignore_ascii_case<B: ?Sized + AsRef<[u8]>>(needle: &'n B) -> Finder<'n> {
+        Finder {
+            prepared: Prepared::new(needle.as_ref(), true),
+        }
+    }
+    ///
+    /// return the needle of self.
+    ///
+    #[inline]
+    pub fn needle(&self) -> &'n [u8] {
+        self.prepared.needle
+    }
+    ///
+    /// search the needle in the haystack.
+    ///
+    /// return index of the haystack, if it found the needle. Otherwise return None.
+    ///
+    #[inline]
+    pub fn find(&self, haystack: &str) -> Option<usize> {
+        self.prepared.find(haystack.as_bytes())
+    }
+    ///
+    /// search the needle in the haystack bytes.
+    ///
+    /// return index of the haystack, if it found the needle. Otherwise return None.
+    ///
+    #[inline]
+    pub fn find_bytes(&self, haystack: &[u8]) -> Option<usize> {
+        self.prepared.find(haystack)
+    }
+    ///
+    /// An iterator over the matches of the needle in the haystack.
+    ///
+    /// The matches of the bytes needle, that are not on the char boundaries, are skipped.
+    ///
+    #[inline]
+    pub fn find_iter<'h>(&self, haystack: &'h str) -> FinderIndices<'h, 'n> {
+        FinderIndices::new(haystack, self.prepared)
+    }
+    ///
+    /// An iterator over the matches of the needle in the haystack bytes.
+    ///
+    #[inline]
+    pub fn find_iter_bytes<'h>(&self, haystack: &'h [u8]) -> FinderIndicesBytes<'h, 'n> {
+        FinderIndicesBytes::new(haystack, self.prepared)
+    }
+    ///
+    /// includes the needle in the haystack.
+    ///
+    /// returns true if the needle matches a sub-slice of the haystack.
+    /// returns false if it does not.
+    ///
+    #[inline]
+    pub fn includes(&self, haystack: &str) -> bool {
+        self.find(haystack).is_some()
+    }
+    ///
+    /// includes the needle in the haystack bytes.
+    ///
+    /// returns true if the needle matches a sub-slice of the haystack.
+    /// returns false if it does not.
+    ///
+    #[inline]
+    pub fn includes_bytes(&self, haystack: &[u8]) -> bool {
+        self.find_bytes(haystack).is_some()
+    }
+}
+
+///
+/// A precompiled reverse searcher of the needle.
+///
+/// Examples
+///
+/// ```rust
+/// use naive_opt::FinderRev;
+///
+/// let finder = FinderRev::new("abc");
+/// assert_eq!(finder.rfind("abc345abc901abc"), Some(12));
+/// assert_eq!(finder.rfind_bytes(b"abc012"), Some(0));
+///
+/// let v: Vec<_> = finder.rfind_iter("abc345abc901abc").collect();
+/// assert_eq!(v, [(12, "abc"), (6, "abc"), (0, "abc")]);
+///
+/// let finder = FinderRev::new_ignore_ascii_case("abc");
+/// assert_eq!(finder.rfind("aBc345ABC901"), Some(6));
+/// ```
+///
+#[derive(Debug, Clone, Copy)]
+pub struct FinderRev<'n> {
+    prepared: Prepared<'n>,
+}
+impl<'n> FinderRev<'n> {
+    ///
+    /// create a new reverse searcher of the needle.
+    ///
+    pub fn new<B: ?Sized + AsRef<[u8]>>(needle: &'n B) -> FinderRev<'n> {
+        FinderRev {
+            prepared: Prepared::new(needle.as_ref(), false),
+        }
+    }
+    ///
+    /// create a new reverse searcher of the needle, ignore ascii case.
+    ///
+    pub fn new_ignore_ascii_case<B: ?Sized + AsRef<[u8]>>(needle: &'n B) -> FinderRev<'n> {
+        FinderRev {
+            prepared: Prepared::new(needle.as_ref(), true),
+        }
+    }
+    ///
+    /// return the needle of self.
+    ///
+    #[inline]
+    pub fn needle(&self) -> &'n [u8] {
+        self.prepared.needle
+    }
+    ///
+    /// reverse search the needle in the haystack.
+    ///
+    /// return index of the haystack, if it found the needle. Otherwise return None.
+    ///
+    #[inline]
+    pub fn rfind(&self, haystack: &str) -> Option<usize> {
+        self.prepared.rfind(haystack.as_bytes())
+    }
+    ///
+    /// reverse search the needle in the haystack bytes.
+    ///
+    /// return index of the haystack, if it found the needle. Otherwise return None.
+    ///
+    #[inline]
+    pub fn rfind_bytes(&self, haystack: &[u8]) -> Option<usize> {
+        self.prepared.rfind(haystack)
+    }
+    ///
+    /// An reverse search iterator over the matches of the needle in the haystack.
+    ///
+    /// The matches of the bytes needle, that are not on the char boundaries, are skipped.
+    ///
+    #[inline]
+    pub fn rfind_iter<'h>(&self, haystack: &'h str) -> FinderRevIndices<'h, 'n> {
+        FinderRevIndices::new(haystack, self.prepared)
+    }
+    ///
+    /// An reverse search iterator over the matches of the needle in the haystack bytes.
+    ///
+    #[inline]
+    pub fn rfind_iter_bytes<'h>(&self, haystack: &'h [u8]) -> FinderRevIndicesBytes<'h, 'n> {
+        FinderRevIndicesBytes::new(haystack, self.prepared)
+    }
+    ///
+    /// includes the needle in the haystack.
+    ///
+    /// returns true if the needle matches a sub-slice of the haystack.
+    /// returns false if it does not.
+    ///
+    #[inline]
+    pub fn includes(&self, haystack: &str) -> bool {
+        self.rfind(haystack).is_some()
+    }
+    ///
+    /// includes the needle in the haystack bytes.
+    ///
+    /// returns true if the needle matches a sub-slice of the haystack.
+    /// returns false if it does not.
+    ///
+    #[inline]
+    pub fn includes_bytes(&self, haystack: &[u8]) -> bool {
+        self.rfind_bytes(haystack).is_some()
+    }
+}
+
+///
+/// Created with the method [Finder::find_iter()].
+///
+#[derive(Debug, Clone)]
+pub struct FinderIndices<'h, 'n> {
+    curr_idx: usize,
+    haystack: &'h str,
+    prepared: Prepared<'n>,
+}
+impl<'h, 'n> FinderIndices<'h, 'n> {
+    fn new(a_haystack: &'h str, a_prepared: Prepared<'n>) -> FinderIndices<'h, 'n> {
+        FinderIndices {
+            curr_idx: 0,
+            haystack: a_haystack,
+            prepared: a_prepared,
+        }
+    }
+}
+impl<'h> Iterator for FinderIndices<'h, '_> {
+    type Item = (usize, &'h str);
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.prepared.needle.is_empty() {
+            return None;
+        }
+        while let Some(idx) = self
+            .prepared
+            .find(&self.haystack.as_bytes()[self.curr_idx..])
+        {
+            let st = self.curr_idx + idx;
+            let ed = st + self.prepared.needle.len();
+            // the bytes needle can match in the middle of a char, it is skipped.
+            if self.haystack.is_char_boundary(st) && self.haystack.is_char_boundary(ed) {
+                self.curr_idx = ed;
+                return Some((st, &self.haystack[st..ed]));
+            }
+            self.curr_idx = st + 1;
+        }
+        None
+    }
+}
+
+///
+/// Created with the method [Finder::find_iter_bytes()].
+///
+#[derive(Debug, Clone)]
+pub struct FinderIndicesBytes<'h, 'n> {
+    curr_idx: usize,
+    haystack: &'h [u8],
+    prepared: Prepared<'n>,
+}
+impl<'h, 'n> FinderIndicesBytes<'h, 'n> {
+    fn new(a_haystack: &'h [u8], a_prepared: Prepared<'n>) -> FinderIndicesBytes<'h, 'n> {
+        FinderIndicesBytes {
+            curr_idx: 0,
+            haystack: a_haystack,
+            prepared: a_prepared,
+        }
+    }
+}
+impl<'h> Iterator for FinderIndicesBytes<'h, '_> {
+    type Item = (usize, &'h [u8]);
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.prepared.needle.is_empty() {
+            return None;
+        }
+        match self.prepared.find(&self.haystack[self.curr_idx..]) {
+            Some(idx) => {
+                let st = self.curr_idx + idx;
+                let ed = st + self.prepared.needle.len();
+                self.curr_idx = ed;
+                Some((st, &self.haystack[st..ed]))
+            }
+            None => None,
+        }
+    }
+}
+
+///
+/// Created with the method [FinderRev::rfind_iter()].
+///
+#[derive(Debug, Clone)]
+pub struct FinderRevIndices<'h, 'n> {
+    curr_ed: usize,
+    haystack: &'h str,
+    prepared: Prepared<'n>,
+}
+impl<'h, 'n> FinderRevIndices<'h, 'n> {
+    fn new(a_haystack: &'h str, a_prepared: Prepared<'n>) -> FinderRevIndices<'h, 'n> {
+        FinderRevIndices {
+            curr_ed: a_haystack.len(),
+            haystack: a_haystack,
+            prepared: a_prepared,
+        }
+    }
+}
+impl<'h> Iterator for FinderRevIndices<'h, '_> {
+    type Item = (usize, &'h str);
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.prepared.needle.is_empty() {
+            return None;
+        }
+        while let Some(idx) = self
+            .prepared
+            .rfind(&self.haystack.as_bytes()[..self.curr_ed])
+        {
+            let st = idx;
+            let ed = st + self.prepared.needle.len();
+            // the bytes needle can match in the middle of a char, it is skipped.
+            if self.haystack.is_char_boundary(st) && self.haystack.is_char_boundary(ed) {
+                self.curr_ed = st;
+                return Some((st, &self.haystack[st..ed]));
+            }
+            self.curr_ed = ed - 1;
+        }
+        None
+    }
+}
+
+///
+/// Created with the method [FinderRev::rfind_iter_bytes()].
+///
+#[derive(Debug, Clone)]
+pub struct FinderRevIndicesBytes<'h, 'n> {
+    curr_ed: usize,
+    haystack: &'h [u8],
+    prepared: Prepared<'n>,
+}
+impl<'h, 'n> FinderRevIndicesBytes<'h, 'n> {
+    fn new(a_haystack: &'h [u8], a_prepared: Prepared<'n>) -> FinderRevIndicesBytes<'h, 'n> {
+        FinderRevIndicesBytes {
+            curr_ed: a_haystack.len(),
+            haystack: a_haystack,
+            prepared: a_prepared,
+        }
+    }
+}
+impl<'h> Iterator for FinderRevIndicesBytes<'h, '_> {
+    type Item = (usize, &'h [u8]);
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.prepared.needle.is_empty() {
+            return None;
+        }
+        match self.prepared.rfind(&self.haystack[..self.curr_ed]) {
+            Some(idx) => {
+                let st = idx;
+                let ed = st + self.prepared.needle.len();
+                self.curr_ed = st;
+                Some((st, &self.haystack[st..ed]))
+            }
+            None => None,
+        }
+    }
+}
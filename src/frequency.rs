@@ -0,0 +1,188 @@
+use crate::mc_generic::rarest_pair_by;
+use crate::mc_generic::{pivot_rsearch, pivot_rsearch_iac, pivot_search, pivot_search_iac};
+
+///
+/// A byte frequency table, which decides the pivot byte and the guard byte of the needle.
+///
+/// The weight of each byte value is 0..=255, the lower is the rarer.
+/// The built-in table is [ByteFrequency::BUILTIN], which is used by
+/// the default dispatcher.
+///
+/// Examples
+///
+/// ```rust
+/// use naive_opt::{ByteFrequency, Search};
+///
+/// // build from a sample corpus
+/// let freq = ByteFrequency::from_corpus("ああいうえおあいう".as_bytes());
+/// assert_eq!("かきくけこあいうえお".search_with_frequency("いう", &freq), Some(18));
+///
+/// // serialize as a const array, and restore it
+/// const TABLE: [u8; 256] = ByteFrequency::BUILTIN.to_table();
+/// const FREQ: ByteFrequency = ByteFrequency::from_table(TABLE);
+/// assert_eq!("abc345abc".search_with_frequency("345", &FREQ), Some(3));
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ByteFrequency {
+    table: [u8; 256],
+}
+
+impl ByteFrequency {
+    ///
+    /// The built-in table.
+    ///
+    pub const BUILTIN: ByteFrequency = ByteFrequency::from_table(crate::_BYTE_STOCHAS);
+    ///
+    /// create from the weight table.
+    ///
+    pub const fn from_table(table: [u8; 256]) -> ByteFrequency {
+        ByteFrequency { table }
+    }
+    ///
+    /// return the weight table, to be serialized as a const array.
+    ///
+    pub const fn to_table(&self) -> [u8; 256] {
+        self.table
+    }
+    ///
+    /// create from the byte counts.
+    ///
+    /// The counts are scaled to 0..=255, so that the most frequent byte
+    /// has the weight 255 and the byte that appeared at least once has
+    /// the weight 1 or more.
+    ///
+    pub fn from_counts(counts: &[u64; 256]) -> ByteFrequency {
+        let max = counts.iter().copied().max().unwrap_or(0) as u128;
+        let mut table = [0u8; 256];
+        for (w, &c) in table.iter_mut().zip(counts.iter()) {
+            // round up, and zero if all counts are zero.
+            let scaled = (c as u128 * 255 + max.saturating_sub(1)).checked_div(max);
+            *w = scaled.unwrap_or(0) as u8;
+        }
+        ByteFrequency { table }
+    }
+    ///
+    /// create from the sample corpus.
+    ///
+    pub fn from_corpus(corpus: &[u8]) -> ByteFrequency {
+        let mut counts = [0u64; 256];
+        for &byte in corpus {
+            counts[byte as usize] += 1;
+        }
+        Self::from_counts(&counts)
+    }
+    ///
+    /// return the weight of the byte. The lower is the rarer.
+    ///
+    #[inline]
+    pub const fn weight(&self, byte: u8) -> u8 {
+        self.table[byte as usize]
+    }
+    ///
+    /// return the index of the pivot byte and the guard byte of the needle,
+    /// or None if the needle is empty.
+    ///
+    /// The pivot byte is scanned first, and the guard byte is checked before
+    /// the full comparison. The needle of 3 bytes or more has the two rarest
+    /// bytes by the weight, otherwise the rarer of the 1st byte and the last byte
+    /// and no guard byte.
+    ///
+    /// Examples
+    ///
+    /// ```rust
+    /// use naive_opt::ByteFrequency;
+    ///
+    /// let freq = ByteFrequency::from_corpus(b"aaaabbc");
+    /// assert_eq!(freq.pivots(b"abcd"), Some((3, Some(2))));
+    /// assert_eq!(freq.pivots(b"ab"), Some((1, None)));
+    /// assert_eq!(freq.pivots(b""), None);
+    /// ```
+    ///
+    #[inline]
+    pub fn pivots(&self, needle: &[u8]) -> Option<(usize, Option<usize>)> {
+        if needle.len() >= crate::DUAL_PIVOT_MIN_LEN {
+            return Some(rarest_pair_by(needle, |byte| self.weight(byte)));
+        }
+        let last = needle.len().checked_sub(1)?;
+        if self.weight(needle[0]) <= self.weight(needle[last]) {
+            Some((0, None))
+        } else {
+            Some((last, None))
+        }
+    }
+}
+
+impl Default for ByteFrequency {
+    fn default() -> ByteFrequency {
+        ByteFrequency::BUILTIN
+    }
+}
+
+#[inline]
+pub(crate) fn search_with_frequency(
+    hay_bytes: &[u8],
+    nee_bytes: &[u8],
+    freq: &ByteFrequency,
+) -> Option<usize> {
+    let (pivot, guard) = match freq.pivots(nee_bytes) {
+        Some(pivots) => pivots,
+        None => return Some(0),
+    };
+    pivot_search(hay_bytes, nee_bytes, pivot, nee_bytes[pivot], guard)
+}
+
+#[inline]
+pub(crate) fn rsearch_with_frequency(
+    hay_bytes: &[u8],
+    nee_bytes: &[u8],
+    freq: &ByteFrequency,
+) -> Option<usize> {
+    let (pivot, guard) = match freq.pivots(nee_bytes) {
+        Some(pivots) => pivots,
+        None => return Some(hay_bytes.len()),
+    };
+    pivot_rsearch(hay_bytes, nee_bytes, pivot, nee_bytes[pivot], guard)
+}
+
+#[inline]
+pub(crate) fn search_with_frequency_iac(
+    hay_bytes: &[u8],
+    nee_bytes: &[u8],
+    freq: &ByteFrequency,
+) -> Option<usize> {
+    let (pivot, guard) = match freq.pivots(nee_bytes) {
+        Some(pivots) => pivots,
+        None => return Some(0),
+    };
+    let pick_byte = nee_bytes[pivot];
+    pivot_search_iac(
+        hay_bytes,
+        nee_bytes,
+        pivot,
+        pick_byte.to_ascii_uppercase(),
+        pick_byte.to_ascii_lowercase(),
+        guard,
+    )
+}
+
+#[inline]
+pub(crate) fn rsearch_with_frequency_iac(
+    hay_bytes: &[u8],
+    nee_bytes: &[u8],
+    freq: &ByteFrequency,
+) -> Option<usize> {
+    let (pivot, guard) = match freq.pivots(nee_bytes) {
+        Some(pivots) => pivots,
+        None => return Some(hay_bytes.len()),
+    };
+    let pick_byte = nee_bytes[pivot];
+    pivot_rsearch_iac(
+        hay_bytes,
+        nee_bytes,
+        pivot,
+        pick_byte.to_ascii_uppercase(),
+        pick_byte.to_ascii_lowercase(),
+        guard,
+    )
+}
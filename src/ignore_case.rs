@@ -0,0 +1,420 @@
+//
+// The unicode case-insensitive match.
+//
+// Each character is folded by `to_uppercase()` and then `to_lowercase()`,
+// so that "ß" and "ẞ" are folded to "ss", "Σ" and "ς" are folded to "σ".
+// Because the folded length may differ from the original,
+// the matched slice of the haystack may have the length different from the needle.
+//
+// When the needle begins with an ascii char, the candidates are scanned by
+// `PickScanner` on the lead bytes of the chars, that are folded to begin with it.
+// These are the ascii upper and lower case, and the few non-ascii chars.
+//
+// The needle is folded once before the search. With `alloc`, the verification
+// work is bounded by the budget, and over it, the rest of the haystack is folded
+// window by window and searched by the Two-Way, so that the search is O(n + m).
+//
+use crate::mc_generic::over_budget;
+use crate::picks::PickScanner;
+
+#[cfg(feature = "alloc")]
+use crate::two_way::{two_way_rsearch_by, two_way_search_by};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+// the non-ascii chars, that are folded to begin with an ascii char:
+// 'ß', 'İ', 'ı', 'ſ', 'ǰ', 'ẖ'..='ẚ', 'ẞ', KELVIN SIGN and the ligatures 'ﬀ'..='ﬆ'.
+const FOLDED_TO_ASCII: [char; 19] = [
+    '\u{df}', '\u{130}', '\u{131}', '\u{17f}', '\u{1f0}', '\u{1e96}', '\u{1e97}', '\u{1e98}',
+    '\u{1e99}', '\u{1e9a}', '\u{1e9e}', '\u{212a}', '\u{fb00}', '\u{fb01}', '\u{fb02}', '\u{fb03}',
+    '\u{fb04}', '\u{fb05}', '\u{fb06}',
+];
+
+#[inline]
+fn fold(c: char) -> impl Iterator<Item = char> + Clone {
+    // 'ẞ' is only the char, that is lowercased to the char that is
+    // uppercased to the other, 'ß' to "SS". fold it as 'ß'.
+    let c = if c == '\u{1e9e}' { '\u{df}' } else { c };
+    c.to_uppercase().flat_map(char::to_lowercase)
+}
+
+#[inline]
+fn first_folded(nee: &str) -> Option<char> {
+    nee.chars().flat_map(fold).next()
+}
+
+// return the byte length of the matched slice at the head of `hay_tail`.
+// `nee` is the folded needle.
+fn match_len_at<I: Iterator<Item = char>>(hay_tail: &str, mut nee: I) -> Option<usize> {
+    let mut pending = nee.next();
+    for (i, hc) in hay_tail.char_indices() {
+        for f in fold(hc) {
+            match pending {
+                Some(nc) if nc == f => pending = nee.next(),
+                _ => return None,
+            }
+        }
+        if pending.is_none() {
+            return Some(i + hc.len_utf8());
+        }
+    }
+    None
+}
+
+// the ascii character is checked quickly before the full comparison.
+#[inline]
+fn is_candidate(hc: char, first: char) -> bool {
+    !hc.is_ascii() || hc.to_ascii_lowercase() == first
+}
+
+// the lead bytes of the chars, that are folded to begin with the ascii `first`.
+// return the count of the bytes, or None if `first` is not ascii.
+fn lead_bytes(first: char, picks: &mut [u8; 8]) -> Option<usize> {
+    if !first.is_ascii() {
+        return None;
+    }
+    let mut len = 0;
+    let mut push = |byte: u8| {
+        if !picks[..len].contains(&byte) {
+            picks[len] = byte;
+            len += 1;
+        }
+    };
+    push(first as u8);
+    push(first.to_ascii_uppercase() as u8);
+    for c in FOLDED_TO_ASCII {
+        if first_folded(c.encode_utf8(&mut [0; 4])) == Some(first) {
+            push(c.encode_utf8(&mut [0; 4]).as_bytes()[0]);
+        }
+    }
+    Some(len)
+}
+
+// verify the candidates in order. `nee` is the folded needle and `nee_len`
+// is its byte length. `scanned` returns the scanned length at the candidate.
+// return Err with the candidate, at which the work is over the budget.
+fn verify<N, C, S>(
+    haystack: &str,
+    nee: N,
+    nee_len: usize,
+    candidates: C,
+    scanned: S,
+) -> Result<Option<(usize, usize)>, usize>
+where
+    N: Iterator<Item = char> + Clone,
+    C: Iterator<Item = usize>,
+    S: Fn(usize) -> usize,
+{
+    let mut work = 0;
+    for i in candidates {
+        if let Some(len) = match_len_at(&haystack[i..], nee.clone()) {
+            return Ok(Some((i, len)));
+        }
+        work += nee_len;
+        // without `alloc`, there is no fallback.
+        if cfg!(feature = "alloc") && over_budget(work, scanned(i)) {
+            return Err(i);
+        }
+    }
+    Ok(None)
+}
+
+fn search_folded<N: Iterator<Item = char> + Clone>(
+    haystack: &str,
+    nee: N,
+    nee_len: usize,
+    first: char,
+) -> Result<Option<(usize, usize)>, usize> {
+    let mut picks = [0; 8];
+    if let Some(len) = lead_bytes(first, &mut picks) {
+        // the ascii bytes and the lead bytes are always on the char boundary.
+        let mut scanner = PickScanner::<2>::new(&picks[..len]);
+        let mut pos = 0;
+        let candidates = core::iter::from_fn(|| {
+            let i = scanner.next_candidate(haystack.as_bytes(), pos)?;
+            pos = i + 1;
+            Some(i)
+        });
+        return verify(haystack, nee, nee_len, candidates, |i| i);
+    }
+    let candidates = haystack
+        .char_indices()
+        .filter(|&(_, hc)| is_candidate(hc, first))
+        .map(|(i, _)| i);
+    verify(haystack, nee, nee_len, candidates, |i| i)
+}
+
+fn rsearch_folded<N: Iterator<Item = char> + Clone>(
+    haystack: &str,
+    nee: N,
+    nee_len: usize,
+    first: char,
+) -> Result<Option<(usize, usize)>, usize> {
+    let hay_len = haystack.len();
+    let mut picks = [0; 8];
+    if let Some(len) = lead_bytes(first, &mut picks) {
+        let mut scanner = PickScanner::<2>::new(&picks[..len]);
+        let mut pos = hay_len;
+        let candidates = core::iter::from_fn(|| {
+            let i = scanner.prev_candidate(haystack.as_bytes(), pos)?;
+            pos = i;
+            Some(i)
+        });
+        return verify(haystack, nee, nee_len, candidates, |i| hay_len - i);
+    }
+    let candidates = haystack
+        .char_indices()
+        .rev()
+        .filter(|&(_, hc)| is_candidate(hc, first))
+        .map(|(i, _)| i);
+    verify(haystack, nee, nee_len, candidates, |i| hay_len - i)
+}
+
+// The minimum byte length of the folded window, it is also at least
+// 4 times of the folded needle, so that the overlap of the windows is small.
+#[cfg(feature = "alloc")]
+const WINDOW_LEN: usize = 4 * 1024;
+
+// the mark of `FoldedWindow::bounds`, that is not at the start of a char.
+#[cfg(feature = "alloc")]
+const NOT_BOUND: usize = usize::MAX;
+
+// The window of the folded haystack.
+#[cfg(feature = "alloc")]
+struct FoldedWindow {
+    bytes: Vec<u8>,
+    // the index in the haystack of the char, whose folded chars begin at
+    // the index in `bytes`, or `NOT_BOUND`. the last is the end of the window.
+    bounds: Vec<usize>,
+}
+#[cfg(feature = "alloc")]
+impl FoldedWindow {
+    // fold `haystack[st..]` until the folded length reaches `min_len`.
+    fn fold_from(&mut self, haystack: &str, st: usize, min_len: usize) {
+        self.bytes.clear();
+        self.bounds.clear();
+        let mut ed = st;
+        for hc in haystack[st..].chars() {
+            if self.bytes.len() >= min_len {
+                break;
+            }
+            self.bounds.push(ed);
+            for f in fold(hc) {
+                self.bytes
+                    .extend_from_slice(f.encode_utf8(&mut [0; 4]).as_bytes());
+            }
+            self.bounds.resize(self.bytes.len(), NOT_BOUND);
+            ed += hc.len_utf8();
+        }
+        self.bounds.push(ed);
+    }
+    // the index in the haystack and the length of the match at `k`.
+    #[inline]
+    fn matched(&self, k: usize, nee_len: usize) -> (usize, usize) {
+        let st = self.bounds[k];
+        (st, self.bounds[k + nee_len] - st)
+    }
+    // the first char, whose folded chars begin at `k` or later.
+    #[inline]
+    fn bound_from(&self, k: usize) -> usize {
+        // the last of `bounds` is always the bound.
+        self.bounds[k..]
+            .iter()
+            .copied()
+            .find(|&b| b != NOT_BOUND)
+            .unwrap_or(NOT_BOUND)
+    }
+}
+
+// search the folded needle in the haystack, which is folded window by window.
+#[cfg(feature = "alloc")]
+fn search_windows(haystack: &str, nee: &str) -> Option<(usize, usize)> {
+    let nee_len = nee.len();
+    let min_len = WINDOW_LEN.max(4 * nee_len);
+    let mut win = FoldedWindow {
+        bytes: Vec::new(),
+        bounds: Vec::new(),
+    };
+    let mut st = 0;
+    loop {
+        win.fold_from(haystack, st, min_len);
+        let bounds = &win.bounds;
+        let found = two_way_search_by(&win.bytes, nee.as_bytes(), |k| {
+            bounds[k] != NOT_BOUND && bounds[k + nee_len] != NOT_BOUND
+        });
+        if let Some(k) = found {
+            return Some(win.matched(k, nee_len));
+        }
+        if win.bounds[win.bytes.len()] == haystack.len() {
+            return None;
+        }
+        // the matches, that begin at the last `nee_len - 1` bytes, are not checked.
+        st = win.bound_from(win.bytes.len() + 1 - nee_len);
+    }
+}
+
+// reverse search the folded needle in the haystack, which is folded window by window.
+#[cfg(feature = "alloc")]
+fn rsearch_windows(haystack: &str, nee: &str) -> Option<(usize, usize)> {
+    let nee_len = nee.len();
+    let min_len = WINDOW_LEN.max(4 * nee_len);
+    let mut win = FoldedWindow {
+        bytes: Vec::new(),
+        bounds: Vec::new(),
+    };
+    let mut ed = haystack.len();
+    loop {
+        let mut st = ed;
+        let mut folded_len = 0;
+        for (i, hc) in haystack[..ed].char_indices().rev() {
+            if folded_len >= min_len {
+                break;
+            }
+            folded_len += fold(hc).map(char::len_utf8).sum::<usize>();
+            st = i;
+        }
+        win.fold_from(&haystack[..ed], st, usize::MAX);
+        let bounds = &win.bounds;
+        let found = two_way_rsearch_by(&win.bytes, nee.as_bytes(), |k| {
+            bounds[k] != NOT_BOUND && bounds[k + nee_len] != NOT_BOUND
+        });
+        if let Some(k) = found {
+            return Some(win.matched(k, nee_len));
+        }
+        if st == 0 {
+            return None;
+        }
+        // the matches, that end at the first `nee_len - 1` bytes, are not checked.
+        ed = win.bound_from(nee_len - 1);
+    }
+}
+
+///
+/// search the needle in the haystack, ignore unicode case.
+///
+/// return the index and the length of the matched slice.
+///
+pub(crate) fn search_ignore_case(haystack: &str, needle: &str) -> Option<(usize, usize)> {
+    let first = match first_folded(needle) {
+        Some(c) => c,
+        None => return Some((0, 0)),
+    };
+    #[cfg(feature = "alloc")]
+    {
+        let folded: String = needle.chars().flat_map(fold).collect();
+        match search_folded(haystack, folded.chars(), folded.len(), first) {
+            Ok(found) => found,
+            Err(i) => search_windows(&haystack[i..], &folded).map(|(idx, len)| (i + idx, len)),
+        }
+    }
+    #[cfg(not(feature = "alloc"))]
+    {
+        let folded = needle.chars().flat_map(fold);
+        search_folded(haystack, folded, needle.len(), first).unwrap_or(None)
+    }
+}
+
+///
+/// reverse search the needle in the haystack, ignore unicode case.
+///
+/// return the index and the length of the matched slice.
+///
+pub(crate) fn rsearch_ignore_case(haystack: &str, needle: &str) -> Option<(usize, usize)> {
+    let first = match first_folded(needle) {
+        Some(c) => c,
+        None => return Some((haystack.len(), 0)),
+    };
+    #[cfg(feature = "alloc")]
+    {
+        let folded: String = needle.chars().flat_map(fold).collect();
+        match rsearch_folded(haystack, folded.chars(), folded.len(), first) {
+            Ok(found) => found,
+            // the candidates after it are already rejected.
+            Err(_) => rsearch_windows(haystack, &folded),
+        }
+    }
+    #[cfg(not(feature = "alloc"))]
+    {
+        let folded = needle.chars().flat_map(fold);
+        rsearch_folded(haystack, folded, needle.len(), first).unwrap_or(None)
+    }
+}
+
+///
+/// Created with the method [Search::search_indices_ignore_case()](crate::Search::search_indices_ignore_case).
+///
+#[derive(Debug, Clone)]
+pub struct SearchIndicesIgnoreCase<'a, 'n> {
+    curr_idx: usize,
+    haystack: &'a str,
+    needle: &'n str,
+}
+impl<'a, 'n> SearchIndicesIgnoreCase<'a, 'n> {
+    pub(crate) fn new(a_haystack: &'a str, a_needle: &'n str) -> SearchIndicesIgnoreCase<'a, 'n> {
+        SearchIndicesIgnoreCase {
+            curr_idx: 0,
+            haystack: a_haystack,
+            needle: a_needle,
+        }
+    }
+}
+impl<'a> Iterator for SearchIndicesIgnoreCase<'a, '_> {
+    type Item = (usize, &'a str);
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.needle.is_empty() {
+            return None;
+        }
+        match search_ignore_case(&self.haystack[self.curr_idx..], self.needle) {
+            Some((idx, len)) => {
+                let st = self.curr_idx + idx;
+                let ed = st + len;
+                self.curr_idx = ed;
+                Some((st, &self.haystack[st..ed]))
+            }
+            None => None,
+        }
+    }
+}
+
+///
+/// Created with the method [Search::rsearch_indices_ignore_case()](crate::Search::rsearch_indices_ignore_case).
+///
+#[derive(Debug, Clone)]
+pub struct RevSearchIndicesIgnoreCase<'a, 'n> {
+    curr_ed: usize,
+    haystack: &'a str,
+    needle: &'n str,
+}
+impl<'a, 'n> RevSearchIndicesIgnoreCase<'a, 'n> {
+    pub(crate) fn new(
+        a_haystack: &'a str,
+        a_needle: &'n str,
+    ) -> RevSearchIndicesIgnoreCase<'a, 'n> {
+        RevSearchIndicesIgnoreCase {
+            curr_ed: a_haystack.len(),
+            haystack: a_haystack,
+            needle: a_needle,
+        }
+    }
+}
+impl<'a> Iterator for RevSearchIndicesIgnoreCase<'a, '_> {
+    type Item = (usize, &'a str);
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.needle.is_empty() {
+            return None;
+        }
+        match rsearch_ignore_case(&self.haystack[0..self.curr_ed], self.needle) {
+            Some((idx, len)) => {
+                let st = idx;
+                let ed = st + len;
+                self.curr_ed = st;
+                Some((st, &self.haystack[st..ed]))
+            }
+            None => None,
+        }
+    }
+}
@@ -7,10 +7,27 @@ The optimized naive string-search algorithm.
 - Enhanced with 1-byte search like the libc++ and the libstd++ string::find
 - Specializing in UTF-8 strings, which is a feature of rust
 - The ASCII Stochastics search
+- The Two-Way fallback, to be O(n + m) in the worst case
+- Support the custom byte frequency table, built from your corpus.
 - Support the zero overhead trait.
+- Support the owned and the shared strings, such as `Box<str>`, `Arc<str>` and `Cow<str>`, and `Vec<u8>`.
 - Support ignore ascii case match.
+- Support `no_std` with or without the `alloc` crate.
 - minimum support rustc 1.65.0 (897e37553 2022-11-02)
 
+# Cargo features
+
+| feature           | description                                                                          |
+|:------------------|:-------------------------------------------------------------------------------------|
+| `std` (default)   | `StreamSearcher` over `std::io::Read` and `SearchOsStr` on unix. It enables `alloc`. |
+| `alloc`           | The impls for `String`, `MultiSearch`, the replace and the chunked search functions. |
+| `mmap`            | `search_file()` family over the mapped file, and `search_file_buffered()` family.    |
+| `parallel`        | `ParallelSearcher` and `par_search()` family over the chunks on the scoped threads.  |
+
+Without the default features, the crate is `no_std`, and the core search layer,
+such as `Search`, `SearchBytes`, `SearchIn`, `SearchInBytes` and the iterators,
+is available for `&str`, `&[u8]` and `[u8; N]`.
+
 # Compatibility
 
 This crate is implemented to replace the rust std library.
@@ -19,13 +36,24 @@ It shouldn't be too difficult.
 
 compatibility:
 
-| rust `std::str`              | this crate                             |
-|:-----------------------------|:---------------------------------------|
-| `std::str::find()`           | `naive_opt::Search::search()`          |
-| `std::str::rfind()`          | `naive_opt::Search::rsearch()`         |
-| `std::str::contains()`       | `naive_opt::Search::includes()`        |
-| `std::str::match_indices()`  | `naive_opt::Search::search_indices()`  |
-| `std::str::rmatch_indices()` | `naive_opt::Search::rsearch_indices()` |
+| rust `std::str`                | this crate                                     |
+|:-------------------------------|:-----------------------------------------------|
+| `std::str::find()`             | `naive_opt::Search::search()`                  |
+| `std::str::rfind()`            | `naive_opt::Search::rsearch()`                 |
+| `std::str::contains()`         | `naive_opt::Search::includes()`                |
+| `std::str::match_indices()`    | `naive_opt::Search::search_indices()`          |
+| `std::str::rmatch_indices()`   | `naive_opt::Search::rsearch_indices()`         |
+| `std::str::matches().count()`  | `naive_opt::Search::search_count()`            |
+| `std::str::split()`            | `naive_opt::Search::search_split()`            |
+| `std::str::rsplit()`           | `naive_opt::Search::search_rsplit()`           |
+| `std::str::splitn()`           | `naive_opt::Search::search_splitn()`           |
+| `std::str::split_terminator()` | `naive_opt::Search::search_split_terminator()` |
+| `std::str::replace()`          | `naive_opt::Search::search_replace()`          |
+| `std::str::replacen()`         | `naive_opt::Search::search_replacen()`         |
+
+Unlike the std library, the empty needle never matches in the match iterators,
+`search_count()`, the split and the replace. For example, `"abc".replace("", "-")`
+is `"-a-b-c-"`, but `"abc".search_replace("", "-")` is `"abc"`.
 
 # Ignore ascii case match
 
@@ -39,6 +67,33 @@ This crate supports an ASCII case-insensitive match with each function.
 | `naive_opt::Search::search_indices_ignore_ascii_case()`  |
 | `naive_opt::Search::rsearch_indices_ignore_ascii_case()` |
 
+# Ignore unicode case match
+
+This crate supports a unicode case-insensitive match by the case folding.
+The matched slice may have the length different from the needle.
+
+| this crate                                           |
+|:-----------------------------------------------------|
+| `naive_opt::Search::search_ignore_case()`            |
+| `naive_opt::Search::rsearch_ignore_case()`           |
+| `naive_opt::Search::includes_ignore_case()`          |
+| `naive_opt::Search::search_indices_ignore_case()`    |
+| `naive_opt::Search::rsearch_indices_ignore_case()`   |
+
+# Whole word match
+
+This crate supports a whole word match, that accepts only the matches
+whose neighbors are not the word characters, defined by `naive_opt::WordChars`.
+
+| this crate                                                   |
+|:-------------------------------------------------------------|
+| `naive_opt::Search::search_word()`                           |
+| `naive_opt::Search::rsearch_word()`                          |
+| `naive_opt::Search::search_indices_word()`                   |
+| `naive_opt::Search::search_word_ignore_ascii_case()`         |
+| `naive_opt::Search::rsearch_word_ignore_ascii_case()`        |
+| `naive_opt::Search::search_indices_word_ignore_ascii_case()` |
+
 # Examples
 
 ## Example function:
@@ -114,9 +169,28 @@ assert_eq!("<A HREF=http://".includes_ignore_ascii_case("href"), true);
 ```
 */
 
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+use core::ops::RangeBounds;
+
 ///
 /// search the needle
 ///
+/// It is implemented for the types of `AsRef<str>`, such as `&str`, `String`,
+/// `Box<str>`, `Rc<str>`, `Arc<str>` and `Cow<str>`.
+///
 pub trait Search {
     ///
     /// search the needle in self.
@@ -131,6 +205,111 @@ pub trait Search {
     ///
     fn rsearch<'a, P: SearchIn<'a>>(&'a self, needle: P) -> Option<usize>;
     ///
+    /// search the needle in self, from the `start` index.
+    ///
+    /// return the index of self (not of the rest from `start`), if it found the needle.
+    /// Otherwise return None.
+    ///
+    /// If `start` is greater than the length of self, or is not on a char boundary,
+    /// return None without panic.
+    ///
+    /// Examples
+    ///
+    /// ```rust
+    /// use naive_opt::Search;
+    ///
+    /// let haystack = "abc345abc901abc";
+    /// assert_eq!(haystack.search_from("abc", 0), Some(0));
+    /// assert_eq!(haystack.search_from("abc", 1), Some(6));
+    /// assert_eq!(haystack.search_from("abc", 13), None);
+    /// assert_eq!(haystack.search_from("abc", 99), None);
+    ///
+    /// assert_eq!("αβγ".search_from("γ", 1), None); // not on a char boundary
+    /// ```
+    ///
+    fn search_from<'a, P: SearchIn<'a>>(&'a self, needle: P, start: usize) -> Option<usize>;
+    ///
+    /// reverse search the needle in self, before the `end` index.
+    ///
+    /// return index of self, if it found the needle ending at or before `end`.
+    /// Otherwise return None.
+    ///
+    /// If `end` is greater than the length of self, or is not on a char boundary,
+    /// return None without panic.
+    ///
+    /// Examples
+    ///
+    /// ```rust
+    /// use naive_opt::Search;
+    ///
+    /// let haystack = "abc345abc901abc";
+    /// assert_eq!(haystack.rsearch_before("abc", 15), Some(12));
+    /// assert_eq!(haystack.rsearch_before("abc", 14), Some(6));
+    /// assert_eq!(haystack.rsearch_before("abc", 2), None);
+    /// ```
+    ///
+    fn rsearch_before<'a, P: SearchIn<'a>>(&'a self, needle: P, end: usize) -> Option<usize>;
+    ///
+    /// search the needle in the range of self.
+    ///
+    /// return the index of self (not of the range), if it found the needle
+    /// entirely in the range. Otherwise return None.
+    ///
+    /// If the range is out of bounds, or its ends are not on a char boundary,
+    /// return None without panic.
+    ///
+    /// Examples
+    ///
+    /// ```rust
+    /// use naive_opt::Search;
+    ///
+    /// let haystack = "abc345abc901abc";
+    /// assert_eq!(haystack.search_in_range("abc", 1..12), Some(6));
+    /// assert_eq!(haystack.search_in_range("abc", 1..8), None);
+    /// assert_eq!(haystack.search_in_range("abc", 7..), Some(12));
+    /// assert_eq!(haystack.search_in_range("abc", ..=2), Some(0));
+    /// ```
+    ///
+    fn search_in_range<'a, P, R>(&'a self, needle: P, range: R) -> Option<usize>
+    where
+        P: SearchIn<'a>,
+        R: RangeBounds<usize>;
+    ///
+    /// search the needle in self, with the byte frequency table.
+    ///
+    /// return index of self, if it found the needle. Otherwise return None.
+    ///
+    /// The pivot byte and the guard byte of the needle are decided by `freq`
+    /// instead of the built-in table, in the same way as [Search::search()].
+    ///
+    fn search_with_frequency(&self, needle: &str, freq: &ByteFrequency) -> Option<usize>;
+    ///
+    /// reverse search the needle in self, with the byte frequency table.
+    ///
+    /// return index of self, if it found the needle. Otherwise return None.
+    ///
+    fn rsearch_with_frequency(&self, needle: &str, freq: &ByteFrequency) -> Option<usize>;
+    ///
+    /// search the needle in self, with the byte frequency table, ignore ascii case.
+    ///
+    /// return index of self, if it found the needle. Otherwise return None.
+    ///
+    fn search_with_frequency_ignore_ascii_case(
+        &self,
+        needle: &str,
+        freq: &ByteFrequency,
+    ) -> Option<usize>;
+    ///
+    /// reverse search the needle in self, with the byte frequency table, ignore ascii case.
+    ///
+    /// return index of self, if it found the needle. Otherwise return None.
+    ///
+    fn rsearch_with_frequency_ignore_ascii_case(
+        &self,
+        needle: &str,
+        freq: &ByteFrequency,
+    ) -> Option<usize>;
+    ///
     /// An iterator over the matches of needle in self.
     ///
     /// Examples
@@ -146,8 +325,19 @@ pub trait Search {
     ///
     /// let v: Vec<_> = "ababa".search_indices("aba").collect();
     /// assert_eq!(v, [(0, "aba")]); // only the first `aba`
+    ///
+    /// let mut iter = "abc345abc901abc".search_indices('c');
+    /// assert_eq!(iter.next(), Some((2, "c")));
+    /// assert_eq!(iter.next_back(), Some((14, "c")));
+    /// assert_eq!(iter.next(), Some((8, "c")));
+    /// assert_eq!(iter.next_back(), None);
     /// ```
     ///
+    /// The iterator is double-ended for the [DoubleEndedSearchIn] needle,
+    /// such as `char`.
+    ///
+    /// For all overlapping matches, use [Search::search_indices_overlapping()].
+    ///
     fn search_indices<'a, P: SearchIn<'a>>(&'a self, needle: P) -> SearchIndices<'a, P>;
     ///
     /// An reverse search iterator over the matches of needle in self.
@@ -169,6 +359,71 @@ pub trait Search {
     ///
     fn rsearch_indices<'a, P: SearchIn<'a>>(&'a self, needle: P) -> RevSearchIndices<'a, P>;
     ///
+    /// An iterator over the overlapping matches of needle in self.
+    ///
+    /// Examples
+    ///
+    /// ```rust
+    /// use naive_opt::Search;
+    ///
+    /// let v: Vec<_> = "ababa".search_indices_overlapping("aba").collect();
+    /// assert_eq!(v, [(0, "aba"), (2, "aba")]);
+    ///
+    /// let v: Vec<_> = "aaaa".search_indices_overlapping("aa").collect();
+    /// assert_eq!(v, [(0, "aa"), (1, "aa"), (2, "aa")]);
+    /// ```
+    ///
+    fn search_indices_overlapping<'a, P: SearchIn<'a>>(
+        &'a self,
+        needle: P,
+    ) -> SearchIndicesOverlapping<'a, P>;
+    ///
+    /// An reverse search iterator over the overlapping matches of needle in self.
+    ///
+    /// Examples
+    ///
+    /// ```rust
+    /// use naive_opt::Search;
+    ///
+    /// let v: Vec<_> = "ababa".rsearch_indices_overlapping("aba").collect();
+    /// assert_eq!(v, [(2, "aba"), (0, "aba")]);
+    /// ```
+    ///
+    fn rsearch_indices_overlapping<'a, P: SearchIn<'a>>(
+        &'a self,
+        needle: P,
+    ) -> RevSearchIndicesOverlapping<'a, P>;
+    ///
+    /// count the matches of needle in self.
+    ///
+    /// This is the same as `self.search_indices(needle).count()`,
+    /// without materializing the matches.
+    ///
+    /// Examples
+    ///
+    /// ```rust
+    /// use naive_opt::Search;
+    ///
+    /// assert_eq!("abc345abc901abc".search_count("abc"), 3);
+    /// assert_eq!("ababa".search_count("aba"), 1);
+    /// assert_eq!("abc".search_count(""), 0);
+    /// ```
+    ///
+    fn search_count<'a, P: SearchIn<'a>>(&'a self, needle: P) -> usize;
+    ///
+    /// count the overlapping matches of needle in self.
+    ///
+    /// Examples
+    ///
+    /// ```rust
+    /// use naive_opt::Search;
+    ///
+    /// assert_eq!("ababa".search_count_overlapping("aba"), 2);
+    /// assert_eq!("aaaa".search_count_overlapping("aa"), 3);
+    /// ```
+    ///
+    fn search_count_overlapping<'a, P: SearchIn<'a>>(&'a self, needle: P) -> usize;
+    ///
     /// includes the needle in self.
     ///
     /// returns true if the given pattern matches a sub-slice of this string slice.
@@ -202,133 +457,817 @@ pub trait Search {
         needle: P,
     ) -> RevSearchIndicesIgnoreAsciiCase<'a, P>;
     ///
-    /// includes the needle in self, ignore ascii case.
+    /// An iterator over the overlapping matches of needle in self, ignore ascii case.
     ///
-    fn includes_ignore_ascii_case<'a, P: SearchIn<'a>>(&'a self, needle: P) -> bool;
-}
-impl Search for &str {
-    #[inline]
-    fn search<'a, P: SearchIn<'a>>(&'a self, needle: P) -> Option<usize> {
-        needle.search_in(self)
-    }
-    #[inline]
-    fn rsearch<'a, P: SearchIn<'a>>(&'a self, needle: P) -> Option<usize> {
-        needle.rsearch_in(self)
-    }
-    #[inline]
-    fn search_indices<'a, P: SearchIn<'a>>(&'a self, needle: P) -> SearchIndices<'a, P> {
-        SearchIndices::new(self, needle)
-    }
-    #[inline]
-    fn rsearch_indices<'a, P: SearchIn<'a>>(&'a self, needle: P) -> RevSearchIndices<'a, P> {
-        RevSearchIndices::new(self, needle)
-    }
-    #[inline]
-    fn includes<'a, P: SearchIn<'a>>(&'a self, needle: P) -> bool {
-        needle.includes_in(self)
-    }
-    //
-    #[inline]
-    fn search_ignore_ascii_case<'a, P: SearchIn<'a>>(&'a self, needle: P) -> Option<usize> {
-        needle.search_in_ignore_ascii_case(self)
-    }
-    #[inline]
-    fn rsearch_ignore_ascii_case<'a, P: SearchIn<'a>>(&'a self, needle: P) -> Option<usize> {
-        needle.rsearch_in_ignore_ascii_case(self)
-    }
-    #[inline]
-    fn search_indices_ignore_ascii_case<'a, P: SearchIn<'a>>(
-        &'a self,
-        needle: P,
-    ) -> SearchIndicesIgnoreAsciiCase<'a, P> {
-        SearchIndicesIgnoreAsciiCase::new(self, needle)
-    }
-    #[inline]
-    fn rsearch_indices_ignore_ascii_case<'a, P: SearchIn<'a>>(
+    fn search_indices_overlapping_ignore_ascii_case<'a, P: SearchIn<'a>>(
         &'a self,
         needle: P,
-    ) -> RevSearchIndicesIgnoreAsciiCase<'a, P> {
-        RevSearchIndicesIgnoreAsciiCase::new(self, needle)
-    }
-    #[inline]
-    fn includes_ignore_ascii_case<'a, P: SearchIn<'a>>(&'a self, needle: P) -> bool {
-        needle.includes_in_ignore_ascii_case(self)
-    }
-}
-impl Search for String {
-    #[inline]
-    fn search<'a, P: SearchIn<'a>>(&'a self, needle: P) -> Option<usize> {
-        needle.search_in(self.as_str())
-    }
-    #[inline]
-    fn rsearch<'a, P: SearchIn<'a>>(&'a self, needle: P) -> Option<usize> {
-        needle.rsearch_in(self.as_str())
-    }
-    #[inline]
-    fn search_indices<'a, P: SearchIn<'a>>(&'a self, needle: P) -> SearchIndices<'a, P> {
-        SearchIndices::new(self.as_str(), needle)
-    }
-    #[inline]
-    fn rsearch_indices<'a, P: SearchIn<'a>>(&'a self, needle: P) -> RevSearchIndices<'a, P> {
-        RevSearchIndices::new(self.as_str(), needle)
-    }
-    #[inline]
-    fn includes<'a, P: SearchIn<'a>>(&'a self, needle: P) -> bool {
-        needle.includes_in(self)
-    }
-    //
-    #[inline]
-    fn search_ignore_ascii_case<'a, P: SearchIn<'a>>(&'a self, needle: P) -> Option<usize> {
-        needle.search_in_ignore_ascii_case(self.as_str())
-    }
-    #[inline]
-    fn rsearch_ignore_ascii_case<'a, P: SearchIn<'a>>(&'a self, needle: P) -> Option<usize> {
-        needle.rsearch_in_ignore_ascii_case(self.as_str())
-    }
-    #[inline]
-    fn search_indices_ignore_ascii_case<'a, P: SearchIn<'a>>(
+    ) -> SearchIndicesOverlappingIgnoreAsciiCase<'a, P>;
+    ///
+    /// An reverse search iterator over the overlapping matches of needle in self,
+    /// ignore ascii case.
+    ///
+    fn rsearch_indices_overlapping_ignore_ascii_case<'a, P: SearchIn<'a>>(
         &'a self,
         needle: P,
-    ) -> SearchIndicesIgnoreAsciiCase<'a, P> {
-        SearchIndicesIgnoreAsciiCase::new(self.as_str(), needle)
-    }
-    #[inline]
-    fn rsearch_indices_ignore_ascii_case<'a, P: SearchIn<'a>>(
+    ) -> RevSearchIndicesOverlappingIgnoreAsciiCase<'a, P>;
+    ///
+    /// count the matches of needle in self, ignore ascii case.
+    ///
+    fn search_count_ignore_ascii_case<'a, P: SearchIn<'a>>(&'a self, needle: P) -> usize;
+    ///
+    /// count the overlapping matches of needle in self, ignore ascii case.
+    ///
+    fn search_count_overlapping_ignore_ascii_case<'a, P: SearchIn<'a>>(
         &'a self,
         needle: P,
-    ) -> RevSearchIndicesIgnoreAsciiCase<'a, P> {
-        RevSearchIndicesIgnoreAsciiCase::new(self.as_str(), needle)
-    }
-    #[inline]
-    fn includes_ignore_ascii_case<'a, P: SearchIn<'a>>(&'a self, needle: P) -> bool {
-        needle.includes_in_ignore_ascii_case(self)
-    }
-}
-
-pub trait SearchBytes {
-    fn search_bytes<'a, P: SearchInBytes<'a>>(&'a self, needle: P) -> Option<usize>;
-    fn rsearch_bytes<'a, P: SearchInBytes<'a>>(&'a self, needle: P) -> Option<usize>;
-    fn search_indices_bytes<'a, P>(&'a self, needle: P) -> SearchIndicesBytes<'a, P>
-    where
-        P: SearchInBytes<'a>;
-    fn rsearch_indices_bytes<'a, P>(&'a self, needle: P) -> RevSearchIndicesBytes<'a, P>
-    where
-        P: SearchInBytes<'a>;
-    fn includes_bytes<'a, P: SearchInBytes<'a>>(&'a self, needle: P) -> bool;
-    //
-    fn search_bytes_ignore_ascii_case<'a, P: SearchInBytes<'a>>(
+    ) -> usize;
+    ///
+    /// includes the needle in self, ignore ascii case.
+    ///
+    fn includes_ignore_ascii_case<'a, P: SearchIn<'a>>(&'a self, needle: P) -> bool;
+    ///
+    /// search the needle in self, ignore unicode case.
+    ///
+    /// return index of self, if it found the needle. Otherwise return None.
+    ///
+    /// The characters are compared by the full case folding of
+    /// `to_uppercase()` and then `to_lowercase()`, so that "ß", "ẞ" and "ss"
+    /// match each other, therefore the matched slice may have the length
+    /// different from the needle. With the `alloc` feature, the worst case is O(n + m).
+    ///
+    /// Examples
+    ///
+    /// ```rust
+    /// use naive_opt::Search;
+    ///
+    /// assert_eq!("Die Straße".search_ignore_case("STRASSE"), Some(4));
+    /// assert_eq!("ÄPFEL und äpfel".search_ignore_case("äpfel"), Some(0));
+    /// assert_eq!("ÄPFEL und äpfel".rsearch_ignore_case("ÄPFEL"), Some(11));
+    ///
+    /// let v: Vec<_> = "ΣΊΣΥΦΟΣ σίσυφος".search_indices_ignore_case("σίσυφος").collect();
+    /// assert_eq!(v, [(0, "ΣΊΣΥΦΟΣ"), (15, "σίσυφος")]);
+    /// ```
+    ///
+    fn search_ignore_case(&self, needle: &str) -> Option<usize>;
+    ///
+    /// reverse search the needle in self, ignore unicode case.
+    ///
+    /// return index of self, if it found the needle. Otherwise return None.
+    ///
+    fn rsearch_ignore_case(&self, needle: &str) -> Option<usize>;
+    ///
+    /// An iterator over the matches of needle in self, ignore unicode case.
+    ///
+    fn search_indices_ignore_case<'a, 'n>(
         &'a self,
-        needle: P,
-    ) -> Option<usize>;
-    fn rsearch_bytes_ignore_ascii_case<'a, P: SearchInBytes<'a>>(
+        needle: &'n str,
+    ) -> SearchIndicesIgnoreCase<'a, 'n>;
+    ///
+    /// An reverse search iterator over the matches of needle in self, ignore unicode case.
+    ///
+    fn rsearch_indices_ignore_case<'a, 'n>(
         &'a self,
-        needle: P,
-    ) -> Option<usize>;
-    fn search_indices_bytes_ignore_ascii_case<'a, P>(
+        needle: &'n str,
+    ) -> RevSearchIndicesIgnoreCase<'a, 'n>;
+    ///
+    /// includes the needle in self, ignore unicode case.
+    ///
+    fn includes_ignore_case(&self, needle: &str) -> bool;
+    ///
+    /// search the whole word of the needle in self.
+    ///
+    /// return index of self, if it found the needle that the neighbors
+    /// are not the word characters. Otherwise return None.
+    /// An empty needle never matches.
+    ///
+    /// Examples
+    ///
+    /// ```rust
+    /// use naive_opt::{Search, WordChars};
+    ///
+    /// let haystack = "let width = id + idx; id";
+    /// assert_eq!(haystack.search_word("id", WordChars::Ascii), Some(12));
+    /// assert_eq!(haystack.rsearch_word("id", WordChars::Ascii), Some(22));
+    /// assert_eq!(haystack.search_word("ID", WordChars::Ascii), None);
+    /// assert_eq!(haystack.search_word_ignore_ascii_case("ID", WordChars::Ascii), Some(12));
+    ///
+    /// let v: Vec<_> = haystack.search_indices_word("id", WordChars::Ascii).collect();
+    /// assert_eq!(v, [(12, "id"), (22, "id")]);
+    /// ```
+    ///
+    fn search_word(&self, needle: &str, word: WordChars) -> Option<usize>;
+    ///
+    /// reverse search the whole word of the needle in self.
+    ///
+    /// return index of self, if it found the needle. Otherwise return None.
+    ///
+    fn rsearch_word(&self, needle: &str, word: WordChars) -> Option<usize>;
+    ///
+    /// An iterator over the whole word matches of the needle in self.
+    ///
+    fn search_indices_word<'a, 'n>(
         &'a self,
-        needle: P,
-    ) -> SearchIndicesBytesIgnoreAsciiCase<'a, P>
-    where
+        needle: &'n str,
+        word: WordChars,
+    ) -> SearchIndicesWord<'a, 'n>;
+    ///
+    /// search the whole word of the needle in self, ignore ascii case.
+    ///
+    /// return index of self, if it found the needle. Otherwise return None.
+    ///
+    fn search_word_ignore_ascii_case(&self, needle: &str, word: WordChars) -> Option<usize>;
+    ///
+    /// reverse search the whole word of the needle in self, ignore ascii case.
+    ///
+    /// return index of self, if it found the needle. Otherwise return None.
+    ///
+    fn rsearch_word_ignore_ascii_case(&self, needle: &str, word: WordChars) -> Option<usize>;
+    ///
+    /// An iterator over the whole word matches of the needle in self, ignore ascii case.
+    ///
+    fn search_indices_word_ignore_ascii_case<'a, 'n>(
+        &'a self,
+        needle: &'n str,
+        word: WordChars,
+    ) -> SearchIndicesWord<'a, 'n>;
+    ///
+    /// An iterator over substrings of self, separated by the needle.
+    ///
+    /// Examples
+    ///
+    /// ```rust
+    /// use naive_opt::Search;
+    ///
+    /// let v: Vec<_> = "abc::def::ghi".search_split("::").collect();
+    /// assert_eq!(v, ["abc", "def", "ghi"]);
+    ///
+    /// let v: Vec<_> = "::abc::::def::".search_split("::").collect();
+    /// assert_eq!(v, ["", "abc", "", "def", ""]);
+    ///
+    /// let v: Vec<_> = "abc".search_split("").collect();
+    /// assert_eq!(v, ["abc"]); // an empty needle does not split
+    /// ```
+    ///
+    fn search_split<'a, P: SearchIn<'a>>(
+        &'a self,
+        needle: P,
+    ) -> SearchSplit<'a, SearchIndices<'a, P>>;
+    ///
+    /// An iterator over substrings of self, separated by the needle,
+    /// and yielded in reverse order.
+    ///
+    /// Examples
+    ///
+    /// ```rust
+    /// use naive_opt::Search;
+    ///
+    /// let v: Vec<_> = "abc::def::ghi".search_rsplit("::").collect();
+    /// assert_eq!(v, ["ghi", "def", "abc"]);
+    /// ```
+    ///
+    fn search_rsplit<'a, P: SearchIn<'a>>(
+        &'a self,
+        needle: P,
+    ) -> SearchRSplit<'a, RevSearchIndices<'a, P>>;
+    ///
+    /// An iterator over substrings of self, separated by the needle,
+    /// restricted to returning at most `n` items.
+    ///
+    /// The last item contains the remainder of self.
+    ///
+    /// Examples
+    ///
+    /// ```rust
+    /// use naive_opt::Search;
+    ///
+    /// let v: Vec<_> = "abc::def::ghi".search_splitn(2, "::").collect();
+    /// assert_eq!(v, ["abc", "def::ghi"]);
+    /// ```
+    ///
+    fn search_splitn<'a, P: SearchIn<'a>>(
+        &'a self,
+        n: usize,
+        needle: P,
+    ) -> SearchSplitN<'a, SearchIndices<'a, P>>;
+    ///
+    /// An iterator over substrings of self, separated by the needle.
+    ///
+    /// Equivalent to [Search::search_split()], except that the trailing substring
+    /// is skipped if empty.
+    ///
+    /// Examples
+    ///
+    /// ```rust
+    /// use naive_opt::Search;
+    ///
+    /// let v: Vec<_> = "abc::def::".search_split_terminator("::").collect();
+    /// assert_eq!(v, ["abc", "def"]);
+    /// ```
+    ///
+    fn search_split_terminator<'a, P: SearchIn<'a>>(
+        &'a self,
+        needle: P,
+    ) -> SearchSplit<'a, SearchIndices<'a, P>>;
+    ///
+    /// An iterator over substrings of self, separated by the needle, ignore ascii case.
+    ///
+    fn search_split_ignore_ascii_case<'a, P: SearchIn<'a>>(
+        &'a self,
+        needle: P,
+    ) -> SearchSplit<'a, SearchIndicesIgnoreAsciiCase<'a, P>>;
+    ///
+    /// An iterator over substrings of self, separated by the needle,
+    /// and yielded in reverse order, ignore ascii case.
+    ///
+    fn search_rsplit_ignore_ascii_case<'a, P: SearchIn<'a>>(
+        &'a self,
+        needle: P,
+    ) -> SearchRSplit<'a, RevSearchIndicesIgnoreAsciiCase<'a, P>>;
+    ///
+    /// An iterator over substrings of self, separated by the needle,
+    /// restricted to returning at most `n` items, ignore ascii case.
+    ///
+    fn search_splitn_ignore_ascii_case<'a, P: SearchIn<'a>>(
+        &'a self,
+        n: usize,
+        needle: P,
+    ) -> SearchSplitN<'a, SearchIndicesIgnoreAsciiCase<'a, P>>;
+    ///
+    /// An iterator over substrings of self, separated by the needle,
+    /// skipped the trailing empty substring, ignore ascii case.
+    ///
+    fn search_split_terminator_ignore_ascii_case<'a, P: SearchIn<'a>>(
+        &'a self,
+        needle: P,
+    ) -> SearchSplit<'a, SearchIndicesIgnoreAsciiCase<'a, P>>;
+    ///
+    /// An iterator over the matches of the needle in self, with their lines.
+    ///
+    /// The newlines are counted incrementally between the matches,
+    /// so the haystack is scanned once.
+    ///
+    /// Examples
+    ///
+    /// ```rust
+    /// use naive_opt::Search;
+    ///
+    /// let haystack = "use std;\nfn main() {\n    let café = \"todo\"; // todo\n}\n";
+    /// let v: Vec<_> = haystack
+    ///     .search_lines("todo")
+    ///     .map(|m| (m.line_no, m.column_byte, m.column_char))
+    ///     .collect();
+    /// assert_eq!(v, [(3, 17, 16), (3, 27, 26)]);
+    ///
+    /// let m = haystack.search_lines("main").next().unwrap();
+    /// assert_eq!(m.line, "fn main() {");
+    /// assert_eq!(m.line_start, 9);
+    /// ```
+    ///
+    fn search_lines<'a, P: SearchIn<'a>>(
+        &'a self,
+        needle: P,
+    ) -> SearchLines<'a, SearchIndices<'a, P>>;
+    ///
+    /// An iterator over the matches of the needle in self, with their lines,
+    /// ignore ascii case.
+    ///
+    fn search_lines_ignore_ascii_case<'a, P: SearchIn<'a>>(
+        &'a self,
+        needle: P,
+    ) -> SearchLines<'a, SearchIndicesIgnoreAsciiCase<'a, P>>;
+    ///
+    /// replace all matches of the needle with the `to` string.
+    ///
+    /// If nothing matched, return the borrowed self without the allocation.
+    /// The empty needle matches nothing, unlike `str::replace()` that inserts
+    /// the `to` string between every char.
+    ///
+    /// Examples
+    ///
+    /// ```rust
+    /// use naive_opt::Search;
+    ///
+    /// assert_eq!("Hello {name}!".search_replace("{name}", "World"), "Hello World!");
+    /// assert_eq!("abc345abc901".search_replace("abc", "X"), "X345X901");
+    ///
+    /// let r = "abc".search_replace("xyz", "X");
+    /// assert!(matches!(r, std::borrow::Cow::Borrowed("abc")));
+    /// ```
+    ///
+    #[cfg(feature = "alloc")]
+    fn search_replace<'a, P: SearchIn<'a>>(&'a self, needle: P, to: &str) -> Cow<'a, str>;
+    ///
+    /// replace first `count` matches of the needle with the `to` string.
+    ///
+    /// If nothing matched, return the borrowed self without the allocation.
+    /// The empty needle matches nothing, the same as [Search::search_replace()].
+    ///
+    /// Examples
+    ///
+    /// ```rust
+    /// use naive_opt::Search;
+    ///
+    /// assert_eq!("abc345abc901".search_replacen("abc", "X", 1), "X345abc901");
+    /// ```
+    ///
+    #[cfg(feature = "alloc")]
+    fn search_replacen<'a, P: SearchIn<'a>>(
+        &'a self,
+        needle: P,
+        to: &str,
+        count: usize,
+    ) -> Cow<'a, str>;
+    ///
+    /// replace all matches of the needle with the `to` string,
+    /// and append the result into the `dst` buffer.
+    ///
+    /// Examples
+    ///
+    /// ```rust
+    /// use naive_opt::Search;
+    ///
+    /// let mut buf = String::from("> ");
+    /// "abc345abc901".search_replace_into("abc", "X", &mut buf);
+    /// assert_eq!(buf, "> X345X901");
+    /// ```
+    ///
+    #[cfg(feature = "alloc")]
+    fn search_replace_into<'a, P: SearchIn<'a>>(&'a self, needle: P, to: &str, dst: &mut String);
+    ///
+    /// replace all matches of the needle with the `to` string, ignore ascii case.
+    ///
+    #[cfg(feature = "alloc")]
+    fn search_replace_ignore_ascii_case<'a, P: SearchIn<'a>>(
+        &'a self,
+        needle: P,
+        to: &str,
+    ) -> Cow<'a, str>;
+    ///
+    /// replace first `count` matches of the needle with the `to` string, ignore ascii case.
+    ///
+    #[cfg(feature = "alloc")]
+    fn search_replacen_ignore_ascii_case<'a, P: SearchIn<'a>>(
+        &'a self,
+        needle: P,
+        to: &str,
+        count: usize,
+    ) -> Cow<'a, str>;
+    ///
+    /// replace all matches of the needle with the `to` string,
+    /// and append the result into the `dst` buffer, ignore ascii case.
+    ///
+    #[cfg(feature = "alloc")]
+    fn search_replace_into_ignore_ascii_case<'a, P: SearchIn<'a>>(
+        &'a self,
+        needle: P,
+        to: &str,
+        dst: &mut String,
+    );
+}
+impl<T: AsRef<str>> Search for T {
+    #[inline]
+    fn search<'a, P: SearchIn<'a>>(&'a self, needle: P) -> Option<usize> {
+        needle.search_in(self.as_ref())
+    }
+    #[inline]
+    fn rsearch<'a, P: SearchIn<'a>>(&'a self, needle: P) -> Option<usize> {
+        needle.rsearch_in(self.as_ref())
+    }
+    #[inline]
+    fn search_from<'a, P: SearchIn<'a>>(&'a self, needle: P, start: usize) -> Option<usize> {
+        range::search_from(self.as_ref(), needle, start)
+    }
+    #[inline]
+    fn rsearch_before<'a, P: SearchIn<'a>>(&'a self, needle: P, end: usize) -> Option<usize> {
+        range::rsearch_before(self.as_ref(), needle, end)
+    }
+    #[inline]
+    fn search_in_range<'a, P, R>(&'a self, needle: P, range: R) -> Option<usize>
+    where
+        P: SearchIn<'a>,
+        R: RangeBounds<usize>,
+    {
+        range::search_in_range(self.as_ref(), needle, range)
+    }
+    #[inline]
+    fn search_with_frequency(&self, needle: &str, freq: &ByteFrequency) -> Option<usize> {
+        frequency::search_with_frequency(self.as_ref().as_bytes(), needle.as_bytes(), freq)
+    }
+    #[inline]
+    fn rsearch_with_frequency(&self, needle: &str, freq: &ByteFrequency) -> Option<usize> {
+        frequency::rsearch_with_frequency(self.as_ref().as_bytes(), needle.as_bytes(), freq)
+    }
+    #[inline]
+    fn search_with_frequency_ignore_ascii_case(
+        &self,
+        needle: &str,
+        freq: &ByteFrequency,
+    ) -> Option<usize> {
+        frequency::search_with_frequency_iac(self.as_ref().as_bytes(), needle.as_bytes(), freq)
+    }
+    #[inline]
+    fn rsearch_with_frequency_ignore_ascii_case(
+        &self,
+        needle: &str,
+        freq: &ByteFrequency,
+    ) -> Option<usize> {
+        frequency::rsearch_with_frequency_iac(self.as_ref().as_bytes(), needle.as_bytes(), freq)
+    }
+    #[inline]
+    fn search_indices<'a, P: SearchIn<'a>>(&'a self, needle: P) -> SearchIndices<'a, P> {
+        SearchIndices::new(self.as_ref(), needle)
+    }
+    #[inline]
+    fn rsearch_indices<'a, P: SearchIn<'a>>(&'a self, needle: P) -> RevSearchIndices<'a, P> {
+        RevSearchIndices::new(self.as_ref(), needle)
+    }
+    #[inline]
+    fn search_indices_overlapping<'a, P: SearchIn<'a>>(
+        &'a self,
+        needle: P,
+    ) -> SearchIndicesOverlapping<'a, P> {
+        SearchIndicesOverlapping::new(self.as_ref(), needle)
+    }
+    #[inline]
+    fn rsearch_indices_overlapping<'a, P: SearchIn<'a>>(
+        &'a self,
+        needle: P,
+    ) -> RevSearchIndicesOverlapping<'a, P> {
+        RevSearchIndicesOverlapping::new(self.as_ref(), needle)
+    }
+    #[inline]
+    fn search_count<'a, P: SearchIn<'a>>(&'a self, needle: P) -> usize {
+        needle.count_in(self.as_ref())
+    }
+    #[inline]
+    fn search_count_overlapping<'a, P: SearchIn<'a>>(&'a self, needle: P) -> usize {
+        needle.count_in_overlapping(self.as_ref())
+    }
+    #[inline]
+    fn includes<'a, P: SearchIn<'a>>(&'a self, needle: P) -> bool {
+        needle.includes_in(self.as_ref())
+    }
+    //
+    #[inline]
+    fn search_ignore_ascii_case<'a, P: SearchIn<'a>>(&'a self, needle: P) -> Option<usize> {
+        needle.search_in_ignore_ascii_case(self.as_ref())
+    }
+    #[inline]
+    fn rsearch_ignore_ascii_case<'a, P: SearchIn<'a>>(&'a self, needle: P) -> Option<usize> {
+        needle.rsearch_in_ignore_ascii_case(self.as_ref())
+    }
+    #[inline]
+    fn search_indices_ignore_ascii_case<'a, P: SearchIn<'a>>(
+        &'a self,
+        needle: P,
+    ) -> SearchIndicesIgnoreAsciiCase<'a, P> {
+        SearchIndicesIgnoreAsciiCase::new(self.as_ref(), needle)
+    }
+    #[inline]
+    fn rsearch_indices_ignore_ascii_case<'a, P: SearchIn<'a>>(
+        &'a self,
+        needle: P,
+    ) -> RevSearchIndicesIgnoreAsciiCase<'a, P> {
+        RevSearchIndicesIgnoreAsciiCase::new(self.as_ref(), needle)
+    }
+    #[inline]
+    fn search_indices_overlapping_ignore_ascii_case<'a, P: SearchIn<'a>>(
+        &'a self,
+        needle: P,
+    ) -> SearchIndicesOverlappingIgnoreAsciiCase<'a, P> {
+        SearchIndicesOverlappingIgnoreAsciiCase::new(self.as_ref(), needle)
+    }
+    #[inline]
+    fn rsearch_indices_overlapping_ignore_ascii_case<'a, P: SearchIn<'a>>(
+        &'a self,
+        needle: P,
+    ) -> RevSearchIndicesOverlappingIgnoreAsciiCase<'a, P> {
+        RevSearchIndicesOverlappingIgnoreAsciiCase::new(self.as_ref(), needle)
+    }
+    #[inline]
+    fn search_count_ignore_ascii_case<'a, P: SearchIn<'a>>(&'a self, needle: P) -> usize {
+        needle.count_in_ignore_ascii_case(self.as_ref())
+    }
+    #[inline]
+    fn search_count_overlapping_ignore_ascii_case<'a, P: SearchIn<'a>>(
+        &'a self,
+        needle: P,
+    ) -> usize {
+        needle.count_in_overlapping_ignore_ascii_case(self.as_ref())
+    }
+    #[inline]
+    fn includes_ignore_ascii_case<'a, P: SearchIn<'a>>(&'a self, needle: P) -> bool {
+        needle.includes_in_ignore_ascii_case(self.as_ref())
+    }
+    //
+    #[inline]
+    fn search_ignore_case(&self, needle: &str) -> Option<usize> {
+        ignore_case::search_ignore_case(self.as_ref(), needle).map(|(idx, _)| idx)
+    }
+    #[inline]
+    fn rsearch_ignore_case(&self, needle: &str) -> Option<usize> {
+        ignore_case::rsearch_ignore_case(self.as_ref(), needle).map(|(idx, _)| idx)
+    }
+    #[inline]
+    fn search_indices_ignore_case<'a, 'n>(
+        &'a self,
+        needle: &'n str,
+    ) -> SearchIndicesIgnoreCase<'a, 'n> {
+        SearchIndicesIgnoreCase::new(self.as_ref(), needle)
+    }
+    #[inline]
+    fn rsearch_indices_ignore_case<'a, 'n>(
+        &'a self,
+        needle: &'n str,
+    ) -> RevSearchIndicesIgnoreCase<'a, 'n> {
+        RevSearchIndicesIgnoreCase::new(self.as_ref(), needle)
+    }
+    #[inline]
+    fn includes_ignore_case(&self, needle: &str) -> bool {
+        self.search_ignore_case(needle).is_some()
+    }
+    //
+    #[inline]
+    fn search_word(&self, needle: &str, word: WordChars) -> Option<usize> {
+        word::search_word_from(self.as_ref().as_bytes(), needle.as_bytes(), 0, word, false)
+    }
+    #[inline]
+    fn rsearch_word(&self, needle: &str, word: WordChars) -> Option<usize> {
+        word::rsearch_word_before(
+            self.as_ref().as_bytes(),
+            needle.as_bytes(),
+            self.as_ref().len(),
+            word,
+            false,
+        )
+    }
+    #[inline]
+    fn search_indices_word<'a, 'n>(
+        &'a self,
+        needle: &'n str,
+        word: WordChars,
+    ) -> SearchIndicesWord<'a, 'n> {
+        SearchIndicesWord::new(self.as_ref(), needle, word, false)
+    }
+    #[inline]
+    fn search_word_ignore_ascii_case(&self, needle: &str, word: WordChars) -> Option<usize> {
+        word::search_word_from(self.as_ref().as_bytes(), needle.as_bytes(), 0, word, true)
+    }
+    #[inline]
+    fn rsearch_word_ignore_ascii_case(&self, needle: &str, word: WordChars) -> Option<usize> {
+        word::rsearch_word_before(
+            self.as_ref().as_bytes(),
+            needle.as_bytes(),
+            self.as_ref().len(),
+            word,
+            true,
+        )
+    }
+    #[inline]
+    fn search_indices_word_ignore_ascii_case<'a, 'n>(
+        &'a self,
+        needle: &'n str,
+        word: WordChars,
+    ) -> SearchIndicesWord<'a, 'n> {
+        SearchIndicesWord::new(self.as_ref(), needle, word, true)
+    }
+    //
+    #[inline]
+    fn search_split<'a, P: SearchIn<'a>>(
+        &'a self,
+        needle: P,
+    ) -> SearchSplit<'a, SearchIndices<'a, P>> {
+        SearchSplit::new(
+            self.as_ref(),
+            SearchIndices::new(self.as_ref(), needle),
+            true,
+        )
+    }
+    #[inline]
+    fn search_rsplit<'a, P: SearchIn<'a>>(
+        &'a self,
+        needle: P,
+    ) -> SearchRSplit<'a, RevSearchIndices<'a, P>> {
+        SearchRSplit::new(self.as_ref(), RevSearchIndices::new(self.as_ref(), needle))
+    }
+    #[inline]
+    fn search_splitn<'a, P: SearchIn<'a>>(
+        &'a self,
+        n: usize,
+        needle: P,
+    ) -> SearchSplitN<'a, SearchIndices<'a, P>> {
+        SearchSplitN::new(self.search_split(needle), n)
+    }
+    #[inline]
+    fn search_split_terminator<'a, P: SearchIn<'a>>(
+        &'a self,
+        needle: P,
+    ) -> SearchSplit<'a, SearchIndices<'a, P>> {
+        SearchSplit::new(
+            self.as_ref(),
+            SearchIndices::new(self.as_ref(), needle),
+            false,
+        )
+    }
+    #[inline]
+    fn search_split_ignore_ascii_case<'a, P: SearchIn<'a>>(
+        &'a self,
+        needle: P,
+    ) -> SearchSplit<'a, SearchIndicesIgnoreAsciiCase<'a, P>> {
+        SearchSplit::new(
+            self.as_ref(),
+            SearchIndicesIgnoreAsciiCase::new(self.as_ref(), needle),
+            true,
+        )
+    }
+    #[inline]
+    fn search_rsplit_ignore_ascii_case<'a, P: SearchIn<'a>>(
+        &'a self,
+        needle: P,
+    ) -> SearchRSplit<'a, RevSearchIndicesIgnoreAsciiCase<'a, P>> {
+        SearchRSplit::new(
+            self.as_ref(),
+            RevSearchIndicesIgnoreAsciiCase::new(self.as_ref(), needle),
+        )
+    }
+    #[inline]
+    fn search_splitn_ignore_ascii_case<'a, P: SearchIn<'a>>(
+        &'a self,
+        n: usize,
+        needle: P,
+    ) -> SearchSplitN<'a, SearchIndicesIgnoreAsciiCase<'a, P>> {
+        SearchSplitN::new(self.search_split_ignore_ascii_case(needle), n)
+    }
+    #[inline]
+    fn search_split_terminator_ignore_ascii_case<'a, P: SearchIn<'a>>(
+        &'a self,
+        needle: P,
+    ) -> SearchSplit<'a, SearchIndicesIgnoreAsciiCase<'a, P>> {
+        SearchSplit::new(
+            self.as_ref(),
+            SearchIndicesIgnoreAsciiCase::new(self.as_ref(), needle),
+            false,
+        )
+    }
+    #[inline]
+    fn search_lines<'a, P: SearchIn<'a>>(
+        &'a self,
+        needle: P,
+    ) -> SearchLines<'a, SearchIndices<'a, P>> {
+        SearchLines::new(self.as_ref(), SearchIndices::new(self.as_ref(), needle))
+    }
+    #[inline]
+    fn search_lines_ignore_ascii_case<'a, P: SearchIn<'a>>(
+        &'a self,
+        needle: P,
+    ) -> SearchLines<'a, SearchIndicesIgnoreAsciiCase<'a, P>> {
+        SearchLines::new(
+            self.as_ref(),
+            SearchIndicesIgnoreAsciiCase::new(self.as_ref(), needle),
+        )
+    }
+    //
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn search_replace<'a, P: SearchIn<'a>>(&'a self, needle: P, to: &str) -> Cow<'a, str> {
+        replace::replace(self.as_ref(), SearchIndices::new(self.as_ref(), needle), to)
+    }
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn search_replacen<'a, P: SearchIn<'a>>(
+        &'a self,
+        needle: P,
+        to: &str,
+        count: usize,
+    ) -> Cow<'a, str> {
+        replace::replace(
+            self.as_ref(),
+            SearchIndices::new(self.as_ref(), needle).take(count),
+            to,
+        )
+    }
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn search_replace_into<'a, P: SearchIn<'a>>(&'a self, needle: P, to: &str, dst: &mut String) {
+        replace::replace_into(
+            self.as_ref(),
+            SearchIndices::new(self.as_ref(), needle),
+            to,
+            dst,
+        )
+    }
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn search_replace_ignore_ascii_case<'a, P: SearchIn<'a>>(
+        &'a self,
+        needle: P,
+        to: &str,
+    ) -> Cow<'a, str> {
+        replace::replace(
+            self.as_ref(),
+            SearchIndicesIgnoreAsciiCase::new(self.as_ref(), needle),
+            to,
+        )
+    }
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn search_replacen_ignore_ascii_case<'a, P: SearchIn<'a>>(
+        &'a self,
+        needle: P,
+        to: &str,
+        count: usize,
+    ) -> Cow<'a, str> {
+        let matches = SearchIndicesIgnoreAsciiCase::new(self.as_ref(), needle).take(count);
+        replace::replace(self.as_ref(), matches, to)
+    }
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn search_replace_into_ignore_ascii_case<'a, P: SearchIn<'a>>(
+        &'a self,
+        needle: P,
+        to: &str,
+        dst: &mut String,
+    ) {
+        let matches = SearchIndicesIgnoreAsciiCase::new(self.as_ref(), needle);
+        replace::replace_into(self.as_ref(), matches, to, dst)
+    }
+}
+
+///
+/// search the needle in bytes
+///
+/// It is implemented for the types of `AsRef<[u8]>`, such as `&[u8]`, `[u8; N]`,
+/// `Vec<u8>`, `&str` and `String`.
+///
+pub trait SearchBytes {
+    fn search_bytes<'a, P: SearchInBytes<'a>>(&'a self, needle: P) -> Option<usize>;
+    fn rsearch_bytes<'a, P: SearchInBytes<'a>>(&'a self, needle: P) -> Option<usize>;
+    fn search_from_bytes<'a, P>(&'a self, needle: P, start: usize) -> Option<usize>
+    where
+        P: SearchInBytes<'a>;
+    fn rsearch_before_bytes<'a, P>(&'a self, needle: P, end: usize) -> Option<usize>
+    where
+        P: SearchInBytes<'a>;
+    fn search_in_range_bytes<'a, P, R>(&'a self, needle: P, range: R) -> Option<usize>
+    where
+        P: SearchInBytes<'a>,
+        R: RangeBounds<usize>;
+    fn search_bytes_with_frequency<B>(&self, needle: &B, freq: &ByteFrequency) -> Option<usize>
+    where
+        B: ?Sized + AsRef<[u8]>;
+    fn rsearch_bytes_with_frequency<B>(&self, needle: &B, freq: &ByteFrequency) -> Option<usize>
+    where
+        B: ?Sized + AsRef<[u8]>;
+    fn search_bytes_with_frequency_ignore_ascii_case<B>(
+        &self,
+        needle: &B,
+        freq: &ByteFrequency,
+    ) -> Option<usize>
+    where
+        B: ?Sized + AsRef<[u8]>;
+    fn rsearch_bytes_with_frequency_ignore_ascii_case<B>(
+        &self,
+        needle: &B,
+        freq: &ByteFrequency,
+    ) -> Option<usize>
+    where
+        B: ?Sized + AsRef<[u8]>;
+    fn search_indices_bytes<'a, P>(&'a self, needle: P) -> SearchIndicesBytes<'a, P>
+    where
+        P: SearchInBytes<'a>;
+    fn rsearch_indices_bytes<'a, P>(&'a self, needle: P) -> RevSearchIndicesBytes<'a, P>
+    where
+        P: SearchInBytes<'a>;
+    fn search_indices_overlapping_bytes<'a, P>(
+        &'a self,
+        needle: P,
+    ) -> SearchIndicesOverlappingBytes<'a, P>
+    where
+        P: SearchInBytes<'a>;
+    fn rsearch_indices_overlapping_bytes<'a, P>(
+        &'a self,
+        needle: P,
+    ) -> RevSearchIndicesOverlappingBytes<'a, P>
+    where
+        P: SearchInBytes<'a>;
+    fn search_count_bytes<'a, P: SearchInBytes<'a>>(&'a self, needle: P) -> usize;
+    fn search_count_overlapping_bytes<'a, P: SearchInBytes<'a>>(&'a self, needle: P) -> usize;
+    fn includes_bytes<'a, P: SearchInBytes<'a>>(&'a self, needle: P) -> bool;
+    //
+    fn search_bytes_ignore_ascii_case<'a, P: SearchInBytes<'a>>(
+        &'a self,
+        needle: P,
+    ) -> Option<usize>;
+    fn rsearch_bytes_ignore_ascii_case<'a, P: SearchInBytes<'a>>(
+        &'a self,
+        needle: P,
+    ) -> Option<usize>;
+    fn search_indices_bytes_ignore_ascii_case<'a, P>(
+        &'a self,
+        needle: P,
+    ) -> SearchIndicesBytesIgnoreAsciiCase<'a, P>
+    where
         P: SearchInBytes<'a>;
     fn rsearch_indices_bytes_ignore_ascii_case<'a, P>(
         &'a self,
@@ -336,34 +1275,312 @@ pub trait SearchBytes {
     ) -> RevSearchIndicesBytesIgnoreAsciiCase<'a, P>
     where
         P: SearchInBytes<'a>;
+    fn search_indices_overlapping_bytes_ignore_ascii_case<'a, P>(
+        &'a self,
+        needle: P,
+    ) -> SearchIndicesOverlappingBytesIgnoreAsciiCase<'a, P>
+    where
+        P: SearchInBytes<'a>;
+    fn rsearch_indices_overlapping_bytes_ignore_ascii_case<'a, P>(
+        &'a self,
+        needle: P,
+    ) -> RevSearchIndicesOverlappingBytesIgnoreAsciiCase<'a, P>
+    where
+        P: SearchInBytes<'a>;
+    fn search_count_bytes_ignore_ascii_case<'a, P: SearchInBytes<'a>>(&'a self, needle: P)
+        -> usize;
+    fn search_count_overlapping_bytes_ignore_ascii_case<'a, P: SearchInBytes<'a>>(
+        &'a self,
+        needle: P,
+    ) -> usize;
     fn includes_bytes_ignore_ascii_case<'a, P: SearchInBytes<'a>>(&'a self, needle: P) -> bool;
+    //
+    fn search_split_bytes<'a, P>(
+        &'a self,
+        needle: P,
+    ) -> SearchSplitBytes<'a, SearchIndicesBytes<'a, P>>
+    where
+        P: SearchInBytes<'a>;
+    fn search_rsplit_bytes<'a, P>(
+        &'a self,
+        needle: P,
+    ) -> SearchRSplitBytes<'a, RevSearchIndicesBytes<'a, P>>
+    where
+        P: SearchInBytes<'a>;
+    fn search_splitn_bytes<'a, P>(
+        &'a self,
+        n: usize,
+        needle: P,
+    ) -> SearchSplitNBytes<'a, SearchIndicesBytes<'a, P>>
+    where
+        P: SearchInBytes<'a>;
+    fn search_split_terminator_bytes<'a, P>(
+        &'a self,
+        needle: P,
+    ) -> SearchSplitBytes<'a, SearchIndicesBytes<'a, P>>
+    where
+        P: SearchInBytes<'a>;
+    fn search_split_bytes_ignore_ascii_case<'a, P>(
+        &'a self,
+        needle: P,
+    ) -> SearchSplitBytes<'a, SearchIndicesBytesIgnoreAsciiCase<'a, P>>
+    where
+        P: SearchInBytes<'a>;
+    fn search_rsplit_bytes_ignore_ascii_case<'a, P>(
+        &'a self,
+        needle: P,
+    ) -> SearchRSplitBytes<'a, RevSearchIndicesBytesIgnoreAsciiCase<'a, P>>
+    where
+        P: SearchInBytes<'a>;
+    fn search_splitn_bytes_ignore_ascii_case<'a, P>(
+        &'a self,
+        n: usize,
+        needle: P,
+    ) -> SearchSplitNBytes<'a, SearchIndicesBytesIgnoreAsciiCase<'a, P>>
+    where
+        P: SearchInBytes<'a>;
+    fn search_split_terminator_bytes_ignore_ascii_case<'a, P>(
+        &'a self,
+        needle: P,
+    ) -> SearchSplitBytes<'a, SearchIndicesBytesIgnoreAsciiCase<'a, P>>
+    where
+        P: SearchInBytes<'a>;
+    ///
+    /// An iterator over the matches of the needle in self, with their lines.
+    ///
+    /// Examples
+    ///
+    /// ```rust
+    /// use naive_opt::SearchBytes;
+    ///
+    /// let haystack: &[u8] = b"abc\r\n\xFFdef abc";
+    /// let v: Vec<_> = haystack
+    ///     .search_lines_bytes("abc")
+    ///     .map(|m| (m.line_no, m.column_byte, m.line))
+    ///     .collect();
+    /// assert_eq!(v, [(1, 0, &b"abc"[..]), (2, 5, &b"\xFFdef abc"[..])]);
+    /// ```
+    ///
+    fn search_lines_bytes<'a, P>(
+        &'a self,
+        needle: P,
+    ) -> SearchLinesBytes<'a, SearchIndicesBytes<'a, P>>
+    where
+        P: SearchInBytes<'a>;
+    ///
+    /// An iterator over the matches of the needle in self, with their lines,
+    /// ignore ascii case.
+    ///
+    fn search_lines_bytes_ignore_ascii_case<'a, P>(
+        &'a self,
+        needle: P,
+    ) -> SearchLinesBytes<'a, SearchIndicesBytesIgnoreAsciiCase<'a, P>>
+    where
+        P: SearchInBytes<'a>;
+    ///
+    /// search the whole word of the needle in self.
+    ///
+    /// return index of self, if it found the needle that the neighbors
+    /// are not the word characters. Otherwise return None.
+    /// An empty needle never matches.
+    ///
+    /// Examples
+    ///
+    /// ```rust
+    /// use naive_opt::{SearchBytes, WordChars};
+    ///
+    /// let haystack: &[u8] = b"width id\xFFid";
+    /// assert_eq!(haystack.search_word_bytes(b"id", WordChars::Ascii), Some(6));
+    /// assert_eq!(haystack.rsearch_word_bytes(b"id", WordChars::Ascii), Some(9));
+    /// ```
+    ///
+    fn search_word_bytes(&self, needle: &[u8], word: WordChars) -> Option<usize>;
+    ///
+    /// reverse search the whole word of the needle in self.
+    ///
+    /// return index of self, if it found the needle. Otherwise return None.
+    ///
+    fn rsearch_word_bytes(&self, needle: &[u8], word: WordChars) -> Option<usize>;
+    ///
+    /// An iterator over the whole word matches of the needle in self.
+    ///
+    fn search_indices_word_bytes<'a, 'n>(
+        &'a self,
+        needle: &'n [u8],
+        word: WordChars,
+    ) -> SearchIndicesWordBytes<'a, 'n>;
+    ///
+    /// search the whole word of the needle in self, ignore ascii case.
+    ///
+    /// return index of self, if it found the needle. Otherwise return None.
+    ///
+    fn search_word_bytes_ignore_ascii_case(&self, needle: &[u8], word: WordChars) -> Option<usize>;
+    ///
+    /// reverse search the whole word of the needle in self, ignore ascii case.
+    ///
+    /// return index of self, if it found the needle. Otherwise return None.
+    ///
+    fn rsearch_word_bytes_ignore_ascii_case(&self, needle: &[u8], word: WordChars)
+        -> Option<usize>;
+    ///
+    /// An iterator over the whole word matches of the needle in self, ignore ascii case.
+    ///
+    fn search_indices_word_bytes_ignore_ascii_case<'a, 'n>(
+        &'a self,
+        needle: &'n [u8],
+        word: WordChars,
+    ) -> SearchIndicesWordBytes<'a, 'n>;
+    //
+    #[cfg(feature = "alloc")]
+    fn search_replace_bytes<'a, P>(&'a self, needle: P, to: &[u8]) -> Cow<'a, [u8]>
+    where
+        P: SearchInBytes<'a>;
+    #[cfg(feature = "alloc")]
+    fn search_replacen_bytes<'a, P>(&'a self, needle: P, to: &[u8], count: usize) -> Cow<'a, [u8]>
+    where
+        P: SearchInBytes<'a>;
+    #[cfg(feature = "alloc")]
+    fn search_replace_into_bytes<'a, P>(&'a self, needle: P, to: &[u8], dst: &mut Vec<u8>)
+    where
+        P: SearchInBytes<'a>;
+    #[cfg(feature = "alloc")]
+    fn search_replace_bytes_ignore_ascii_case<'a, P>(
+        &'a self,
+        needle: P,
+        to: &[u8],
+    ) -> Cow<'a, [u8]>
+    where
+        P: SearchInBytes<'a>;
+    #[cfg(feature = "alloc")]
+    fn search_replacen_bytes_ignore_ascii_case<'a, P>(
+        &'a self,
+        needle: P,
+        to: &[u8],
+        count: usize,
+    ) -> Cow<'a, [u8]>
+    where
+        P: SearchInBytes<'a>;
+    #[cfg(feature = "alloc")]
+    fn search_replace_into_bytes_ignore_ascii_case<'a, P>(
+        &'a self,
+        needle: P,
+        to: &[u8],
+        dst: &mut Vec<u8>,
+    ) where
+        P: SearchInBytes<'a>;
 }
-impl SearchBytes for &[u8] {
+impl<T: AsRef<[u8]>> SearchBytes for T {
     #[inline]
     fn search_bytes<'a, P: SearchInBytes<'a>>(&'a self, needle: P) -> Option<usize> {
-        needle.search_in(self)
+        needle.search_in(self.as_ref())
     }
     #[inline]
     fn rsearch_bytes<'a, P: SearchInBytes<'a>>(&'a self, needle: P) -> Option<usize> {
-        needle.rsearch_in(self)
+        needle.rsearch_in(self.as_ref())
+    }
+    #[inline]
+    fn search_from_bytes<'a, P>(&'a self, needle: P, start: usize) -> Option<usize>
+    where
+        P: SearchInBytes<'a>,
+    {
+        range::search_from_bytes(self.as_ref(), needle, start)
+    }
+    #[inline]
+    fn rsearch_before_bytes<'a, P>(&'a self, needle: P, end: usize) -> Option<usize>
+    where
+        P: SearchInBytes<'a>,
+    {
+        range::rsearch_before_bytes(self.as_ref(), needle, end)
+    }
+    #[inline]
+    fn search_in_range_bytes<'a, P, R>(&'a self, needle: P, range: R) -> Option<usize>
+    where
+        P: SearchInBytes<'a>,
+        R: RangeBounds<usize>,
+    {
+        range::search_in_range_bytes(self.as_ref(), needle, range)
+    }
+    #[inline]
+    fn search_bytes_with_frequency<B>(&self, needle: &B, freq: &ByteFrequency) -> Option<usize>
+    where
+        B: ?Sized + AsRef<[u8]>,
+    {
+        frequency::search_with_frequency(self.as_ref(), needle.as_ref(), freq)
+    }
+    #[inline]
+    fn rsearch_bytes_with_frequency<B>(&self, needle: &B, freq: &ByteFrequency) -> Option<usize>
+    where
+        B: ?Sized + AsRef<[u8]>,
+    {
+        frequency::rsearch_with_frequency(self.as_ref(), needle.as_ref(), freq)
+    }
+    #[inline]
+    fn search_bytes_with_frequency_ignore_ascii_case<B>(
+        &self,
+        needle: &B,
+        freq: &ByteFrequency,
+    ) -> Option<usize>
+    where
+        B: ?Sized + AsRef<[u8]>,
+    {
+        frequency::search_with_frequency_iac(self.as_ref(), needle.as_ref(), freq)
+    }
+    #[inline]
+    fn rsearch_bytes_with_frequency_ignore_ascii_case<B>(
+        &self,
+        needle: &B,
+        freq: &ByteFrequency,
+    ) -> Option<usize>
+    where
+        B: ?Sized + AsRef<[u8]>,
+    {
+        frequency::rsearch_with_frequency_iac(self.as_ref(), needle.as_ref(), freq)
     }
     #[inline]
     fn search_indices_bytes<'a, P>(&'a self, needle: P) -> SearchIndicesBytes<'a, P>
     where
         P: SearchInBytes<'a>,
     {
-        SearchIndicesBytes::new(self, needle)
+        SearchIndicesBytes::new(self.as_ref(), needle)
     }
     #[inline]
     fn rsearch_indices_bytes<'a, P>(&'a self, needle: P) -> RevSearchIndicesBytes<'a, P>
     where
         P: SearchInBytes<'a>,
     {
-        RevSearchIndicesBytes::new(self, needle)
+        RevSearchIndicesBytes::new(self.as_ref(), needle)
+    }
+    #[inline]
+    fn search_indices_overlapping_bytes<'a, P>(
+        &'a self,
+        needle: P,
+    ) -> SearchIndicesOverlappingBytes<'a, P>
+    where
+        P: SearchInBytes<'a>,
+    {
+        SearchIndicesOverlappingBytes::new(self.as_ref(), needle)
+    }
+    #[inline]
+    fn rsearch_indices_overlapping_bytes<'a, P>(
+        &'a self,
+        needle: P,
+    ) -> RevSearchIndicesOverlappingBytes<'a, P>
+    where
+        P: SearchInBytes<'a>,
+    {
+        RevSearchIndicesOverlappingBytes::new(self.as_ref(), needle)
+    }
+    #[inline]
+    fn search_count_bytes<'a, P: SearchInBytes<'a>>(&'a self, needle: P) -> usize {
+        needle.count_in(self.as_ref())
+    }
+    #[inline]
+    fn search_count_overlapping_bytes<'a, P: SearchInBytes<'a>>(&'a self, needle: P) -> usize {
+        needle.count_in_overlapping(self.as_ref())
     }
     #[inline]
     fn includes_bytes<'a, P: SearchInBytes<'a>>(&'a self, needle: P) -> bool {
-        needle.includes_in(self)
+        needle.includes_in(self.as_ref())
     }
     //
     #[inline]
@@ -371,14 +1588,14 @@ impl SearchBytes for &[u8] {
         &'a self,
         needle: P,
     ) -> Option<usize> {
-        needle.search_in_ignore_ascii_case(self)
+        needle.search_in_ignore_ascii_case(self.as_ref())
     }
     #[inline]
     fn rsearch_bytes_ignore_ascii_case<'a, P: SearchInBytes<'a>>(
         &'a self,
         needle: P,
     ) -> Option<usize> {
-        needle.rsearch_in_ignore_ascii_case(self)
+        needle.rsearch_in_ignore_ascii_case(self.as_ref())
     }
     #[inline]
     fn search_indices_bytes_ignore_ascii_case<'a, P>(
@@ -388,7 +1605,7 @@ impl SearchBytes for &[u8] {
     where
         P: SearchInBytes<'a>,
     {
-        SearchIndicesBytesIgnoreAsciiCase::new(self, needle)
+        SearchIndicesBytesIgnoreAsciiCase::new(self.as_ref(), needle)
     }
     #[inline]
     fn rsearch_indices_bytes_ignore_ascii_case<'a, P>(
@@ -398,145 +1615,291 @@ impl SearchBytes for &[u8] {
     where
         P: SearchInBytes<'a>,
     {
-        RevSearchIndicesBytesIgnoreAsciiCase::new(self, needle)
+        RevSearchIndicesBytesIgnoreAsciiCase::new(self.as_ref(), needle)
+    }
+    #[inline]
+    fn search_indices_overlapping_bytes_ignore_ascii_case<'a, P>(
+        &'a self,
+        needle: P,
+    ) -> SearchIndicesOverlappingBytesIgnoreAsciiCase<'a, P>
+    where
+        P: SearchInBytes<'a>,
+    {
+        SearchIndicesOverlappingBytesIgnoreAsciiCase::new(self.as_ref(), needle)
+    }
+    #[inline]
+    fn rsearch_indices_overlapping_bytes_ignore_ascii_case<'a, P>(
+        &'a self,
+        needle: P,
+    ) -> RevSearchIndicesOverlappingBytesIgnoreAsciiCase<'a, P>
+    where
+        P: SearchInBytes<'a>,
+    {
+        RevSearchIndicesOverlappingBytesIgnoreAsciiCase::new(self.as_ref(), needle)
+    }
+    #[inline]
+    fn search_count_bytes_ignore_ascii_case<'a, P: SearchInBytes<'a>>(
+        &'a self,
+        needle: P,
+    ) -> usize {
+        needle.count_in_ignore_ascii_case(self.as_ref())
+    }
+    #[inline]
+    fn search_count_overlapping_bytes_ignore_ascii_case<'a, P: SearchInBytes<'a>>(
+        &'a self,
+        needle: P,
+    ) -> usize {
+        needle.count_in_overlapping_ignore_ascii_case(self.as_ref())
     }
     #[inline]
     fn includes_bytes_ignore_ascii_case<'a, P: SearchInBytes<'a>>(&'a self, needle: P) -> bool {
-        needle.includes_in_ignore_ascii_case(self)
+        needle.includes_in_ignore_ascii_case(self.as_ref())
     }
-}
-impl SearchBytes for &str {
+    //
     #[inline]
-    fn search_bytes<'a, P: SearchInBytes<'a>>(&'a self, needle: P) -> Option<usize> {
-        needle.search_in(self.as_bytes())
+    fn search_split_bytes<'a, P>(
+        &'a self,
+        needle: P,
+    ) -> SearchSplitBytes<'a, SearchIndicesBytes<'a, P>>
+    where
+        P: SearchInBytes<'a>,
+    {
+        SearchSplitBytes::new(
+            self.as_ref(),
+            SearchIndicesBytes::new(self.as_ref(), needle),
+            true,
+        )
     }
     #[inline]
-    fn rsearch_bytes<'a, P: SearchInBytes<'a>>(&'a self, needle: P) -> Option<usize> {
-        needle.rsearch_in(self.as_bytes())
+    fn search_rsplit_bytes<'a, P>(
+        &'a self,
+        needle: P,
+    ) -> SearchRSplitBytes<'a, RevSearchIndicesBytes<'a, P>>
+    where
+        P: SearchInBytes<'a>,
+    {
+        SearchRSplitBytes::new(
+            self.as_ref(),
+            RevSearchIndicesBytes::new(self.as_ref(), needle),
+        )
     }
     #[inline]
-    fn search_indices_bytes<'a, P>(&'a self, needle: P) -> SearchIndicesBytes<'a, P>
+    fn search_splitn_bytes<'a, P>(
+        &'a self,
+        n: usize,
+        needle: P,
+    ) -> SearchSplitNBytes<'a, SearchIndicesBytes<'a, P>>
     where
         P: SearchInBytes<'a>,
     {
-        SearchIndicesBytes::new(self.as_bytes(), needle)
+        SearchSplitNBytes::new(self.search_split_bytes(needle), n)
     }
     #[inline]
-    fn rsearch_indices_bytes<'a, P>(&'a self, needle: P) -> RevSearchIndicesBytes<'a, P>
+    fn search_split_terminator_bytes<'a, P>(
+        &'a self,
+        needle: P,
+    ) -> SearchSplitBytes<'a, SearchIndicesBytes<'a, P>>
     where
         P: SearchInBytes<'a>,
     {
-        RevSearchIndicesBytes::new(self.as_bytes(), needle)
+        SearchSplitBytes::new(
+            self.as_ref(),
+            SearchIndicesBytes::new(self.as_ref(), needle),
+            false,
+        )
     }
     #[inline]
-    fn includes_bytes<'a, P: SearchInBytes<'a>>(&'a self, needle: P) -> bool {
-        needle.includes_in(self.as_bytes())
+    fn search_split_bytes_ignore_ascii_case<'a, P>(
+        &'a self,
+        needle: P,
+    ) -> SearchSplitBytes<'a, SearchIndicesBytesIgnoreAsciiCase<'a, P>>
+    where
+        P: SearchInBytes<'a>,
+    {
+        SearchSplitBytes::new(
+            self.as_ref(),
+            SearchIndicesBytesIgnoreAsciiCase::new(self.as_ref(), needle),
+            true,
+        )
     }
-    //
     #[inline]
-    fn search_bytes_ignore_ascii_case<'a, P: SearchInBytes<'a>>(
+    fn search_rsplit_bytes_ignore_ascii_case<'a, P>(
         &'a self,
         needle: P,
-    ) -> Option<usize> {
-        needle.search_in_ignore_ascii_case(self.as_bytes())
+    ) -> SearchRSplitBytes<'a, RevSearchIndicesBytesIgnoreAsciiCase<'a, P>>
+    where
+        P: SearchInBytes<'a>,
+    {
+        SearchRSplitBytes::new(
+            self.as_ref(),
+            RevSearchIndicesBytesIgnoreAsciiCase::new(self.as_ref(), needle),
+        )
     }
     #[inline]
-    fn rsearch_bytes_ignore_ascii_case<'a, P: SearchInBytes<'a>>(
+    fn search_splitn_bytes_ignore_ascii_case<'a, P>(
         &'a self,
+        n: usize,
         needle: P,
-    ) -> Option<usize> {
-        needle.rsearch_in_ignore_ascii_case(self.as_bytes())
+    ) -> SearchSplitNBytes<'a, SearchIndicesBytesIgnoreAsciiCase<'a, P>>
+    where
+        P: SearchInBytes<'a>,
+    {
+        SearchSplitNBytes::new(self.search_split_bytes_ignore_ascii_case(needle), n)
     }
     #[inline]
-    fn search_indices_bytes_ignore_ascii_case<'a, P>(
+    fn search_split_terminator_bytes_ignore_ascii_case<'a, P>(
         &'a self,
         needle: P,
-    ) -> SearchIndicesBytesIgnoreAsciiCase<'a, P>
+    ) -> SearchSplitBytes<'a, SearchIndicesBytesIgnoreAsciiCase<'a, P>>
+    where
+        P: SearchInBytes<'a>,
+    {
+        SearchSplitBytes::new(
+            self.as_ref(),
+            SearchIndicesBytesIgnoreAsciiCase::new(self.as_ref(), needle),
+            false,
+        )
+    }
+    #[inline]
+    fn search_lines_bytes<'a, P>(
+        &'a self,
+        needle: P,
+    ) -> SearchLinesBytes<'a, SearchIndicesBytes<'a, P>>
+    where
+        P: SearchInBytes<'a>,
+    {
+        SearchLinesBytes::new(
+            self.as_ref(),
+            SearchIndicesBytes::new(self.as_ref(), needle),
+        )
+    }
+    #[inline]
+    fn search_lines_bytes_ignore_ascii_case<'a, P>(
+        &'a self,
+        needle: P,
+    ) -> SearchLinesBytes<'a, SearchIndicesBytesIgnoreAsciiCase<'a, P>>
     where
         P: SearchInBytes<'a>,
     {
-        SearchIndicesBytesIgnoreAsciiCase::new(self.as_bytes(), needle)
+        SearchLinesBytes::new(
+            self.as_ref(),
+            SearchIndicesBytesIgnoreAsciiCase::new(self.as_ref(), needle),
+        )
+    }
+    //
+    #[inline]
+    fn search_word_bytes(&self, needle: &[u8], word: WordChars) -> Option<usize> {
+        word::search_word_from(self.as_ref(), needle, 0, word, false)
+    }
+    #[inline]
+    fn rsearch_word_bytes(&self, needle: &[u8], word: WordChars) -> Option<usize> {
+        word::rsearch_word_before(self.as_ref(), needle, self.as_ref().len(), word, false)
     }
     #[inline]
-    fn rsearch_indices_bytes_ignore_ascii_case<'a, P>(
+    fn search_indices_word_bytes<'a, 'n>(
         &'a self,
-        needle: P,
-    ) -> RevSearchIndicesBytesIgnoreAsciiCase<'a, P>
-    where
-        P: SearchInBytes<'a>,
-    {
-        RevSearchIndicesBytesIgnoreAsciiCase::new(self.as_bytes(), needle)
+        needle: &'n [u8],
+        word: WordChars,
+    ) -> SearchIndicesWordBytes<'a, 'n> {
+        SearchIndicesWordBytes::new(self.as_ref(), needle, word, false)
     }
     #[inline]
-    fn includes_bytes_ignore_ascii_case<'a, P: SearchInBytes<'a>>(&'a self, needle: P) -> bool {
-        needle.includes_in_ignore_ascii_case(self.as_bytes())
+    fn search_word_bytes_ignore_ascii_case(&self, needle: &[u8], word: WordChars) -> Option<usize> {
+        word::search_word_from(self.as_ref(), needle, 0, word, true)
     }
-}
-impl SearchBytes for String {
     #[inline]
-    fn search_bytes<'a, P: SearchInBytes<'a>>(&'a self, needle: P) -> Option<usize> {
-        needle.search_in(self.as_bytes())
+    fn rsearch_word_bytes_ignore_ascii_case(
+        &self,
+        needle: &[u8],
+        word: WordChars,
+    ) -> Option<usize> {
+        word::rsearch_word_before(self.as_ref(), needle, self.as_ref().len(), word, true)
     }
     #[inline]
-    fn rsearch_bytes<'a, P: SearchInBytes<'a>>(&'a self, needle: P) -> Option<usize> {
-        needle.rsearch_in(self.as_bytes())
+    fn search_indices_word_bytes_ignore_ascii_case<'a, 'n>(
+        &'a self,
+        needle: &'n [u8],
+        word: WordChars,
+    ) -> SearchIndicesWordBytes<'a, 'n> {
+        SearchIndicesWordBytes::new(self.as_ref(), needle, word, true)
     }
+    //
+    #[cfg(feature = "alloc")]
     #[inline]
-    fn search_indices_bytes<'a, P>(&'a self, needle: P) -> SearchIndicesBytes<'a, P>
+    fn search_replace_bytes<'a, P>(&'a self, needle: P, to: &[u8]) -> Cow<'a, [u8]>
     where
         P: SearchInBytes<'a>,
     {
-        SearchIndicesBytes::new(self.as_bytes(), needle)
+        replace::replace_bytes(
+            self.as_ref(),
+            SearchIndicesBytes::new(self.as_ref(), needle),
+            to,
+        )
     }
+    #[cfg(feature = "alloc")]
     #[inline]
-    fn rsearch_indices_bytes<'a, P>(&'a self, needle: P) -> RevSearchIndicesBytes<'a, P>
+    fn search_replacen_bytes<'a, P>(&'a self, needle: P, to: &[u8], count: usize) -> Cow<'a, [u8]>
     where
         P: SearchInBytes<'a>,
     {
-        RevSearchIndicesBytes::new(self.as_bytes(), needle)
+        replace::replace_bytes(
+            self.as_ref(),
+            SearchIndicesBytes::new(self.as_ref(), needle).take(count),
+            to,
+        )
     }
+    #[cfg(feature = "alloc")]
     #[inline]
-    fn includes_bytes<'a, P: SearchInBytes<'a>>(&'a self, needle: P) -> bool {
-        needle.includes_in(self.as_bytes())
-    }
-    //
-    #[inline]
-    fn search_bytes_ignore_ascii_case<'a, P: SearchInBytes<'a>>(
-        &'a self,
-        needle: P,
-    ) -> Option<usize> {
-        needle.search_in_ignore_ascii_case(self.as_bytes())
+    fn search_replace_into_bytes<'a, P>(&'a self, needle: P, to: &[u8], dst: &mut Vec<u8>)
+    where
+        P: SearchInBytes<'a>,
+    {
+        replace::replace_into_bytes(
+            self.as_ref(),
+            SearchIndicesBytes::new(self.as_ref(), needle),
+            to,
+            dst,
+        )
     }
+    #[cfg(feature = "alloc")]
     #[inline]
-    fn rsearch_bytes_ignore_ascii_case<'a, P: SearchInBytes<'a>>(
+    fn search_replace_bytes_ignore_ascii_case<'a, P>(
         &'a self,
         needle: P,
-    ) -> Option<usize> {
-        needle.rsearch_in_ignore_ascii_case(self.as_bytes())
+        to: &[u8],
+    ) -> Cow<'a, [u8]>
+    where
+        P: SearchInBytes<'a>,
+    {
+        let matches = SearchIndicesBytesIgnoreAsciiCase::new(self.as_ref(), needle);
+        replace::replace_bytes(self.as_ref(), matches, to)
     }
+    #[cfg(feature = "alloc")]
     #[inline]
-    fn search_indices_bytes_ignore_ascii_case<'a, P>(
+    fn search_replacen_bytes_ignore_ascii_case<'a, P>(
         &'a self,
         needle: P,
-    ) -> SearchIndicesBytesIgnoreAsciiCase<'a, P>
+        to: &[u8],
+        count: usize,
+    ) -> Cow<'a, [u8]>
     where
         P: SearchInBytes<'a>,
     {
-        SearchIndicesBytesIgnoreAsciiCase::new(self.as_bytes(), needle)
+        let matches = SearchIndicesBytesIgnoreAsciiCase::new(self.as_ref(), needle).take(count);
+        replace::replace_bytes(self.as_ref(), matches, to)
     }
+    #[cfg(feature = "alloc")]
     #[inline]
-    fn rsearch_indices_bytes_ignore_ascii_case<'a, P>(
+    fn search_replace_into_bytes_ignore_ascii_case<'a, P>(
         &'a self,
         needle: P,
-    ) -> RevSearchIndicesBytesIgnoreAsciiCase<'a, P>
-    where
+        to: &[u8],
+        dst: &mut Vec<u8>,
+    ) where
         P: SearchInBytes<'a>,
     {
-        RevSearchIndicesBytesIgnoreAsciiCase::new(self.as_bytes(), needle)
-    }
-    #[inline]
-    fn includes_bytes_ignore_ascii_case<'a, P: SearchInBytes<'a>>(&'a self, needle: P) -> bool {
-        needle.includes_in_ignore_ascii_case(self.as_bytes())
+        let matches = SearchIndicesBytesIgnoreAsciiCase::new(self.as_ref(), needle);
+        replace::replace_into_bytes(self.as_ref(), matches, to, dst)
     }
 }
 
@@ -545,6 +1908,7 @@ impl SearchBytes for String {
 ///
 pub struct SearchIndices<'a, P: SearchIn<'a>> {
     curr_idx: usize,
+    curr_ed: usize,
     haystack: &'a str,
     needle: P,
 }
@@ -552,6 +1916,7 @@ impl<'a, P: SearchIn<'a>> SearchIndices<'a, P> {
     fn new(a_haystack: &'a str, a_needle: P) -> SearchIndices<'a, P> {
         SearchIndices {
             curr_idx: 0,
+            curr_ed: a_haystack.len(),
             haystack: a_haystack,
             needle: a_needle,
         }
@@ -561,14 +1926,16 @@ impl<'a, P: SearchIn<'a>> Iterator for SearchIndices<'a, P> {
     type Item = (usize, &'a str);
     #[inline(always)]
     fn next(&mut self) -> Option<Self::Item> {
-        if self.needle.is_empty() {
+        if self.needle.max_match_len() == 0 {
             return None;
         }
-        match self.needle.search_in(&self.haystack[self.curr_idx..]) {
+        match self
+            .needle
+            .search_in(&self.haystack[self.curr_idx..self.curr_ed])
+        {
             Some(idx) => {
-                self.curr_idx += idx;
-                let st = self.curr_idx;
-                let ed = st + self.needle.len();
+                let st = self.curr_idx + idx;
+                let ed = st + self.needle.match_len_at(self.haystack, st);
                 self.curr_idx = ed;
                 Some((st, &self.haystack[st..ed]))
             }
@@ -576,9 +1943,31 @@ impl<'a, P: SearchIn<'a>> Iterator for SearchIndices<'a, P> {
         }
     }
 }
+impl<'a, P: DoubleEndedSearchIn<'a>> DoubleEndedIterator for SearchIndices<'a, P> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.needle.max_match_len() == 0 {
+            return None;
+        }
+        match self
+            .needle
+            .rsearch_in(&self.haystack[self.curr_idx..self.curr_ed])
+        {
+            Some(idx) => {
+                let st = self.curr_idx + idx;
+                let ed = st + self.needle.match_len_at(self.haystack, st);
+                self.curr_ed = st;
+                Some((st, &self.haystack[st..ed]))
+            }
+            None => None,
+        }
+    }
+}
+impl<'a, P: SearchIn<'a>> FusedIterator for SearchIndices<'a, P> {}
 
 pub struct SearchIndicesBytes<'a, P: SearchInBytes<'a>> {
     curr_idx: usize,
+    curr_ed: usize,
     haystack: &'a [u8],
     needle: P,
 }
@@ -586,6 +1975,7 @@ impl<'a, P: SearchInBytes<'a>> SearchIndicesBytes<'a, P> {
     fn new(a_haystack: &'a [u8], a_needle: P) -> SearchIndicesBytes<'a, P> {
         SearchIndicesBytes {
             curr_idx: 0,
+            curr_ed: a_haystack.len(),
             haystack: a_haystack,
             needle: a_needle,
         }
@@ -595,14 +1985,16 @@ impl<'a, P: SearchInBytes<'a>> Iterator for SearchIndicesBytes<'a, P> {
     type Item = (usize, &'a [u8]);
     #[inline(always)]
     fn next(&mut self) -> Option<Self::Item> {
-        if self.needle.is_empty() {
+        if self.needle.max_match_len() == 0 {
             return None;
         }
-        match self.needle.search_in(&self.haystack[self.curr_idx..]) {
+        match self
+            .needle
+            .search_in(&self.haystack[self.curr_idx..self.curr_ed])
+        {
             Some(idx) => {
-                self.curr_idx += idx;
-                let st = self.curr_idx;
-                let ed = st + self.needle.len();
+                let st = self.curr_idx + idx;
+                let ed = st + self.needle.match_len_at(self.haystack, st);
                 self.curr_idx = ed;
                 Some((st, &self.haystack[st..ed]))
             }
@@ -610,11 +2002,33 @@ impl<'a, P: SearchInBytes<'a>> Iterator for SearchIndicesBytes<'a, P> {
         }
     }
 }
+impl<'a, P: DoubleEndedSearchInBytes<'a>> DoubleEndedIterator for SearchIndicesBytes<'a, P> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.needle.max_match_len() == 0 {
+            return None;
+        }
+        match self
+            .needle
+            .rsearch_in(&self.haystack[self.curr_idx..self.curr_ed])
+        {
+            Some(idx) => {
+                let st = self.curr_idx + idx;
+                let ed = st + self.needle.match_len_at(self.haystack, st);
+                self.curr_ed = st;
+                Some((st, &self.haystack[st..ed]))
+            }
+            None => None,
+        }
+    }
+}
+impl<'a, P: SearchInBytes<'a>> FusedIterator for SearchIndicesBytes<'a, P> {}
 
 ///
 /// Created with the method [Search::rsearch_indices()].
 ///
 pub struct RevSearchIndices<'a, P: SearchIn<'a>> {
+    curr_idx: usize,
     curr_ed: usize,
     haystack: &'a str,
     needle: P,
@@ -622,6 +2036,7 @@ pub struct RevSearchIndices<'a, P: SearchIn<'a>> {
 impl<'a, P: SearchIn<'a>> RevSearchIndices<'a, P> {
     fn new(a_haystack: &'a str, a_needle: P) -> RevSearchIndices<'a, P> {
         RevSearchIndices {
+            curr_idx: 0,
             curr_ed: a_haystack.len(),
             haystack: a_haystack,
             needle: a_needle,
@@ -632,13 +2047,16 @@ impl<'a, P: SearchIn<'a>> Iterator for RevSearchIndices<'a, P> {
     type Item = (usize, &'a str);
     #[inline(always)]
     fn next(&mut self) -> Option<Self::Item> {
-        if self.needle.is_empty() {
+        if self.needle.max_match_len() == 0 {
             return None;
         }
-        match self.needle.rsearch_in(&self.haystack[0..self.curr_ed]) {
+        match self
+            .needle
+            .rsearch_in(&self.haystack[self.curr_idx..self.curr_ed])
+        {
             Some(idx) => {
-                let st = idx;
-                let ed = st + self.needle.len();
+                let st = self.curr_idx + idx;
+                let ed = st + self.needle.match_len_at(self.haystack, st);
                 self.curr_ed = st;
                 Some((st, &self.haystack[st..ed]))
             }
@@ -646,8 +2064,30 @@ impl<'a, P: SearchIn<'a>> Iterator for RevSearchIndices<'a, P> {
         }
     }
 }
+impl<'a, P: DoubleEndedSearchIn<'a>> DoubleEndedIterator for RevSearchIndices<'a, P> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.needle.max_match_len() == 0 {
+            return None;
+        }
+        match self
+            .needle
+            .search_in(&self.haystack[self.curr_idx..self.curr_ed])
+        {
+            Some(idx) => {
+                let st = self.curr_idx + idx;
+                let ed = st + self.needle.match_len_at(self.haystack, st);
+                self.curr_idx = ed;
+                Some((st, &self.haystack[st..ed]))
+            }
+            None => None,
+        }
+    }
+}
+impl<'a, P: SearchIn<'a>> FusedIterator for RevSearchIndices<'a, P> {}
 
 pub struct RevSearchIndicesBytes<'a, P: SearchInBytes<'a>> {
+    curr_idx: usize,
     curr_ed: usize,
     haystack: &'a [u8],
     needle: P,
@@ -655,6 +2095,7 @@ pub struct RevSearchIndicesBytes<'a, P: SearchInBytes<'a>> {
 impl<'a, P: SearchInBytes<'a>> RevSearchIndicesBytes<'a, P> {
     fn new(a_haystack: &'a [u8], a_needle: P) -> RevSearchIndicesBytes<'a, P> {
         RevSearchIndicesBytes {
+            curr_idx: 0,
             curr_ed: a_haystack.len(),
             haystack: a_haystack,
             needle: a_needle,
@@ -665,13 +2106,16 @@ impl<'a, P: SearchInBytes<'a>> Iterator for RevSearchIndicesBytes<'a, P> {
     type Item = (usize, &'a [u8]);
     #[inline(always)]
     fn next(&mut self) -> Option<Self::Item> {
-        if self.needle.is_empty() {
+        if self.needle.max_match_len() == 0 {
             return None;
         }
-        match self.needle.rsearch_in(&self.haystack[0..self.curr_ed]) {
+        match self
+            .needle
+            .rsearch_in(&self.haystack[self.curr_idx..self.curr_ed])
+        {
             Some(idx) => {
-                let st = idx;
-                let ed = st + self.needle.len();
+                let st = self.curr_idx + idx;
+                let ed = st + self.needle.match_len_at(self.haystack, st);
                 self.curr_ed = st;
                 Some((st, &self.haystack[st..ed]))
             }
@@ -679,12 +2123,34 @@ impl<'a, P: SearchInBytes<'a>> Iterator for RevSearchIndicesBytes<'a, P> {
         }
     }
 }
+impl<'a, P: DoubleEndedSearchInBytes<'a>> DoubleEndedIterator for RevSearchIndicesBytes<'a, P> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.needle.max_match_len() == 0 {
+            return None;
+        }
+        match self
+            .needle
+            .search_in(&self.haystack[self.curr_idx..self.curr_ed])
+        {
+            Some(idx) => {
+                let st = self.curr_idx + idx;
+                let ed = st + self.needle.match_len_at(self.haystack, st);
+                self.curr_idx = ed;
+                Some((st, &self.haystack[st..ed]))
+            }
+            None => None,
+        }
+    }
+}
+impl<'a, P: SearchInBytes<'a>> FusedIterator for RevSearchIndicesBytes<'a, P> {}
 
 ///
 /// Created with the method [Search::search_indices_ignore_ascii_case()].
 ///
 pub struct SearchIndicesIgnoreAsciiCase<'a, P: SearchIn<'a>> {
     curr_idx: usize,
+    curr_ed: usize,
     haystack: &'a str,
     needle: P,
 }
@@ -692,6 +2158,7 @@ impl<'a, P: SearchIn<'a>> SearchIndicesIgnoreAsciiCase<'a, P> {
     fn new(a_haystack: &'a str, a_needle: P) -> SearchIndicesIgnoreAsciiCase<'a, P> {
         SearchIndicesIgnoreAsciiCase {
             curr_idx: 0,
+            curr_ed: a_haystack.len(),
             haystack: a_haystack,
             needle: a_needle,
         }
@@ -701,17 +2168,16 @@ impl<'a, P: SearchIn<'a>> Iterator for SearchIndicesIgnoreAsciiCase<'a, P> {
     type Item = (usize, &'a str);
     #[inline(always)]
     fn next(&mut self) -> Option<Self::Item> {
-        if self.needle.is_empty() {
+        if self.needle.max_match_len() == 0 {
             return None;
         }
         match self
             .needle
-            .search_in_ignore_ascii_case(&self.haystack[self.curr_idx..])
+            .search_in_ignore_ascii_case(&self.haystack[self.curr_idx..self.curr_ed])
         {
             Some(idx) => {
-                self.curr_idx += idx;
-                let st = self.curr_idx;
-                let ed = st + self.needle.len();
+                let st = self.curr_idx + idx;
+                let ed = st + self.needle.match_len_at(self.haystack, st);
                 self.curr_idx = ed;
                 Some((st, &self.haystack[st..ed]))
             }
@@ -719,9 +2185,31 @@ impl<'a, P: SearchIn<'a>> Iterator for SearchIndicesIgnoreAsciiCase<'a, P> {
         }
     }
 }
+impl<'a, P: DoubleEndedSearchIn<'a>> DoubleEndedIterator for SearchIndicesIgnoreAsciiCase<'a, P> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.needle.max_match_len() == 0 {
+            return None;
+        }
+        match self
+            .needle
+            .rsearch_in_ignore_ascii_case(&self.haystack[self.curr_idx..self.curr_ed])
+        {
+            Some(idx) => {
+                let st = self.curr_idx + idx;
+                let ed = st + self.needle.match_len_at(self.haystack, st);
+                self.curr_ed = st;
+                Some((st, &self.haystack[st..ed]))
+            }
+            None => None,
+        }
+    }
+}
+impl<'a, P: SearchIn<'a>> FusedIterator for SearchIndicesIgnoreAsciiCase<'a, P> {}
 
 pub struct SearchIndicesBytesIgnoreAsciiCase<'a, P: SearchInBytes<'a>> {
     curr_idx: usize,
+    curr_ed: usize,
     haystack: &'a [u8],
     needle: P,
 }
@@ -729,6 +2217,7 @@ impl<'a, P: SearchInBytes<'a>> SearchIndicesBytesIgnoreAsciiCase<'a, P> {
     fn new(a_haystack: &'a [u8], a_needle: P) -> SearchIndicesBytesIgnoreAsciiCase<'a, P> {
         SearchIndicesBytesIgnoreAsciiCase {
             curr_idx: 0,
+            curr_ed: a_haystack.len(),
             haystack: a_haystack,
             needle: a_needle,
         }
@@ -738,17 +2227,16 @@ impl<'a, P: SearchInBytes<'a>> Iterator for SearchIndicesBytesIgnoreAsciiCase<'a
     type Item = (usize, &'a [u8]);
     #[inline(always)]
     fn next(&mut self) -> Option<Self::Item> {
-        if self.needle.is_empty() {
+        if self.needle.max_match_len() == 0 {
             return None;
         }
         match self
             .needle
-            .search_in_ignore_ascii_case(&self.haystack[self.curr_idx..])
+            .search_in_ignore_ascii_case(&self.haystack[self.curr_idx..self.curr_ed])
         {
             Some(idx) => {
-                self.curr_idx += idx;
-                let st = self.curr_idx;
-                let ed = st + self.needle.len();
+                let st = self.curr_idx + idx;
+                let ed = st + self.needle.match_len_at(self.haystack, st);
                 self.curr_idx = ed;
                 Some((st, &self.haystack[st..ed]))
             }
@@ -756,11 +2244,35 @@ impl<'a, P: SearchInBytes<'a>> Iterator for SearchIndicesBytesIgnoreAsciiCase<'a
         }
     }
 }
+impl<'a, P: DoubleEndedSearchInBytes<'a>> DoubleEndedIterator
+    for SearchIndicesBytesIgnoreAsciiCase<'a, P>
+{
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.needle.max_match_len() == 0 {
+            return None;
+        }
+        match self
+            .needle
+            .rsearch_in_ignore_ascii_case(&self.haystack[self.curr_idx..self.curr_ed])
+        {
+            Some(idx) => {
+                let st = self.curr_idx + idx;
+                let ed = st + self.needle.match_len_at(self.haystack, st);
+                self.curr_ed = st;
+                Some((st, &self.haystack[st..ed]))
+            }
+            None => None,
+        }
+    }
+}
+impl<'a, P: SearchInBytes<'a>> FusedIterator for SearchIndicesBytesIgnoreAsciiCase<'a, P> {}
 
 ///
 /// Created with the method [Search::rsearch_indices_ignore_ascii_case()].
 ///
 pub struct RevSearchIndicesIgnoreAsciiCase<'a, P: SearchIn<'a>> {
+    curr_idx: usize,
     curr_ed: usize,
     haystack: &'a str,
     needle: P,
@@ -768,6 +2280,7 @@ pub struct RevSearchIndicesIgnoreAsciiCase<'a, P: SearchIn<'a>> {
 impl<'a, P: SearchIn<'a>> RevSearchIndicesIgnoreAsciiCase<'a, P> {
     fn new(a_haystack: &'a str, a_needle: P) -> RevSearchIndicesIgnoreAsciiCase<'a, P> {
         RevSearchIndicesIgnoreAsciiCase {
+            curr_idx: 0,
             curr_ed: a_haystack.len(),
             haystack: a_haystack,
             needle: a_needle,
@@ -778,16 +2291,16 @@ impl<'a, P: SearchIn<'a>> Iterator for RevSearchIndicesIgnoreAsciiCase<'a, P> {
     type Item = (usize, &'a str);
     #[inline(always)]
     fn next(&mut self) -> Option<Self::Item> {
-        if self.needle.is_empty() {
+        if self.needle.max_match_len() == 0 {
             return None;
         }
         match self
             .needle
-            .rsearch_in_ignore_ascii_case(&self.haystack[0..self.curr_ed])
+            .rsearch_in_ignore_ascii_case(&self.haystack[self.curr_idx..self.curr_ed])
         {
             Some(idx) => {
-                let st = idx;
-                let ed = st + self.needle.len();
+                let st = self.curr_idx + idx;
+                let ed = st + self.needle.match_len_at(self.haystack, st);
                 self.curr_ed = st;
                 Some((st, &self.haystack[st..ed]))
             }
@@ -795,8 +2308,32 @@ impl<'a, P: SearchIn<'a>> Iterator for RevSearchIndicesIgnoreAsciiCase<'a, P> {
         }
     }
 }
+impl<'a, P: DoubleEndedSearchIn<'a>> DoubleEndedIterator
+    for RevSearchIndicesIgnoreAsciiCase<'a, P>
+{
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.needle.max_match_len() == 0 {
+            return None;
+        }
+        match self
+            .needle
+            .search_in_ignore_ascii_case(&self.haystack[self.curr_idx..self.curr_ed])
+        {
+            Some(idx) => {
+                let st = self.curr_idx + idx;
+                let ed = st + self.needle.match_len_at(self.haystack, st);
+                self.curr_idx = ed;
+                Some((st, &self.haystack[st..ed]))
+            }
+            None => None,
+        }
+    }
+}
+impl<'a, P: SearchIn<'a>> FusedIterator for RevSearchIndicesIgnoreAsciiCase<'a, P> {}
 
 pub struct RevSearchIndicesBytesIgnoreAsciiCase<'a, P: SearchInBytes<'a>> {
+    curr_idx: usize,
     curr_ed: usize,
     haystack: &'a [u8],
     needle: P,
@@ -804,6 +2341,7 @@ pub struct RevSearchIndicesBytesIgnoreAsciiCase<'a, P: SearchInBytes<'a>> {
 impl<'a, P: SearchInBytes<'a>> RevSearchIndicesBytesIgnoreAsciiCase<'a, P> {
     fn new(a_haystack: &'a [u8], a_needle: P) -> RevSearchIndicesBytesIgnoreAsciiCase<'a, P> {
         RevSearchIndicesBytesIgnoreAsciiCase {
+            curr_idx: 0,
             curr_ed: a_haystack.len(),
             haystack: a_haystack,
             needle: a_needle,
@@ -814,16 +2352,16 @@ impl<'a, P: SearchInBytes<'a>> Iterator for RevSearchIndicesBytesIgnoreAsciiCase
     type Item = (usize, &'a [u8]);
     #[inline(always)]
     fn next(&mut self) -> Option<Self::Item> {
-        if self.needle.is_empty() {
+        if self.needle.max_match_len() == 0 {
             return None;
         }
         match self
             .needle
-            .rsearch_in_ignore_ascii_case(&self.haystack[0..self.curr_ed])
+            .rsearch_in_ignore_ascii_case(&self.haystack[self.curr_idx..self.curr_ed])
         {
             Some(idx) => {
-                let st = idx;
-                let ed = st + self.needle.len();
+                let st = self.curr_idx + idx;
+                let ed = st + self.needle.match_len_at(self.haystack, st);
                 self.curr_ed = st;
                 Some((st, &self.haystack[st..ed]))
             }
@@ -831,12 +2369,57 @@ impl<'a, P: SearchInBytes<'a>> Iterator for RevSearchIndicesBytesIgnoreAsciiCase
         }
     }
 }
+impl<'a, P: DoubleEndedSearchInBytes<'a>> DoubleEndedIterator
+    for RevSearchIndicesBytesIgnoreAsciiCase<'a, P>
+{
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.needle.max_match_len() == 0 {
+            return None;
+        }
+        match self
+            .needle
+            .search_in_ignore_ascii_case(&self.haystack[self.curr_idx..self.curr_ed])
+        {
+            Some(idx) => {
+                let st = self.curr_idx + idx;
+                let ed = st + self.needle.match_len_at(self.haystack, st);
+                self.curr_idx = ed;
+                Some((st, &self.haystack[st..ed]))
+            }
+            None => None,
+        }
+    }
+}
+impl<'a, P: SearchInBytes<'a>> FusedIterator for RevSearchIndicesBytesIgnoreAsciiCase<'a, P> {}
 
 ///
 /// search in the haystack
 ///
 /// return index of the haystack, if it found the needle. Otherwise return None.
 ///
+/// It is implemented for `&str`, `&String`, the references of `Box<str>`, `Rc<str>`,
+/// `Arc<str>` and `Cow<str>`, `char`, the set of chars (`&[char]`, `[char; N]`
+/// and `&[char; N]`) and the predicate `Fn(char) -> bool`.
+///
+/// The predicate is `Fn`, not `FnMut` as the std library accepts, because
+/// the needle is searched by `&self` and is called again by the iterators,
+/// from the front and from the back.
+///
+/// Examples
+///
+/// ```rust
+/// use naive_opt::Search;
+///
+/// let haystack = "key=value;next|last";
+/// assert_eq!(haystack.search([',', ';', '|']), Some(9));
+/// assert_eq!(haystack.rsearch([',', ';', '|']), Some(14));
+/// assert_eq!(haystack.search(char::is_uppercase), None);
+///
+/// let v: Vec<_> = haystack.search_split([';', '|']).collect();
+/// assert_eq!(v, ["key=value", "next", "last"]);
+/// ```
+///
 pub trait SearchIn<'a>: Sized {
     ///
     /// search self in the haystack.
@@ -863,14 +2446,21 @@ pub trait SearchIn<'a>: Sized {
     ///
     fn rsearch_in_ignore_ascii_case(&self, haystack: &'a str) -> Option<usize>;
     ///
-    /// return the length of self
+    /// return the maximum length of the match of self, in bytes.
+    ///
+    /// The empty self, that never matches, returns 0. It is not named `len()`,
+    /// so that it does not shadow the length of the char array.
     ///
-    fn len(&self) -> usize;
+    fn max_match_len(&self) -> usize;
     ///
-    /// true if a length of 0.
+    /// return the length of the match of self at the index of the haystack.
     ///
-    fn is_empty(&self) -> bool {
-        self.len() == 0
+    /// The default is [SearchIn::max_match_len()]. The set of chars and the predicate
+    /// override this, because the length of the matched char varies.
+    ///
+    #[inline]
+    fn match_len_at(&self, _haystack: &'a str, _idx: usize) -> usize {
+        self.max_match_len()
     }
     ///
     /// includes self in the haystack.
@@ -892,7 +2482,86 @@ pub trait SearchIn<'a>: Sized {
     fn includes_in_ignore_ascii_case(&self, haystack: &'a str) -> bool {
         self.search_in_ignore_ascii_case(haystack).is_some()
     }
+    ///
+    /// count the matches of self in the haystack.
+    ///
+    /// The default is by the repeated searches. The empty self counts 0.
+    ///
+    #[inline]
+    fn count_in(&self, haystack: &'a str) -> usize {
+        count::count_in(self, haystack, false, false)
+    }
+    ///
+    /// count the overlapping matches of self in the haystack.
+    ///
+    #[inline]
+    fn count_in_overlapping(&self, haystack: &'a str) -> usize {
+        count::count_in(self, haystack, true, false)
+    }
+    ///
+    /// count the matches of self in the haystack, ignore ascii case.
+    ///
+    #[inline]
+    fn count_in_ignore_ascii_case(&self, haystack: &'a str) -> usize {
+        count::count_in(self, haystack, false, true)
+    }
+    ///
+    /// count the overlapping matches of self in the haystack, ignore ascii case.
+    ///
+    #[inline]
+    fn count_in_overlapping_ignore_ascii_case(&self, haystack: &'a str) -> usize {
+        count::count_in(self, haystack, true, true)
+    }
 }
+///
+/// The needle, that the matches from the front and from the back are the same.
+///
+/// The match iterators, such as [SearchIndices], are `DoubleEndedIterator`
+/// only for this needle, the same as `std::str::pattern::DoubleEndedSearcher`.
+/// It is implemented for the needles that match a single char, such as `char`,
+/// the set of chars and the predicate.
+///
+/// The substring needle is not, because the overlapping matches of it, such as
+/// `"aa"` in `"aaa"`, are different from the front and from the back.
+/// Use [Search::rsearch_indices()] for the reverse order.
+///
+/// Examples
+///
+/// ```rust
+/// use naive_opt::Search;
+///
+/// let v: Vec<_> = "a,b;c".search_indices([',', ';']).rev().collect();
+/// assert_eq!(v, [(3, ";"), (1, ",")]);
+/// ```
+///
+/// This trait is sealed, and it can not be implemented outside of this crate.
+///
+/// ```rust,compile_fail
+/// use naive_opt::{DoubleEndedSearchIn, SearchIn};
+///
+/// struct Dot;
+/// impl<'a> SearchIn<'a> for Dot {
+///     fn search_in(&self, haystack: &'a str) -> Option<usize> {
+///         haystack.find('.')
+///     }
+///     fn rsearch_in(&self, haystack: &'a str) -> Option<usize> {
+///         haystack.rfind('.')
+///     }
+///     fn search_in_ignore_ascii_case(&self, haystack: &'a str) -> Option<usize> {
+///         haystack.find('.')
+///     }
+///     fn rsearch_in_ignore_ascii_case(&self, haystack: &'a str) -> Option<usize> {
+///         haystack.rfind('.')
+///     }
+///     fn max_match_len(&self) -> usize {
+///         1
+///     }
+/// }
+/// impl<'a> DoubleEndedSearchIn<'a> for Dot {}
+/// ```
+///
+pub trait DoubleEndedSearchIn<'a>: SearchIn<'a> + sealed::DoubleEnded {}
+
 impl<'a> SearchIn<'a> for &str {
     #[inline]
     fn search_in(&self, haystack: &'a str) -> Option<usize> {
@@ -911,11 +2580,28 @@ impl<'a> SearchIn<'a> for &str {
         naive_opt_mc_rev_bytes_iac(haystack.as_bytes(), self.as_bytes())
     }
     #[inline]
-    fn len(&self) -> usize {
+    fn count_in(&self, haystack: &'a str) -> usize {
+        naive_opt_mc_count_bytes(haystack.as_bytes(), self.as_bytes(), false)
+    }
+    #[inline]
+    fn count_in_overlapping(&self, haystack: &'a str) -> usize {
+        naive_opt_mc_count_bytes(haystack.as_bytes(), self.as_bytes(), true)
+    }
+    #[inline]
+    fn count_in_ignore_ascii_case(&self, haystack: &'a str) -> usize {
+        naive_opt_mc_count_bytes_iac(haystack.as_bytes(), self.as_bytes(), false)
+    }
+    #[inline]
+    fn count_in_overlapping_ignore_ascii_case(&self, haystack: &'a str) -> usize {
+        naive_opt_mc_count_bytes_iac(haystack.as_bytes(), self.as_bytes(), true)
+    }
+    #[inline]
+    fn max_match_len(&self) -> usize {
         #[allow(clippy::needless_as_bytes)]
         self.as_bytes().len()
     }
 }
+#[cfg(feature = "alloc")]
 impl<'a> SearchIn<'a> for &String {
     #[inline]
     fn search_in(&self, haystack: &'a str) -> Option<usize> {
@@ -934,7 +2620,23 @@ impl<'a> SearchIn<'a> for &String {
         naive_opt_mc_rev_bytes_iac(haystack.as_bytes(), self.as_str().as_bytes())
     }
     #[inline]
-    fn len(&self) -> usize {
+    fn count_in(&self, haystack: &'a str) -> usize {
+        naive_opt_mc_count_bytes(haystack.as_bytes(), self.as_str().as_bytes(), false)
+    }
+    #[inline]
+    fn count_in_overlapping(&self, haystack: &'a str) -> usize {
+        naive_opt_mc_count_bytes(haystack.as_bytes(), self.as_str().as_bytes(), true)
+    }
+    #[inline]
+    fn count_in_ignore_ascii_case(&self, haystack: &'a str) -> usize {
+        naive_opt_mc_count_bytes_iac(haystack.as_bytes(), self.as_str().as_bytes(), false)
+    }
+    #[inline]
+    fn count_in_overlapping_ignore_ascii_case(&self, haystack: &'a str) -> usize {
+        naive_opt_mc_count_bytes_iac(haystack.as_bytes(), self.as_str().as_bytes(), true)
+    }
+    #[inline]
+    fn max_match_len(&self) -> usize {
         self.as_str().len()
     }
 }
@@ -964,19 +2666,46 @@ impl<'a> SearchIn<'a> for char {
         naive_opt_mc_rev_bytes_iac(haystack.as_bytes(), nee_bytes)
     }
     #[inline]
-    fn len(&self) -> usize {
+    fn count_in(&self, haystack: &'a str) -> usize {
+        let mut buf = [0u8; 4];
+        let nee_bytes = self.encode_utf8(&mut buf).as_bytes();
+        naive_opt_mc_count_bytes(haystack.as_bytes(), nee_bytes, false)
+    }
+    #[inline]
+    fn count_in_overlapping(&self, haystack: &'a str) -> usize {
+        let mut buf = [0u8; 4];
+        let nee_bytes = self.encode_utf8(&mut buf).as_bytes();
+        naive_opt_mc_count_bytes(haystack.as_bytes(), nee_bytes, true)
+    }
+    #[inline]
+    fn count_in_ignore_ascii_case(&self, haystack: &'a str) -> usize {
+        let mut buf = [0u8; 4];
+        let nee_bytes = self.encode_utf8(&mut buf).as_bytes();
+        naive_opt_mc_count_bytes_iac(haystack.as_bytes(), nee_bytes, false)
+    }
+    #[inline]
+    fn count_in_overlapping_ignore_ascii_case(&self, haystack: &'a str) -> usize {
+        let mut buf = [0u8; 4];
+        let nee_bytes = self.encode_utf8(&mut buf).as_bytes();
+        naive_opt_mc_count_bytes_iac(haystack.as_bytes(), nee_bytes, true)
+    }
+    #[inline]
+    fn max_match_len(&self) -> usize {
         self.len_utf8()
     }
 }
+impl<'a> DoubleEndedSearchIn<'a> for char {}
+impl sealed::DoubleEnded for char {}
 
 pub trait SearchInBytes<'a>: Sized {
     fn search_in(&self, haystack: &'a [u8]) -> Option<usize>;
     fn rsearch_in(&self, haystack: &'a [u8]) -> Option<usize>;
     fn search_in_ignore_ascii_case(&self, haystack: &'a [u8]) -> Option<usize>;
     fn rsearch_in_ignore_ascii_case(&self, haystack: &'a [u8]) -> Option<usize>;
-    fn len(&self) -> usize;
-    fn is_empty(&self) -> bool {
-        self.len() == 0
+    fn max_match_len(&self) -> usize;
+    #[inline]
+    fn match_len_at(&self, _haystack: &'a [u8], _idx: usize) -> usize {
+        self.max_match_len()
     }
     #[inline]
     fn includes_in(&self, haystack: &'a [u8]) -> bool {
@@ -986,7 +2715,40 @@ pub trait SearchInBytes<'a>: Sized {
     fn includes_in_ignore_ascii_case(&self, haystack: &'a [u8]) -> bool {
         self.search_in_ignore_ascii_case(haystack).is_some()
     }
+    #[inline]
+    fn count_in(&self, haystack: &'a [u8]) -> usize {
+        count::count_in_bytes(self, haystack, false, false)
+    }
+    #[inline]
+    fn count_in_overlapping(&self, haystack: &'a [u8]) -> usize {
+        count::count_in_bytes(self, haystack, true, false)
+    }
+    #[inline]
+    fn count_in_ignore_ascii_case(&self, haystack: &'a [u8]) -> usize {
+        count::count_in_bytes(self, haystack, false, true)
+    }
+    #[inline]
+    fn count_in_overlapping_ignore_ascii_case(&self, haystack: &'a [u8]) -> usize {
+        count::count_in_bytes(self, haystack, true, true)
+    }
+}
+///
+/// The needle of the bytes, that the matches from the front and from the back are the same.
+///
+/// It is implemented for the needles that match a single char or a single byte,
+/// such as `char`, the set of chars, [ByteSet] and the predicate.
+/// See [DoubleEndedSearchIn].
+///
+/// This trait is sealed, and it can not be implemented outside of this crate.
+///
+pub trait DoubleEndedSearchInBytes<'a>: SearchInBytes<'a> + sealed::DoubleEndedBytes {}
+
+// The private supertraits, that seal the public marker traits.
+mod sealed {
+    pub trait DoubleEnded {}
+    pub trait DoubleEndedBytes {}
 }
+
 impl<'a> SearchInBytes<'a> for &[u8] {
     #[inline]
     fn search_in(&self, haystack: &'a [u8]) -> Option<usize> {
@@ -1005,7 +2767,23 @@ impl<'a> SearchInBytes<'a> for &[u8] {
         naive_opt_mc_rev_bytes_iac(haystack, self)
     }
     #[inline]
-    fn len(&self) -> usize {
+    fn count_in(&self, haystack: &'a [u8]) -> usize {
+        naive_opt_mc_count_bytes(haystack, self, false)
+    }
+    #[inline]
+    fn count_in_overlapping(&self, haystack: &'a [u8]) -> usize {
+        naive_opt_mc_count_bytes(haystack, self, true)
+    }
+    #[inline]
+    fn count_in_ignore_ascii_case(&self, haystack: &'a [u8]) -> usize {
+        naive_opt_mc_count_bytes_iac(haystack, self, false)
+    }
+    #[inline]
+    fn count_in_overlapping_ignore_ascii_case(&self, haystack: &'a [u8]) -> usize {
+        naive_opt_mc_count_bytes_iac(haystack, self, true)
+    }
+    #[inline]
+    fn max_match_len(&self) -> usize {
         u8_len(self)
     }
 }
@@ -1031,11 +2809,28 @@ impl<'a> SearchInBytes<'a> for &str {
         naive_opt_mc_rev_bytes_iac(haystack, self.as_bytes())
     }
     #[inline]
-    fn len(&self) -> usize {
+    fn count_in(&self, haystack: &'a [u8]) -> usize {
+        naive_opt_mc_count_bytes(haystack, self.as_bytes(), false)
+    }
+    #[inline]
+    fn count_in_overlapping(&self, haystack: &'a [u8]) -> usize {
+        naive_opt_mc_count_bytes(haystack, self.as_bytes(), true)
+    }
+    #[inline]
+    fn count_in_ignore_ascii_case(&self, haystack: &'a [u8]) -> usize {
+        naive_opt_mc_count_bytes_iac(haystack, self.as_bytes(), false)
+    }
+    #[inline]
+    fn count_in_overlapping_ignore_ascii_case(&self, haystack: &'a [u8]) -> usize {
+        naive_opt_mc_count_bytes_iac(haystack, self.as_bytes(), true)
+    }
+    #[inline]
+    fn max_match_len(&self) -> usize {
         #[allow(clippy::needless_as_bytes)]
         self.as_bytes().len()
     }
 }
+#[cfg(feature = "alloc")]
 impl<'a> SearchInBytes<'a> for &String {
     #[inline]
     fn search_in(&self, haystack: &'a [u8]) -> Option<usize> {
@@ -1054,7 +2849,23 @@ impl<'a> SearchInBytes<'a> for &String {
         naive_opt_mc_rev_bytes_iac(haystack, self.as_bytes())
     }
     #[inline]
-    fn len(&self) -> usize {
+    fn count_in(&self, haystack: &'a [u8]) -> usize {
+        naive_opt_mc_count_bytes(haystack, self.as_bytes(), false)
+    }
+    #[inline]
+    fn count_in_overlapping(&self, haystack: &'a [u8]) -> usize {
+        naive_opt_mc_count_bytes(haystack, self.as_bytes(), true)
+    }
+    #[inline]
+    fn count_in_ignore_ascii_case(&self, haystack: &'a [u8]) -> usize {
+        naive_opt_mc_count_bytes_iac(haystack, self.as_bytes(), false)
+    }
+    #[inline]
+    fn count_in_overlapping_ignore_ascii_case(&self, haystack: &'a [u8]) -> usize {
+        naive_opt_mc_count_bytes_iac(haystack, self.as_bytes(), true)
+    }
+    #[inline]
+    fn max_match_len(&self) -> usize {
         self.as_str().len()
     }
 }
@@ -1084,10 +2895,36 @@ impl<'a> SearchInBytes<'a> for char {
         naive_opt_mc_rev_bytes_iac(haystack, nee_bytes)
     }
     #[inline]
-    fn len(&self) -> usize {
+    fn count_in(&self, haystack: &'a [u8]) -> usize {
+        let mut buf = [0u8; 4];
+        let nee_bytes = self.encode_utf8(&mut buf).as_bytes();
+        naive_opt_mc_count_bytes(haystack, nee_bytes, false)
+    }
+    #[inline]
+    fn count_in_overlapping(&self, haystack: &'a [u8]) -> usize {
+        let mut buf = [0u8; 4];
+        let nee_bytes = self.encode_utf8(&mut buf).as_bytes();
+        naive_opt_mc_count_bytes(haystack, nee_bytes, true)
+    }
+    #[inline]
+    fn count_in_ignore_ascii_case(&self, haystack: &'a [u8]) -> usize {
+        let mut buf = [0u8; 4];
+        let nee_bytes = self.encode_utf8(&mut buf).as_bytes();
+        naive_opt_mc_count_bytes_iac(haystack, nee_bytes, false)
+    }
+    #[inline]
+    fn count_in_overlapping_ignore_ascii_case(&self, haystack: &'a [u8]) -> usize {
+        let mut buf = [0u8; 4];
+        let nee_bytes = self.encode_utf8(&mut buf).as_bytes();
+        naive_opt_mc_count_bytes_iac(haystack, nee_bytes, true)
+    }
+    #[inline]
+    fn max_match_len(&self) -> usize {
         self.len_utf8()
     }
 }
+impl<'a> DoubleEndedSearchInBytes<'a> for char {}
+impl sealed::DoubleEndedBytes for char {}
 
 ///
 /// search the needle in the haystack
@@ -1183,6 +3020,24 @@ pub fn string_rsearch_bytes_ignore_ascii_case(haystack: &[u8], needle: &[u8]) ->
     naive_opt_mc_rev_bytes_iac(haystack, needle)
 }
 
+///
+/// search the needle in the haystack, ignore unicode case.
+///
+/// return index of the haystack, if it found the needle. Otherwise return None.
+///
+pub fn string_search_ignore_case(haystack: &str, needle: &str) -> Option<usize> {
+    ignore_case::search_ignore_case(haystack, needle).map(|(idx, _)| idx)
+}
+
+///
+/// reverse search the needle in the haystack, ignore unicode case.
+///
+/// return index of the haystack, if it found the needle. Otherwise return None.
+///
+pub fn string_rsearch_ignore_case(haystack: &str, needle: &str) -> Option<usize> {
+    ignore_case::rsearch_ignore_case(haystack, needle).map(|(idx, _)| idx)
+}
+
 ///
 /// An iterator over the matches of the needle in the haystack.
 ///
@@ -1274,6 +3129,186 @@ pub fn string_rsearch_indices_bytes_ignore_ascii_case<'a, P: SearchInBytes<'a>>(
 ) -> RevSearchIndicesBytesIgnoreAsciiCase<'a, P> {
     RevSearchIndicesBytesIgnoreAsciiCase::new(haystack, needle)
 }
+
+///
+/// An iterator over the matches of the needle in the haystack, ignore unicode case.
+///
+/// Examples
+///
+/// ```rust
+/// use naive_opt::string_search_indices_ignore_case;
+///
+/// let v: Vec<_> = string_search_indices_ignore_case("straße STRASSE", "strasse").collect();
+/// assert_eq!(v, [(0, "straße"), (8, "STRASSE")]);
+/// ```
+///
+pub fn string_search_indices_ignore_case<'a, 'n>(
+    haystack: &'a str,
+    needle: &'n str,
+) -> SearchIndicesIgnoreCase<'a, 'n> {
+    SearchIndicesIgnoreCase::new(haystack, needle)
+}
+
+///
+/// An reverse search iterator over the matches of the needle in the haystack,
+/// ignore unicode case.
+///
+pub fn string_rsearch_indices_ignore_case<'a, 'n>(
+    haystack: &'a str,
+    needle: &'n str,
+) -> RevSearchIndicesIgnoreCase<'a, 'n> {
+    RevSearchIndicesIgnoreCase::new(haystack, needle)
+}
+
+///
+/// An iterator over the overlapping matches of the needle in the haystack.
+///
+/// Examples
+///
+/// ```rust
+/// use naive_opt::string_search_indices_overlapping;
+///
+/// let v: Vec<_> = string_search_indices_overlapping("ababa", "aba").collect();
+/// assert_eq!(v, [(0, "aba"), (2, "aba")]);
+/// ```
+///
+pub fn string_search_indices_overlapping<'a, P: SearchIn<'a>>(
+    haystack: &'a str,
+    needle: P,
+) -> SearchIndicesOverlapping<'a, P> {
+    SearchIndicesOverlapping::new(haystack, needle)
+}
+
+pub fn string_search_indices_overlapping_bytes<'a, P: SearchInBytes<'a>>(
+    haystack: &'a [u8],
+    needle: P,
+) -> SearchIndicesOverlappingBytes<'a, P> {
+    SearchIndicesOverlappingBytes::new(haystack, needle)
+}
+
+///
+/// An reverse search iterator over the overlapping matches of the needle in the haystack.
+///
+/// Examples
+///
+/// ```rust
+/// use naive_opt::string_rsearch_indices_overlapping;
+///
+/// let v: Vec<_> = string_rsearch_indices_overlapping("ababa", "aba").collect();
+/// assert_eq!(v, [(2, "aba"), (0, "aba")]);
+/// ```
+///
+pub fn string_rsearch_indices_overlapping<'a, P: SearchIn<'a>>(
+    haystack: &'a str,
+    needle: P,
+) -> RevSearchIndicesOverlapping<'a, P> {
+    RevSearchIndicesOverlapping::new(haystack, needle)
+}
+
+pub fn string_rsearch_indices_overlapping_bytes<'a, P: SearchInBytes<'a>>(
+    haystack: &'a [u8],
+    needle: P,
+) -> RevSearchIndicesOverlappingBytes<'a, P> {
+    RevSearchIndicesOverlappingBytes::new(haystack, needle)
+}
+
+pub fn string_search_indices_overlapping_ignore_ascii_case<'a, P: SearchIn<'a>>(
+    haystack: &'a str,
+    needle: P,
+) -> SearchIndicesOverlappingIgnoreAsciiCase<'a, P> {
+    SearchIndicesOverlappingIgnoreAsciiCase::new(haystack, needle)
+}
+
+pub fn string_search_indices_overlapping_bytes_ignore_ascii_case<'a, P: SearchInBytes<'a>>(
+    haystack: &'a [u8],
+    needle: P,
+) -> SearchIndicesOverlappingBytesIgnoreAsciiCase<'a, P> {
+    SearchIndicesOverlappingBytesIgnoreAsciiCase::new(haystack, needle)
+}
+
+pub fn string_rsearch_indices_overlapping_ignore_ascii_case<'a, P: SearchIn<'a>>(
+    haystack: &'a str,
+    needle: P,
+) -> RevSearchIndicesOverlappingIgnoreAsciiCase<'a, P> {
+    RevSearchIndicesOverlappingIgnoreAsciiCase::new(haystack, needle)
+}
+
+pub fn string_rsearch_indices_overlapping_bytes_ignore_ascii_case<'a, P: SearchInBytes<'a>>(
+    haystack: &'a [u8],
+    needle: P,
+) -> RevSearchIndicesOverlappingBytesIgnoreAsciiCase<'a, P> {
+    RevSearchIndicesOverlappingBytesIgnoreAsciiCase::new(haystack, needle)
+}
+
+///
+/// count the matches of the needle in the haystack.
+///
+/// Examples
+///
+/// ```rust
+/// use naive_opt::string_search_count;
+///
+/// assert_eq!(string_search_count("abc345abc901abc", "abc"), 3);
+/// assert_eq!(string_search_count("aaaa", "aa"), 2);
+/// ```
+///
+pub fn string_search_count<'a, P: SearchIn<'a>>(haystack: &'a str, needle: P) -> usize {
+    needle.count_in(haystack)
+}
+
+pub fn string_search_count_bytes<'a, P: SearchInBytes<'a>>(haystack: &'a [u8], needle: P) -> usize {
+    needle.count_in(haystack)
+}
+
+///
+/// count the overlapping matches of the needle in the haystack.
+///
+/// Examples
+///
+/// ```rust
+/// use naive_opt::string_search_count_overlapping;
+///
+/// assert_eq!(string_search_count_overlapping("aaaa", "aa"), 3);
+/// ```
+///
+pub fn string_search_count_overlapping<'a, P: SearchIn<'a>>(haystack: &'a str, needle: P) -> usize {
+    needle.count_in_overlapping(haystack)
+}
+
+pub fn string_search_count_overlapping_bytes<'a, P: SearchInBytes<'a>>(
+    haystack: &'a [u8],
+    needle: P,
+) -> usize {
+    needle.count_in_overlapping(haystack)
+}
+
+pub fn string_search_count_ignore_ascii_case<'a, P: SearchIn<'a>>(
+    haystack: &'a str,
+    needle: P,
+) -> usize {
+    needle.count_in_ignore_ascii_case(haystack)
+}
+
+pub fn string_search_count_bytes_ignore_ascii_case<'a, P: SearchInBytes<'a>>(
+    haystack: &'a [u8],
+    needle: P,
+) -> usize {
+    needle.count_in_ignore_ascii_case(haystack)
+}
+
+pub fn string_search_count_overlapping_ignore_ascii_case<'a, P: SearchIn<'a>>(
+    haystack: &'a str,
+    needle: P,
+) -> usize {
+    needle.count_in_overlapping_ignore_ascii_case(haystack)
+}
+
+pub fn string_search_count_overlapping_bytes_ignore_ascii_case<'a, P: SearchInBytes<'a>>(
+    haystack: &'a [u8],
+    needle: P,
+) -> usize {
+    needle.count_in_overlapping_ignore_ascii_case(haystack)
+}
 //
 // Only UTF-8 character sequence are used in the rust.
 //
@@ -1288,6 +3323,7 @@ pub fn string_rsearch_indices_bytes_ignore_ascii_case<'a, P: SearchInBytes<'a>>(
 //
 // Otherwise the ASCII character are using many space code: 0x20.
 // This code do stochastics using by the 1st byte and last byte.
+// The needle of 3 bytes or more is searched by the two rarest bytes of it.
 //
 
 /*
@@ -1301,27 +3337,186 @@ pub fn string_rsearch_indices_bytes_ignore_ascii_case<'a, P: SearchInBytes<'a>>(
 
 mod mc_generic;
 
-#[cfg(not(feature = "only_mc_last"))]
+#[cfg(feature = "alloc")]
+mod chunked;
+#[cfg(feature = "alloc")]
+pub use chunked::ChunkedHaystack;
+#[cfg(feature = "alloc")]
+pub use chunked::{chunked_rsearch, chunked_rsearch_ignore_ascii_case};
+#[cfg(feature = "alloc")]
+pub use chunked::{chunked_search, chunked_search_ignore_ascii_case};
+
+mod count;
+
+mod finder;
+pub use finder::{Finder, FinderIndices, FinderIndicesBytes};
+pub use finder::{FinderRev, FinderRevIndices, FinderRevIndicesBytes};
+
+mod frequency;
+pub use frequency::ByteFrequency;
+
+mod ignore_case;
+pub use ignore_case::{RevSearchIndicesIgnoreCase, SearchIndicesIgnoreCase};
+
+mod lines;
+pub use lines::{LineMatch, LineMatchBytes, SearchLines, SearchLinesBytes};
+
+#[cfg(feature = "alloc")]
+mod multi;
+#[cfg(feature = "alloc")]
+pub use multi::{MultiSearch, MultiSearchIndices, MultiSearchIndicesBytes};
+
+#[cfg(feature = "std")]
+mod stream;
+#[cfg(feature = "std")]
+pub use stream::StreamSearcher;
+
+#[cfg(feature = "mmap")]
+mod mmap;
+#[cfg(feature = "mmap")]
+pub use mmap::{count_in_file, count_in_file_ignore_ascii_case, FileSearchError};
+#[cfg(feature = "mmap")]
+pub use mmap::{count_in_file_buffered, count_in_file_buffered_ignore_ascii_case};
+#[cfg(feature = "mmap")]
+pub use mmap::{search_file, search_file_ignore_ascii_case};
+#[cfg(feature = "mmap")]
+pub use mmap::{search_file_buffered, search_file_buffered_ignore_ascii_case};
+#[cfg(feature = "mmap")]
+pub use mmap::{search_file_indices, search_file_indices_ignore_ascii_case};
+#[cfg(feature = "mmap")]
+pub use mmap::{search_file_indices_buffered, search_file_indices_buffered_ignore_ascii_case};
+
+#[cfg(feature = "parallel")]
+mod parallel;
+#[cfg(feature = "parallel")]
+pub use parallel::{par_includes, par_includes_ignore_ascii_case, ParallelSearcher};
+#[cfg(feature = "parallel")]
+pub use parallel::{par_search, par_search_count, par_search_indices};
+#[cfg(feature = "parallel")]
+pub use parallel::{
+    par_search_count_ignore_ascii_case, par_search_ignore_ascii_case,
+    par_search_indices_ignore_ascii_case,
+};
+
+mod container;
+
+#[cfg(all(feature = "std", unix))]
+mod os;
+#[cfg(all(feature = "std", unix))]
+pub use os::{SearchIndicesOs, SearchOsStr};
+
+mod overlapping;
+pub use overlapping::{RevSearchIndicesOverlapping, RevSearchIndicesOverlappingBytes};
+pub use overlapping::{
+    RevSearchIndicesOverlappingBytesIgnoreAsciiCase, RevSearchIndicesOverlappingIgnoreAsciiCase,
+};
+pub use overlapping::{SearchIndicesOverlapping, SearchIndicesOverlappingBytes};
+pub use overlapping::{
+    SearchIndicesOverlappingBytesIgnoreAsciiCase, SearchIndicesOverlappingIgnoreAsciiCase,
+};
+
+mod picks;
+
+mod range;
+
+#[cfg(feature = "alloc")]
+mod replace;
+
+mod set;
+pub use set::ByteSet;
+
+mod split;
+pub use split::{SearchRSplit, SearchRSplitBytes};
+pub use split::{SearchSplit, SearchSplitBytes, SearchSplitN, SearchSplitNBytes};
+
+mod two_way;
+
+mod word;
+pub use word::{SearchIndicesWord, SearchIndicesWordBytes, WordChars};
+
+#[cfg(all(
+    not(feature = "only_mc_last"),
+    not(feature = "only_mc_rare"),
+    not(feature = "only_mc_dual"),
+    not(feature = "only_mc_adaptive")
+))]
 mod mc_1st;
 
-#[cfg(not(feature = "only_mc_1st"))]
+#[cfg(all(
+    not(feature = "only_mc_1st"),
+    not(feature = "only_mc_rare"),
+    not(feature = "only_mc_dual"),
+    not(feature = "only_mc_adaptive")
+))]
 mod mc_last;
 
+#[cfg(feature = "only_mc_rare")]
+mod mc_rare;
+
+#[cfg(any(
+    feature = "only_mc_dual",
+    all(
+        not(feature = "only_mc_1st"),
+        not(feature = "only_mc_last"),
+        not(feature = "only_mc_rare"),
+        not(feature = "only_mc_adaptive")
+    )
+))]
+mod mc_dual;
+
+#[cfg(feature = "only_mc_adaptive")]
+mod mc_adaptive;
+
+///
+/// The minimum needle length to search by the two rarest bytes of the needle.
+/// The shorter needle has only the 1st byte and the last byte.
+///
+const DUAL_PIVOT_MIN_LEN: usize = 3;
+
 ///
 /// Internal helper to decide whether to use 1st-byte search or last-byte search
-/// based on ASCII frequency stochastics.
+/// based on byte frequency stochastics.
 ///
 #[inline]
 fn prefer_1st_strategy(nee_bytes: &[u8]) -> bool {
-    let byte_1st = nee_bytes[0];
-    let byte_last = nee_bytes[nee_bytes.len() - 1];
-    if byte_1st.is_ascii() && byte_last.is_ascii() {
-        let weight_1st = _ASCII_STOCHAS[byte_1st as usize];
-        let weight_last = _ASCII_STOCHAS[byte_last as usize];
-        weight_1st <= weight_last
-    } else {
-        false // default to mc_last for non-ascii
-    }
+    let weight_1st = byte_weight(nee_bytes[0]);
+    let weight_last = byte_weight(nee_bytes[nee_bytes.len() - 1]);
+    weight_1st <= weight_last
+}
+
+///
+/// Internal helper to rank a byte by the stochastics. The lower is the rarer.
+///
+#[inline]
+const fn byte_weight(byte: u8) -> u8 {
+    _BYTE_STOCHAS[byte as usize]
+}
+
+///
+/// Internal helper to decide the pivot index and the guard index of the needle
+/// for the precompiled searchers. The needle must not be empty.
+///
+/// The searchers scan the whole needle only once, so that they use
+/// the two rarest bytes of the needle, the same as the default search
+/// of the needle of `DUAL_PIVOT_MIN_LEN` bytes or more.
+///
+#[inline]
+fn select_pivots(nee_bytes: &[u8]) -> (usize, Option<usize>) {
+    use mc_generic::SearchStrategy;
+    #[cfg(feature = "only_mc_1st")]
+    type Strategy = mc_generic::FirstByte;
+    #[cfg(feature = "only_mc_last")]
+    type Strategy = mc_generic::LastByte;
+    #[cfg(feature = "only_mc_rare")]
+    type Strategy = mc_generic::RarestByte;
+    #[cfg(all(
+        not(feature = "only_mc_1st"),
+        not(feature = "only_mc_last"),
+        not(feature = "only_mc_rare")
+    ))]
+    type Strategy = mc_generic::RarestPair;
+    //
+    Strategy::pivots(nee_bytes)
 }
 
 #[inline(always)]
@@ -1334,12 +3529,32 @@ fn naive_opt_mc_bytes(hay_bytes: &[u8], nee_bytes: &[u8]) -> Option<usize> {
     {
         mc_last::naive_opt_mc_last_bytes(hay_bytes, nee_bytes)
     }
-    #[cfg(all(not(feature = "only_mc_1st"), not(feature = "only_mc_last")))]
+    #[cfg(feature = "only_mc_rare")]
+    {
+        mc_rare::naive_opt_mc_rare_bytes(hay_bytes, nee_bytes)
+    }
+    #[cfg(feature = "only_mc_dual")]
+    {
+        mc_dual::naive_opt_mc_dual_bytes(hay_bytes, nee_bytes)
+    }
+    #[cfg(feature = "only_mc_adaptive")]
+    {
+        mc_adaptive::naive_opt_mc_adaptive_bytes(hay_bytes, nee_bytes)
+    }
+    #[cfg(all(
+        not(feature = "only_mc_1st"),
+        not(feature = "only_mc_last"),
+        not(feature = "only_mc_rare"),
+        not(feature = "only_mc_dual"),
+        not(feature = "only_mc_adaptive")
+    ))]
     {
         if nee_bytes.is_empty() {
             return Some(0);
         }
-        if prefer_1st_strategy(nee_bytes) {
+        if nee_bytes.len() >= DUAL_PIVOT_MIN_LEN {
+            mc_dual::naive_opt_mc_dual_bytes(hay_bytes, nee_bytes)
+        } else if prefer_1st_strategy(nee_bytes) {
             mc_1st::naive_opt_mc_1st_bytes(hay_bytes, nee_bytes)
         } else {
             mc_last::naive_opt_mc_last_bytes(hay_bytes, nee_bytes)
@@ -1357,12 +3572,32 @@ fn naive_opt_mc_rev_bytes(hay_bytes: &[u8], nee_bytes: &[u8]) -> Option<usize> {
     {
         mc_last::naive_opt_mc_last_rev_bytes(hay_bytes, nee_bytes)
     }
-    #[cfg(all(not(feature = "only_mc_1st"), not(feature = "only_mc_last")))]
+    #[cfg(feature = "only_mc_rare")]
+    {
+        mc_rare::naive_opt_mc_rare_rev_bytes(hay_bytes, nee_bytes)
+    }
+    #[cfg(feature = "only_mc_dual")]
+    {
+        mc_dual::naive_opt_mc_dual_rev_bytes(hay_bytes, nee_bytes)
+    }
+    #[cfg(feature = "only_mc_adaptive")]
+    {
+        mc_adaptive::naive_opt_mc_adaptive_rev_bytes(hay_bytes, nee_bytes)
+    }
+    #[cfg(all(
+        not(feature = "only_mc_1st"),
+        not(feature = "only_mc_last"),
+        not(feature = "only_mc_rare"),
+        not(feature = "only_mc_dual"),
+        not(feature = "only_mc_adaptive")
+    ))]
     {
         if nee_bytes.is_empty() {
             return Some(hay_bytes.len());
         }
-        if prefer_1st_strategy(nee_bytes) {
+        if nee_bytes.len() >= DUAL_PIVOT_MIN_LEN {
+            mc_dual::naive_opt_mc_dual_rev_bytes(hay_bytes, nee_bytes)
+        } else if prefer_1st_strategy(nee_bytes) {
             mc_1st::naive_opt_mc_1st_rev_bytes(hay_bytes, nee_bytes)
         } else {
             mc_last::naive_opt_mc_last_rev_bytes(hay_bytes, nee_bytes)
@@ -1370,13 +3605,23 @@ fn naive_opt_mc_rev_bytes(hay_bytes: &[u8], nee_bytes: &[u8]) -> Option<usize> {
     }
 }
 
-// ascii stochastics
-const _ASCII_STOCHAS: [u8; 128] = [
+// byte stochastics, the lower is the rarer.
+//
+// 0x00..=0x7F: the ascii stochastics of the english text, by `make stochastics`.
+// 0x80..=0xFF: the byte frequencies of the english and the japanese "Rust by Example"
+// of the rust 1.95.0 documentation, scaled to the ascii stochastics of 'e',
+// by `make stochastics-non-ascii` (`cargo xtask byte_stochastics`).
+// The bytes that never appear in UTF-8 (0xC0, 0xC1 and 0xF5..=0xFF) are 0.
+const _BYTE_STOCHAS: [u8; 256] = [
     0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     255, 0, 2, 0, 0, 0, 0, 0, 1, 1, 0, 3, 6, 14, 19, 1, 3, 4, 3, 2, 2, 1, 1, 1, 1, 1, 2, 0, 0, 1,
     0, 0, 0, 4, 1, 5, 2, 4, 3, 0, 1, 5, 0, 0, 2, 3, 3, 2, 5, 0, 4, 6, 6, 1, 0, 0, 0, 0, 0, 1, 0, 1,
     0, 1, 0, 39, 7, 20, 19, 69, 11, 9, 18, 39, 0, 2, 18, 12, 38, 38, 12, 1, 34, 35, 50, 13, 5, 5,
-    2, 7, 0, 0, 2, 0, 0, 0,
+    2, 7, 0, 0, 2, 0, 0, 0, 3, 12, 7, 5, 1, 1, 1, 1, 2, 1, 1, 2, 2, 1, 1, 1, 1, 1, 2, 1, 1, 1, 1,
+    2, 1, 2, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 2, 2, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 2, 1, 1, 1, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 22, 1, 3, 2, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 ];
 
 #[inline(always)]
@@ -1389,12 +3634,32 @@ fn naive_opt_mc_bytes_iac(hay_bytes: &[u8], nee_bytes: &[u8]) -> Option<usize> {
     {
         mc_last::naive_opt_mc_last_bytes_iac(hay_bytes, nee_bytes)
     }
-    #[cfg(all(not(feature = "only_mc_1st"), not(feature = "only_mc_last")))]
+    #[cfg(feature = "only_mc_rare")]
+    {
+        mc_rare::naive_opt_mc_rare_bytes_iac(hay_bytes, nee_bytes)
+    }
+    #[cfg(feature = "only_mc_dual")]
+    {
+        mc_dual::naive_opt_mc_dual_bytes_iac(hay_bytes, nee_bytes)
+    }
+    #[cfg(feature = "only_mc_adaptive")]
+    {
+        mc_adaptive::naive_opt_mc_adaptive_bytes_iac(hay_bytes, nee_bytes)
+    }
+    #[cfg(all(
+        not(feature = "only_mc_1st"),
+        not(feature = "only_mc_last"),
+        not(feature = "only_mc_rare"),
+        not(feature = "only_mc_dual"),
+        not(feature = "only_mc_adaptive")
+    ))]
     {
         if nee_bytes.is_empty() {
             return Some(0);
         }
-        if prefer_1st_strategy(nee_bytes) {
+        if nee_bytes.len() >= DUAL_PIVOT_MIN_LEN {
+            mc_dual::naive_opt_mc_dual_bytes_iac(hay_bytes, nee_bytes)
+        } else if prefer_1st_strategy(nee_bytes) {
             mc_1st::naive_opt_mc_1st_bytes_iac(hay_bytes, nee_bytes)
         } else {
             mc_last::naive_opt_mc_last_bytes_iac(hay_bytes, nee_bytes)
@@ -1412,12 +3677,32 @@ fn naive_opt_mc_rev_bytes_iac(hay_bytes: &[u8], nee_bytes: &[u8]) -> Option<usiz
     {
         mc_last::naive_opt_mc_last_rev_bytes_iac(hay_bytes, nee_bytes)
     }
-    #[cfg(all(not(feature = "only_mc_1st"), not(feature = "only_mc_last")))]
+    #[cfg(feature = "only_mc_rare")]
+    {
+        mc_rare::naive_opt_mc_rare_rev_bytes_iac(hay_bytes, nee_bytes)
+    }
+    #[cfg(feature = "only_mc_dual")]
+    {
+        mc_dual::naive_opt_mc_dual_rev_bytes_iac(hay_bytes, nee_bytes)
+    }
+    #[cfg(feature = "only_mc_adaptive")]
+    {
+        mc_adaptive::naive_opt_mc_adaptive_rev_bytes_iac(hay_bytes, nee_bytes)
+    }
+    #[cfg(all(
+        not(feature = "only_mc_1st"),
+        not(feature = "only_mc_last"),
+        not(feature = "only_mc_rare"),
+        not(feature = "only_mc_dual"),
+        not(feature = "only_mc_adaptive")
+    ))]
     {
         if nee_bytes.is_empty() {
             return Some(hay_bytes.len());
         }
-        if prefer_1st_strategy(nee_bytes) {
+        if nee_bytes.len() >= DUAL_PIVOT_MIN_LEN {
+            mc_dual::naive_opt_mc_dual_rev_bytes_iac(hay_bytes, nee_bytes)
+        } else if prefer_1st_strategy(nee_bytes) {
             mc_1st::naive_opt_mc_1st_rev_bytes_iac(hay_bytes, nee_bytes)
         } else {
             mc_last::naive_opt_mc_last_rev_bytes_iac(hay_bytes, nee_bytes)
@@ -1425,3 +3710,89 @@ fn naive_opt_mc_rev_bytes_iac(hay_bytes: &[u8], nee_bytes: &[u8]) -> Option<usiz
     }
 }
 
+#[inline(always)]
+fn naive_opt_mc_count_bytes(hay_bytes: &[u8], nee_bytes: &[u8], overlapping: bool) -> usize {
+    #[cfg(feature = "only_mc_1st")]
+    {
+        mc_1st::naive_opt_mc_1st_count_bytes(hay_bytes, nee_bytes, overlapping)
+    }
+    #[cfg(feature = "only_mc_last")]
+    {
+        mc_last::naive_opt_mc_last_count_bytes(hay_bytes, nee_bytes, overlapping)
+    }
+    #[cfg(feature = "only_mc_rare")]
+    {
+        mc_rare::naive_opt_mc_rare_count_bytes(hay_bytes, nee_bytes, overlapping)
+    }
+    #[cfg(feature = "only_mc_dual")]
+    {
+        mc_dual::naive_opt_mc_dual_count_bytes(hay_bytes, nee_bytes, overlapping)
+    }
+    #[cfg(feature = "only_mc_adaptive")]
+    {
+        mc_adaptive::naive_opt_mc_adaptive_count_bytes(hay_bytes, nee_bytes, overlapping)
+    }
+    #[cfg(all(
+        not(feature = "only_mc_1st"),
+        not(feature = "only_mc_last"),
+        not(feature = "only_mc_rare"),
+        not(feature = "only_mc_dual"),
+        not(feature = "only_mc_adaptive")
+    ))]
+    {
+        if nee_bytes.is_empty() {
+            return 0;
+        }
+        if nee_bytes.len() >= DUAL_PIVOT_MIN_LEN {
+            mc_dual::naive_opt_mc_dual_count_bytes(hay_bytes, nee_bytes, overlapping)
+        } else if prefer_1st_strategy(nee_bytes) {
+            mc_1st::naive_opt_mc_1st_count_bytes(hay_bytes, nee_bytes, overlapping)
+        } else {
+            mc_last::naive_opt_mc_last_count_bytes(hay_bytes, nee_bytes, overlapping)
+        }
+    }
+}
+
+#[inline(always)]
+fn naive_opt_mc_count_bytes_iac(hay_bytes: &[u8], nee_bytes: &[u8], overlapping: bool) -> usize {
+    #[cfg(feature = "only_mc_1st")]
+    {
+        mc_1st::naive_opt_mc_1st_count_bytes_iac(hay_bytes, nee_bytes, overlapping)
+    }
+    #[cfg(feature = "only_mc_last")]
+    {
+        mc_last::naive_opt_mc_last_count_bytes_iac(hay_bytes, nee_bytes, overlapping)
+    }
+    #[cfg(feature = "only_mc_rare")]
+    {
+        mc_rare::naive_opt_mc_rare_count_bytes_iac(hay_bytes, nee_bytes, overlapping)
+    }
+    #[cfg(feature = "only_mc_dual")]
+    {
+        mc_dual::naive_opt_mc_dual_count_bytes_iac(hay_bytes, nee_bytes, overlapping)
+    }
+    #[cfg(feature = "only_mc_adaptive")]
+    {
+        mc_adaptive::naive_opt_mc_adaptive_count_bytes_iac(hay_bytes, nee_bytes, overlapping)
+    }
+    #[cfg(all(
+        not(feature = "only_mc_1st"),
+        not(feature = "only_mc_last"),
+        not(feature = "only_mc_rare"),
+        not(feature = "only_mc_dual"),
+        not(feature = "only_mc_adaptive")
+    ))]
+    {
+        if nee_bytes.is_empty() {
+            return 0;
+        }
+        if nee_bytes.len() >= DUAL_PIVOT_MIN_LEN {
+            mc_dual::naive_opt_mc_dual_count_bytes_iac(hay_bytes, nee_bytes, overlapping)
+        } else if prefer_1st_strategy(nee_bytes) {
+            mc_1st::naive_opt_mc_1st_count_bytes_iac(hay_bytes, nee_bytes, overlapping)
+        } else {
+            mc_last::naive_opt_mc_last_count_bytes_iac(hay_bytes, nee_bytes, overlapping)
+        }
+    }
+}
+
@@ -0,0 +1,184 @@
+//
+// The line reporting iterators are built on the match iterators,
+// such as `SearchIndices` and `SearchIndicesBytes`.
+//
+// The newlines are counted incrementally between the matches, and the end of
+// the current line is kept for the following matches on the same line.
+// So the haystack is scanned once, however many matches there are.
+//
+
+///
+/// The match with its line, yielded by [Search::search_lines()](crate::Search::search_lines).
+///
+/// The line does not contain the line terminator, `"\n"` or `"\r\n"`.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LineMatch<'a> {
+    /// the line number, starting at 1.
+    pub line_no: usize,
+    /// the byte offset of the start of the line in the haystack.
+    pub line_start: usize,
+    /// the byte offset of the match in the line, starting at 0.
+    pub column_byte: usize,
+    /// the char offset of the match in the line, starting at 0.
+    pub column_char: usize,
+    /// the line that contains the start of the match.
+    pub line: &'a str,
+}
+
+///
+/// The match with its line, yielded by [SearchBytes::search_lines_bytes()](crate::SearchBytes::search_lines_bytes).
+///
+/// The line does not contain the line terminator, `b"\n"` or `b"\r\n"`.
+/// The `column_char` counts the bytes that are not the UTF-8 continuation bytes.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LineMatchBytes<'a> {
+    /// the line number, starting at 1.
+    pub line_no: usize,
+    /// the byte offset of the start of the line in the haystack.
+    pub line_start: usize,
+    /// the byte offset of the match in the line, starting at 0.
+    pub column_byte: usize,
+    /// the char offset of the match in the line, starting at 0.
+    pub column_char: usize,
+    /// the line that contains the start of the match.
+    pub line: &'a [u8],
+}
+
+// the line of the last match, and the char column of the last match on it.
+#[derive(Debug, Clone)]
+struct LineTracker {
+    line_no: usize,
+    line_start: usize,
+    // the offset of the newline that ends the line, or the haystack length.
+    line_end: usize,
+    col_byte: usize,
+    col_char: usize,
+    started: bool,
+}
+impl LineTracker {
+    fn new() -> Self {
+        LineTracker {
+            line_no: 1,
+            line_start: 0,
+            line_end: 0,
+            col_byte: 0,
+            col_char: 0,
+            started: false,
+        }
+    }
+    // move to the line of the match starting at `st`, that is not before the last match.
+    fn advance(&mut self, hay_bytes: &[u8], st: usize) {
+        if !self.started || st > self.line_end {
+            let mut curr_idx = if self.started {
+                self.line_no += 1;
+                self.line_start = self.line_end + 1;
+                self.line_start
+            } else {
+                self.started = true;
+                0
+            };
+            while let Some(idx) = ::memx::memchr(&hay_bytes[curr_idx..st], b'\n') {
+                self.line_no += 1;
+                self.line_start = curr_idx + idx + 1;
+                curr_idx = self.line_start;
+            }
+            self.line_end = match ::memx::memchr(&hay_bytes[st..], b'\n') {
+                Some(idx) => st + idx,
+                None => hay_bytes.len(),
+            };
+            self.col_byte = 0;
+            self.col_char = 0;
+        }
+        let col_byte = st - self.line_start;
+        self.col_char += count_chars(&hay_bytes[self.line_start + self.col_byte..st]);
+        self.col_byte = col_byte;
+    }
+    // the range of the line without the line terminator.
+    fn line_range(&self, hay_bytes: &[u8]) -> (usize, usize) {
+        let mut ed = self.line_end;
+        if ed > self.line_start && ed < hay_bytes.len() && hay_bytes[ed - 1] == b'\r' {
+            ed -= 1;
+        }
+        (self.line_start, ed)
+    }
+}
+
+#[inline]
+fn count_chars(bytes: &[u8]) -> usize {
+    bytes.iter().filter(|&&b| (b as i8) >= -0x40).count()
+}
+
+///
+/// Created with the method [Search::search_lines()](crate::Search::search_lines)
+/// and [Search::search_lines_ignore_ascii_case()](crate::Search::search_lines_ignore_ascii_case).
+///
+#[derive(Debug, Clone)]
+pub struct SearchLines<'a, I> {
+    haystack: &'a str,
+    matches: I,
+    tracker: LineTracker,
+}
+impl<'a, I: Iterator<Item = (usize, &'a str)>> SearchLines<'a, I> {
+    pub(crate) fn new(a_haystack: &'a str, a_matches: I) -> Self {
+        SearchLines {
+            haystack: a_haystack,
+            matches: a_matches,
+            tracker: LineTracker::new(),
+        }
+    }
+}
+impl<'a, I: Iterator<Item = (usize, &'a str)>> Iterator for SearchLines<'a, I> {
+    type Item = LineMatch<'a>;
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (st, _) = self.matches.next()?;
+        let hay_bytes = self.haystack.as_bytes();
+        self.tracker.advance(hay_bytes, st);
+        let (line_st, line_ed) = self.tracker.line_range(hay_bytes);
+        Some(LineMatch {
+            line_no: self.tracker.line_no,
+            line_start: line_st,
+            column_byte: self.tracker.col_byte,
+            column_char: self.tracker.col_char,
+            line: &self.haystack[line_st..line_ed],
+        })
+    }
+}
+
+///
+/// Created with the method [SearchBytes::search_lines_bytes()](crate::SearchBytes::search_lines_bytes)
+/// and [SearchBytes::search_lines_bytes_ignore_ascii_case()](crate::SearchBytes::search_lines_bytes_ignore_ascii_case).
+///
+#[derive(Debug, Clone)]
+pub struct SearchLinesBytes<'a, I> {
+    haystack: &'a [u8],
+    matches: I,
+    tracker: LineTracker,
+}
+impl<'a, I: Iterator<Item = (usize, &'a [u8])>> SearchLinesBytes<'a, I> {
+    pub(crate) fn new(a_haystack: &'a [u8], a_matches: I) -> Self {
+        SearchLinesBytes {
+            haystack: a_haystack,
+            matches: a_matches,
+            tracker: LineTracker::new(),
+        }
+    }
+}
+impl<'a, I: Iterator<Item = (usize, &'a [u8])>> Iterator for SearchLinesBytes<'a, I> {
+    type Item = LineMatchBytes<'a>;
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (st, _) = self.matches.next()?;
+        self.tracker.advance(self.haystack, st);
+        let (line_st, line_ed) = self.tracker.line_range(self.haystack);
+        Some(LineMatchBytes {
+            line_no: self.tracker.line_no,
+            line_start: line_st,
+            column_byte: self.tracker.col_byte,
+            column_char: self.tracker.col_char,
+            line: &self.haystack[line_st..line_ed],
+        })
+    }
+}
@@ -0,0 +1,341 @@
+//
+// The adaptive search, that switches the pivot at the runtime.
+//
+// The initial pivot is the 1st byte or the last byte, the same as the default
+// dispatcher. While scanning, it counts the failed verifications. When they
+// pile up over the threshold per bytes scanned, the pivot byte is frequent in
+// this haystack, in spite of the stochastics. Then it switches the pivot to
+// the rarest byte of the needle that has not been tried yet, and continues
+// the scan from the last candidate.
+//
+// The verification work is bounded by the same budget as the other strategies,
+// over it, the rest of the scan falls back to the Two-Way search.
+//
+use crate::mc_generic::over_budget;
+use crate::two_way::{two_way_rsearch_by, two_way_rsearch_iac_by, two_way_search_by, two_way_search_iac_by};
+
+// the failed verifications are not counted as too many until this.
+const MIN_FAILS: usize = 8;
+// too many, if more than 1 failed verification per this bytes scanned.
+const BYTES_PER_FAIL: usize = 16;
+
+struct Adaptive {
+    pivot: usize,
+    fails: usize,
+    // the verification work, counted as the needle length per failed or rejected verification.
+    work: usize,
+    // the byte values that were already used as the pivot.
+    tried: [bool; 256],
+    // no pivot remains to switch to.
+    exhausted: bool,
+    ignore_ascii_case: bool,
+}
+impl Adaptive {
+    fn new(nee_bytes: &[u8], ignore_ascii_case: bool) -> Adaptive {
+        let pivot = if crate::prefer_1st_strategy(nee_bytes) {
+            0
+        } else {
+            nee_bytes.len() - 1
+        };
+        let mut r = Adaptive {
+            pivot,
+            fails: 0,
+            work: 0,
+            tried: [false; 256],
+            exhausted: false,
+            ignore_ascii_case,
+        };
+        r.tried[r.key(nee_bytes[pivot])] = true;
+        r
+    }
+    #[inline(always)]
+    fn key(&self, byte: u8) -> usize {
+        if self.ignore_ascii_case {
+            byte.to_ascii_lowercase() as usize
+        } else {
+            byte as usize
+        }
+    }
+    #[inline(always)]
+    fn pick_bytes(&self, nee_bytes: &[u8]) -> (u8, u8) {
+        let byte = nee_bytes[self.pivot];
+        if self.ignore_ascii_case {
+            (byte.to_ascii_uppercase(), byte.to_ascii_lowercase())
+        } else {
+            (byte, byte)
+        }
+    }
+    // count the failed verification, and return true if the pivot is switched.
+    #[inline]
+    fn fail(&mut self, nee_bytes: &[u8], scanned: usize) -> bool {
+        self.fails += 1;
+        if self.exhausted || self.fails < MIN_FAILS || self.fails * BYTES_PER_FAIL <= scanned {
+            return false;
+        }
+        // the rarest byte of the needle that has not been tried.
+        let mut next = None;
+        let mut weight = 0;
+        for (i, &byte) in nee_bytes.iter().enumerate() {
+            if self.tried[self.key(byte)] {
+                continue;
+            }
+            let w = crate::byte_weight(byte);
+            if next.is_none() || w < weight {
+                next = Some(i);
+                weight = w;
+            }
+        }
+        match next {
+            Some(pivot) => {
+                self.pivot = pivot;
+                self.tried[self.key(nee_bytes[pivot])] = true;
+                self.fails = 0;
+                true
+            }
+            None => {
+                self.exhausted = true;
+                false
+            }
+        }
+    }
+}
+
+enum Scan {
+    Found(usize),
+    // the pivot is switched at the candidate.
+    Switched(usize),
+    // the work is over the budget at the candidate.
+    OverBudget(usize),
+    NotFound,
+}
+
+// verify the candidates, which are the start indices of the haystack.
+// `base` is the index where the scan started, to count the bytes scanned.
+// `origin` is the index where the whole search started, for the budget.
+// the match that `accept` rejects is charged to the budget, but it is not
+// counted as the failed verification.
+#[inline(always)]
+fn scan<I: Iterator<Item = usize>, F: FnMut(usize) -> bool>(
+    hay_bytes: &[u8],
+    nee_bytes: &[u8],
+    candidates: I,
+    base: usize,
+    origin: usize,
+    state: &mut Adaptive,
+    accept: &mut F,
+) -> Scan {
+    let nee_len = nee_bytes.len();
+    for cand in candidates {
+        let hay_part = &hay_bytes[cand..cand + nee_len];
+        let matched = if state.ignore_ascii_case {
+            nee_bytes.eq_ignore_ascii_case(hay_part)
+        } else {
+            ::memx::memeq(nee_bytes, hay_part)
+        };
+        if matched && accept(cand) {
+            return Scan::Found(cand);
+        }
+        state.work += nee_len;
+        if over_budget(state.work, origin.abs_diff(cand) + 1) {
+            return Scan::OverBudget(cand);
+        }
+        if !matched && state.fail(nee_bytes, base.abs_diff(cand) + 1) {
+            return Scan::Switched(cand);
+        }
+    }
+    Scan::NotFound
+}
+
+#[inline]
+fn adaptive_search(hay_bytes: &[u8], nee_bytes: &[u8], ignore_ascii_case: bool) -> Option<usize> {
+    adaptive_search_by(hay_bytes, nee_bytes, ignore_ascii_case, |_| true)
+}
+
+// search the first match that is accepted by `accept`.
+#[inline]
+fn adaptive_search_by<F: FnMut(usize) -> bool>(
+    hay_bytes: &[u8],
+    nee_bytes: &[u8],
+    ignore_ascii_case: bool,
+    mut accept: F,
+) -> Option<usize> {
+    let hay_len = hay_bytes.len();
+    let nee_len = nee_bytes.len();
+    if nee_len == 0 {
+        return Some(0);
+    }
+    if hay_len < nee_len {
+        return None;
+    }
+    let mut state = Adaptive::new(nee_bytes, ignore_ascii_case);
+    // the candidates of the start index are `st..=hay_len - nee_len`.
+    let mut st = 0;
+    loop {
+        let pivot = state.pivot;
+        let (pick_byte_uc, pick_byte_lc) = state.pick_bytes(nee_bytes);
+        let search_slice = &hay_bytes[st + pivot..hay_len - nee_len + pivot + 1];
+        let r = if ignore_ascii_case {
+            let iter = ::memx::iter::memchr_dbl_iter(search_slice, pick_byte_uc, pick_byte_lc);
+            scan(
+                hay_bytes,
+                nee_bytes,
+                iter.map(|m| st + m),
+                st,
+                0,
+                &mut state,
+                &mut accept,
+            )
+        } else {
+            let iter = ::memx::iter::memchr_iter(search_slice, pick_byte_lc);
+            scan(
+                hay_bytes,
+                nee_bytes,
+                iter.map(|m| st + m),
+                st,
+                0,
+                &mut state,
+                &mut accept,
+            )
+        };
+        match r {
+            Scan::Found(idx) => return Some(idx),
+            Scan::Switched(cand) => st = cand + 1,
+            Scan::OverBudget(cand) => {
+                let base = cand + 1;
+                let hay_rest = &hay_bytes[base..];
+                let r = if ignore_ascii_case {
+                    two_way_search_iac_by(hay_rest, nee_bytes, |idx| accept(base + idx))
+                } else {
+                    two_way_search_by(hay_rest, nee_bytes, |idx| accept(base + idx))
+                };
+                return r.map(|idx| cand + 1 + idx);
+            }
+            Scan::NotFound => return None,
+        }
+    }
+}
+
+#[inline]
+fn adaptive_rsearch(hay_bytes: &[u8], nee_bytes: &[u8], ignore_ascii_case: bool) -> Option<usize> {
+    let hay_len = hay_bytes.len();
+    let nee_len = nee_bytes.len();
+    if nee_len == 0 {
+        return Some(hay_len);
+    }
+    if hay_len < nee_len {
+        return None;
+    }
+    let mut state = Adaptive::new(nee_bytes, ignore_ascii_case);
+    // the candidates of the start index are `0..ed`.
+    let mut ed = hay_len - nee_len + 1;
+    loop {
+        let pivot = state.pivot;
+        let (pick_byte_uc, pick_byte_lc) = state.pick_bytes(nee_bytes);
+        let search_slice = &hay_bytes[pivot..ed + pivot];
+        let r = if ignore_ascii_case {
+            let iter = ::memx::iter::memrchr_dbl_iter(search_slice, pick_byte_uc, pick_byte_lc);
+            scan(
+                hay_bytes,
+                nee_bytes,
+                iter,
+                ed - 1,
+                hay_len - nee_len,
+                &mut state,
+                &mut |_| true,
+            )
+        } else {
+            let iter = ::memx::iter::memrchr_iter(search_slice, pick_byte_lc);
+            scan(
+                hay_bytes,
+                nee_bytes,
+                iter,
+                ed - 1,
+                hay_len - nee_len,
+                &mut state,
+                &mut |_| true,
+            )
+        };
+        match r {
+            Scan::Found(idx) => return Some(idx),
+            // no candidate is left before the index 0.
+            Scan::Switched(0) => return None,
+            Scan::Switched(cand) => ed = cand,
+            Scan::OverBudget(cand) => {
+                let hay_rest = &hay_bytes[..cand + nee_len - 1];
+                return if ignore_ascii_case {
+                    two_way_rsearch_iac_by(hay_rest, nee_bytes, |_| true)
+                } else {
+                    two_way_rsearch_by(hay_rest, nee_bytes, |_| true)
+                };
+            }
+            Scan::NotFound => return None,
+        }
+    }
+}
+
+#[inline]
+pub(crate) fn naive_opt_mc_adaptive_bytes(hay_bytes: &[u8], nee_bytes: &[u8]) -> Option<usize> {
+    adaptive_search(hay_bytes, nee_bytes, false)
+}
+
+#[inline]
+pub(crate) fn naive_opt_mc_adaptive_rev_bytes(hay_bytes: &[u8], nee_bytes: &[u8]) -> Option<usize> {
+    adaptive_rsearch(hay_bytes, nee_bytes, false)
+}
+
+#[inline]
+pub(crate) fn naive_opt_mc_adaptive_bytes_iac(hay_bytes: &[u8], nee_bytes: &[u8]) -> Option<usize> {
+    adaptive_search(hay_bytes, nee_bytes, true)
+}
+
+#[inline]
+pub(crate) fn naive_opt_mc_adaptive_rev_bytes_iac(
+    hay_bytes: &[u8],
+    nee_bytes: &[u8],
+) -> Option<usize> {
+    adaptive_rsearch(hay_bytes, nee_bytes, true)
+}
+
+// the matches are counted and rejected in a single search,
+// so that the pivot and the budget are kept over the whole haystack.
+#[inline]
+fn adaptive_count(
+    hay_bytes: &[u8],
+    nee_bytes: &[u8],
+    ignore_ascii_case: bool,
+    overlapping: bool,
+) -> usize {
+    let nee_len = nee_bytes.len();
+    if nee_len == 0 {
+        return 0;
+    }
+    let step = if overlapping { 1 } else { nee_len };
+    let mut count = 0;
+    let mut next_st = 0;
+    adaptive_search_by(hay_bytes, nee_bytes, ignore_ascii_case, |idx| {
+        if idx >= next_st {
+            count += 1;
+            next_st = idx + step;
+        }
+        false
+    });
+    count
+}
+
+#[inline]
+pub(crate) fn naive_opt_mc_adaptive_count_bytes(
+    hay_bytes: &[u8],
+    nee_bytes: &[u8],
+    overlapping: bool,
+) -> usize {
+    adaptive_count(hay_bytes, nee_bytes, false, overlapping)
+}
+
+#[inline]
+pub(crate) fn naive_opt_mc_adaptive_count_bytes_iac(
+    hay_bytes: &[u8],
+    nee_bytes: &[u8],
+    overlapping: bool,
+) -> usize {
+    adaptive_count(hay_bytes, nee_bytes, true, overlapping)
+}
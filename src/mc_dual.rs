@@ -0,0 +1,40 @@
+use crate::mc_generic::{generic_count, generic_count_iac};
+use crate::mc_generic::{generic_rsearch, generic_rsearch_iac, generic_search, generic_search_iac, RarestPair};
+
+#[inline]
+pub(crate) fn naive_opt_mc_dual_bytes(hay_bytes: &[u8], nee_bytes: &[u8]) -> Option<usize> {
+    generic_search::<RarestPair>(hay_bytes, nee_bytes)
+}
+
+#[inline]
+pub(crate) fn naive_opt_mc_dual_rev_bytes(hay_bytes: &[u8], nee_bytes: &[u8]) -> Option<usize> {
+    generic_rsearch::<RarestPair>(hay_bytes, nee_bytes)
+}
+
+#[inline]
+pub(crate) fn naive_opt_mc_dual_bytes_iac(hay_bytes: &[u8], nee_bytes: &[u8]) -> Option<usize> {
+    generic_search_iac::<RarestPair>(hay_bytes, nee_bytes)
+}
+
+#[inline]
+pub(crate) fn naive_opt_mc_dual_rev_bytes_iac(hay_bytes: &[u8], nee_bytes: &[u8]) -> Option<usize> {
+    generic_rsearch_iac::<RarestPair>(hay_bytes, nee_bytes)
+}
+
+#[inline]
+pub(crate) fn naive_opt_mc_dual_count_bytes(
+    hay_bytes: &[u8],
+    nee_bytes: &[u8],
+    overlapping: bool,
+) -> usize {
+    generic_count::<RarestPair>(hay_bytes, nee_bytes, overlapping)
+}
+
+#[inline]
+pub(crate) fn naive_opt_mc_dual_count_bytes_iac(
+    hay_bytes: &[u8],
+    nee_bytes: &[u8],
+    overlapping: bool,
+) -> usize {
+    generic_count_iac::<RarestPair>(hay_bytes, nee_bytes, overlapping)
+}
@@ -1,11 +1,36 @@
+use crate::two_way::{two_way_count, two_way_count_iac};
+use crate::two_way::{two_way_rsearch_by, two_way_rsearch_iac_by, two_way_search_by, two_way_search_iac_by};
+
+// The verification work, counted as the needle length per failed or rejected
+// verification, is bounded by this budget. Over it, the rest of the scan falls
+// back to the Two-Way search, so that the whole search is O(n + m) in the worst case.
+const BUDGET_BASE: usize = 1024;
+const BUDGET_PER_BYTE: usize = 8;
+
+#[inline(always)]
+pub(crate) fn over_budget(work: usize, scanned: usize) -> bool {
+    work > BUDGET_BASE + scanned.saturating_mul(BUDGET_PER_BYTE)
+}
+
 pub(crate) trait SearchStrategy {
-    fn pivot(nee_len: usize) -> usize;
+    fn pivot(nee_bytes: &[u8]) -> usize;
+    // The 2nd pivot, which is checked before the full comparison.
+    #[inline(always)]
+    fn guard(_nee_bytes: &[u8], _pivot: usize) -> Option<usize> {
+        None
+    }
+    // The pivot and the guard.
+    #[inline(always)]
+    fn pivots(nee_bytes: &[u8]) -> (usize, Option<usize>) {
+        let pivot = Self::pivot(nee_bytes);
+        (pivot, Self::guard(nee_bytes, pivot))
+    }
 }
 
 pub(crate) struct FirstByte;
 impl SearchStrategy for FirstByte {
     #[inline(always)]
-    fn pivot(_: usize) -> usize {
+    fn pivot(_: &[u8]) -> usize {
         0
     }
 }
@@ -13,9 +38,79 @@ impl SearchStrategy for FirstByte {
 pub(crate) struct LastByte;
 impl SearchStrategy for LastByte {
     #[inline(always)]
-    fn pivot(nee_len: usize) -> usize {
-        nee_len - 1
+    fn pivot(nee_bytes: &[u8]) -> usize {
+        nee_bytes.len() - 1
+    }
+}
+
+// The position of the rarest byte in the whole needle, by the stochastics.
+pub(crate) struct RarestByte;
+impl SearchStrategy for RarestByte {
+    #[inline]
+    fn pivot(nee_bytes: &[u8]) -> usize {
+        let mut pivot = 0;
+        let mut weight = crate::byte_weight(nee_bytes[0]);
+        for (i, &byte) in nee_bytes.iter().enumerate().skip(1) {
+            let w = crate::byte_weight(byte);
+            if w < weight {
+                pivot = i;
+                weight = w;
+            }
+        }
+        pivot
+    }
+}
+
+// The positions of the two rarest bytes in the whole needle, by the stochastics.
+pub(crate) struct RarestPair;
+impl SearchStrategy for RarestPair {
+    #[inline]
+    fn pivot(nee_bytes: &[u8]) -> usize {
+        RarestByte::pivot(nee_bytes)
+    }
+    #[inline]
+    fn guard(nee_bytes: &[u8], pivot: usize) -> Option<usize> {
+        let mut guard = None;
+        let mut weight = 0;
+        for (i, &byte) in nee_bytes.iter().enumerate() {
+            if i == pivot {
+                continue;
+            }
+            let w = crate::byte_weight(byte);
+            if guard.is_none() || w < weight {
+                guard = Some(i);
+                weight = w;
+            }
+        }
+        guard
+    }
+    // both in a single scan of the needle, for the short haystack.
+    #[inline]
+    fn pivots(nee_bytes: &[u8]) -> (usize, Option<usize>) {
+        rarest_pair_by(nee_bytes, crate::byte_weight)
+    }
+}
+
+// The positions of the two rarest bytes in the whole needle, by `weight`.
+#[inline]
+pub(crate) fn rarest_pair_by<W: Fn(u8) -> u8>(nee_bytes: &[u8], weight: W) -> (usize, Option<usize>) {
+    let mut pivot = 0;
+    let mut pivot_weight = weight(nee_bytes[0]);
+    let mut guard = None;
+    let mut guard_weight = 0;
+    for (i, &byte) in nee_bytes.iter().enumerate().skip(1) {
+        let w = weight(byte);
+        if w < pivot_weight {
+            guard = Some(pivot);
+            guard_weight = pivot_weight;
+            pivot = i;
+            pivot_weight = w;
+        } else if guard.is_none() || w < guard_weight {
+            guard = Some(i);
+            guard_weight = w;
+        }
     }
+    (pivot, guard)
 }
 
 #[inline]
@@ -29,16 +124,58 @@ pub(crate) fn generic_search<S: SearchStrategy>(hay_bytes: &[u8], nee_bytes: &[u
         return None;
     }
 
-    let pivot = S::pivot(nee_len);
-    let pick_byte = nee_bytes[pivot];
+    let (pivot, guard) = S::pivots(nee_bytes);
+    pivot_search(hay_bytes, nee_bytes, pivot, nee_bytes[pivot], guard)
+}
+
+#[inline]
+pub(crate) fn pivot_search(
+    hay_bytes: &[u8],
+    nee_bytes: &[u8],
+    pivot: usize,
+    pick_byte: u8,
+    guard: Option<usize>,
+) -> Option<usize> {
+    pivot_search_by(hay_bytes, nee_bytes, pivot, pick_byte, guard, |_| true)
+}
+
+#[inline]
+pub(crate) fn pivot_search_by<F: FnMut(usize) -> bool>(
+    hay_bytes: &[u8],
+    nee_bytes: &[u8],
+    pivot: usize,
+    pick_byte: u8,
+    guard: Option<usize>,
+    mut accept: F,
+) -> Option<usize> {
+    let hay_len = hay_bytes.len();
+    let nee_len = nee_bytes.len();
+    if nee_len == 0 {
+        return Some(0);
+    }
+    if hay_len < nee_len {
+        return None;
+    }
 
     let search_slice = &hay_bytes[pivot..hay_len - nee_len + pivot + 1];
 
+    let mut work = 0;
     for m in ::memx::iter::memchr_iter(search_slice, pick_byte) {
         let st = m;
-        if ::memx::memeq(nee_bytes, &hay_bytes[st..st + nee_len]) {
+        if let Some(g) = guard {
+            if hay_bytes[st + g] != nee_bytes[g] {
+                continue;
+            }
+        }
+        if ::memx::memeq(nee_bytes, &hay_bytes[st..st + nee_len]) && accept(st) {
             return Some(st);
         }
+        work += nee_len;
+        if over_budget(work, st + 1) {
+            let base = st + 1;
+            return two_way_search_by(&hay_bytes[base..], nee_bytes, |idx| accept(base + idx))
+                .map(|idx| base + idx);
+        }
     }
     None
 }
@@ -57,16 +194,56 @@ pub(crate) fn generic_rsearch<S: SearchStrategy>(
         return None;
     }
 
-    let pivot = S::pivot(nee_len);
-    let pick_byte = nee_bytes[pivot];
+    let (pivot, guard) = S::pivots(nee_bytes);
+    pivot_rsearch(hay_bytes, nee_bytes, pivot, nee_bytes[pivot], guard)
+}
+
+#[inline]
+pub(crate) fn pivot_rsearch(
+    hay_bytes: &[u8],
+    nee_bytes: &[u8],
+    pivot: usize,
+    pick_byte: u8,
+    guard: Option<usize>,
+) -> Option<usize> {
+    pivot_rsearch_by(hay_bytes, nee_bytes, pivot, pick_byte, guard, |_| true)
+}
+
+#[inline]
+pub(crate) fn pivot_rsearch_by<F: FnMut(usize) -> bool>(
+    hay_bytes: &[u8],
+    nee_bytes: &[u8],
+    pivot: usize,
+    pick_byte: u8,
+    guard: Option<usize>,
+    mut accept: F,
+) -> Option<usize> {
+    let hay_len = hay_bytes.len();
+    let nee_len = nee_bytes.len();
+    if nee_len == 0 {
+        return Some(hay_len);
+    }
+    if hay_len < nee_len {
+        return None;
+    }
 
     let search_slice = &hay_bytes[pivot..hay_len - nee_len + pivot + 1];
 
+    let mut work = 0;
     for m in ::memx::iter::memrchr_iter(search_slice, pick_byte) {
         let st = m;
-        if ::memx::memeq(nee_bytes, &hay_bytes[st..st + nee_len]) {
+        if let Some(g) = guard {
+            if hay_bytes[st + g] != nee_bytes[g] {
+                continue;
+            }
+        }
+        if ::memx::memeq(nee_bytes, &hay_bytes[st..st + nee_len]) && accept(st) {
             return Some(st);
         }
+        work += nee_len;
+        if over_budget(work, hay_len - nee_len + 1 - st) {
+            return two_way_rsearch_by(&hay_bytes[..st + nee_len - 1], nee_bytes, accept);
+        }
     }
     None
 }
@@ -85,18 +262,68 @@ pub(crate) fn generic_search_iac<S: SearchStrategy>(
         return None;
     }
 
-    let pivot = S::pivot(nee_len);
+    let (pivot, guard) = S::pivots(nee_bytes);
     let pick_byte = nee_bytes[pivot];
-    let pick_byte_uc = pick_byte.to_ascii_uppercase();
-    let pick_byte_lc = pick_byte.to_ascii_lowercase();
+    pivot_search_iac(
+        hay_bytes,
+        nee_bytes,
+        pivot,
+        pick_byte.to_ascii_uppercase(),
+        pick_byte.to_ascii_lowercase(),
+        guard,
+    )
+}
+
+#[inline]
+pub(crate) fn pivot_search_iac(
+    hay_bytes: &[u8],
+    nee_bytes: &[u8],
+    pivot: usize,
+    pick_byte_uc: u8,
+    pick_byte_lc: u8,
+    guard: Option<usize>,
+) -> Option<usize> {
+    pivot_search_iac_by(hay_bytes, nee_bytes, pivot, pick_byte_uc, pick_byte_lc, guard, |_| true)
+}
+
+#[inline]
+pub(crate) fn pivot_search_iac_by<F: FnMut(usize) -> bool>(
+    hay_bytes: &[u8],
+    nee_bytes: &[u8],
+    pivot: usize,
+    pick_byte_uc: u8,
+    pick_byte_lc: u8,
+    guard: Option<usize>,
+    mut accept: F,
+) -> Option<usize> {
+    let hay_len = hay_bytes.len();
+    let nee_len = nee_bytes.len();
+    if nee_len == 0 {
+        return Some(0);
+    }
+    if hay_len < nee_len {
+        return None;
+    }
 
     let search_slice = &hay_bytes[pivot..hay_len - nee_len + pivot + 1];
 
+    let mut work = 0;
     for m in ::memx::iter::memchr_dbl_iter(search_slice, pick_byte_uc, pick_byte_lc) {
         let st = m;
-        if nee_bytes.eq_ignore_ascii_case(&hay_bytes[st..st + nee_len]) {
+        if let Some(g) = guard {
+            if !hay_bytes[st + g].eq_ignore_ascii_case(&nee_bytes[g]) {
+                continue;
+            }
+        }
+        if nee_bytes.eq_ignore_ascii_case(&hay_bytes[st..st + nee_len]) && accept(st) {
             return Some(st);
         }
+        work += nee_len;
+        if over_budget(work, st + 1) {
+            let base = st + 1;
+            return two_way_search_iac_by(&hay_bytes[base..], nee_bytes, |idx| accept(base + idx))
+                .map(|idx| base + idx);
+        }
     }
     None
 }
@@ -115,18 +342,208 @@ pub(crate) fn generic_rsearch_iac<S: SearchStrategy>(
         return None;
     }
 
-    let pivot = S::pivot(nee_len);
+    let (pivot, guard) = S::pivots(nee_bytes);
     let pick_byte = nee_bytes[pivot];
-    let pick_byte_uc = pick_byte.to_ascii_uppercase();
-    let pick_byte_lc = pick_byte.to_ascii_lowercase();
+    pivot_rsearch_iac(
+        hay_bytes,
+        nee_bytes,
+        pivot,
+        pick_byte.to_ascii_uppercase(),
+        pick_byte.to_ascii_lowercase(),
+        guard,
+    )
+}
+
+#[inline]
+pub(crate) fn pivot_rsearch_iac(
+    hay_bytes: &[u8],
+    nee_bytes: &[u8],
+    pivot: usize,
+    pick_byte_uc: u8,
+    pick_byte_lc: u8,
+    guard: Option<usize>,
+) -> Option<usize> {
+    pivot_rsearch_iac_by(hay_bytes, nee_bytes, pivot, pick_byte_uc, pick_byte_lc, guard, |_| true)
+}
+
+#[inline]
+pub(crate) fn pivot_rsearch_iac_by<F: FnMut(usize) -> bool>(
+    hay_bytes: &[u8],
+    nee_bytes: &[u8],
+    pivot: usize,
+    pick_byte_uc: u8,
+    pick_byte_lc: u8,
+    guard: Option<usize>,
+    mut accept: F,
+) -> Option<usize> {
+    let hay_len = hay_bytes.len();
+    let nee_len = nee_bytes.len();
+    if nee_len == 0 {
+        return Some(hay_len);
+    }
+    if hay_len < nee_len {
+        return None;
+    }
 
     let search_slice = &hay_bytes[pivot..hay_len - nee_len + pivot + 1];
 
+    let mut work = 0;
     for m in ::memx::iter::memrchr_dbl_iter(search_slice, pick_byte_uc, pick_byte_lc) {
         let st = m;
-        if nee_bytes.eq_ignore_ascii_case(&hay_bytes[st..st + nee_len]) {
+        if let Some(g) = guard {
+            if !hay_bytes[st + g].eq_ignore_ascii_case(&nee_bytes[g]) {
+                continue;
+            }
+        }
+        if nee_bytes.eq_ignore_ascii_case(&hay_bytes[st..st + nee_len]) && accept(st) {
             return Some(st);
         }
+        work += nee_len;
+        if over_budget(work, hay_len - nee_len + 1 - st) {
+            return two_way_rsearch_iac_by(&hay_bytes[..st + nee_len - 1], nee_bytes, accept);
+        }
     }
     None
 }
+
+//
+// The counting runs on the same loop as the searching, without materializing
+// the matches. After a match, the candidates before the next start are skipped,
+// the next byte of the start if overlapping, otherwise the end of the match.
+//
+
+#[inline]
+pub(crate) fn generic_count<S: SearchStrategy>(
+    hay_bytes: &[u8],
+    nee_bytes: &[u8],
+    overlapping: bool,
+) -> usize {
+    let hay_len = hay_bytes.len();
+    let nee_len = nee_bytes.len();
+    if nee_len == 0 || hay_len < nee_len {
+        return 0;
+    }
+
+    let (pivot, guard) = S::pivots(nee_bytes);
+    pivot_count(hay_bytes, nee_bytes, pivot, nee_bytes[pivot], guard, overlapping)
+}
+
+#[inline]
+pub(crate) fn pivot_count(
+    hay_bytes: &[u8],
+    nee_bytes: &[u8],
+    pivot: usize,
+    pick_byte: u8,
+    guard: Option<usize>,
+    overlapping: bool,
+) -> usize {
+    let hay_len = hay_bytes.len();
+    let nee_len = nee_bytes.len();
+    if nee_len == 0 || hay_len < nee_len {
+        return 0;
+    }
+    let step = if overlapping { 1 } else { nee_len };
+
+    let search_slice = &hay_bytes[pivot..hay_len - nee_len + pivot + 1];
+
+    let mut count = 0;
+    let mut next_st = 0;
+    let mut work = 0;
+    for m in ::memx::iter::memchr_iter(search_slice, pick_byte) {
+        let st = m;
+        if st < next_st {
+            continue;
+        }
+        if let Some(g) = guard {
+            if hay_bytes[st + g] != nee_bytes[g] {
+                continue;
+            }
+        }
+        if ::memx::memeq(nee_bytes, &hay_bytes[st..st + nee_len]) {
+            count += 1;
+            next_st = st + step;
+            // the overlapping matches are charged, as they do not skip the haystack.
+            if !overlapping {
+                continue;
+            }
+        }
+        work += nee_len;
+        if over_budget(work, st + 1) {
+            return count + two_way_count(&hay_bytes[st + 1..], nee_bytes, overlapping);
+        }
+    }
+    count
+}
+
+#[inline]
+pub(crate) fn generic_count_iac<S: SearchStrategy>(
+    hay_bytes: &[u8],
+    nee_bytes: &[u8],
+    overlapping: bool,
+) -> usize {
+    let hay_len = hay_bytes.len();
+    let nee_len = nee_bytes.len();
+    if nee_len == 0 || hay_len < nee_len {
+        return 0;
+    }
+
+    let (pivot, guard) = S::pivots(nee_bytes);
+    let pick_byte = nee_bytes[pivot];
+    pivot_count_iac(
+        hay_bytes,
+        nee_bytes,
+        pivot,
+        pick_byte.to_ascii_uppercase(),
+        pick_byte.to_ascii_lowercase(),
+        guard,
+        overlapping,
+    )
+}
+
+#[inline]
+pub(crate) fn pivot_count_iac(
+    hay_bytes: &[u8],
+    nee_bytes: &[u8],
+    pivot: usize,
+    pick_byte_uc: u8,
+    pick_byte_lc: u8,
+    guard: Option<usize>,
+    overlapping: bool,
+) -> usize {
+    let hay_len = hay_bytes.len();
+    let nee_len = nee_bytes.len();
+    if nee_len == 0 || hay_len < nee_len {
+        return 0;
+    }
+    let step = if overlapping { 1 } else { nee_len };
+
+    let search_slice = &hay_bytes[pivot..hay_len - nee_len + pivot + 1];
+
+    let mut count = 0;
+    let mut next_st = 0;
+    let mut work = 0;
+    for m in ::memx::iter::memchr_dbl_iter(search_slice, pick_byte_uc, pick_byte_lc) {
+        let st = m;
+        if st < next_st {
+            continue;
+        }
+        if let Some(g) = guard {
+            if !hay_bytes[st + g].eq_ignore_ascii_case(&nee_bytes[g]) {
+                continue;
+            }
+        }
+        if nee_bytes.eq_ignore_ascii_case(&hay_bytes[st..st + nee_len]) {
+            count += 1;
+            next_st = st + step;
+            // the overlapping matches are charged, as they do not skip the haystack.
+            if !overlapping {
+                continue;
+            }
+        }
+        work += nee_len;
+        if over_budget(work, st + 1) {
+            return count + two_way_count_iac(&hay_bytes[st + 1..], nee_bytes, overlapping);
+        }
+    }
+    count
+}
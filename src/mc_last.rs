@@ -1,3 +1,4 @@
+use crate::mc_generic::{generic_count, generic_count_iac};
 use crate::mc_generic::{generic_rsearch, generic_rsearch_iac, generic_search, generic_search_iac, LastByte};
 
 #[inline]
@@ -19,3 +20,21 @@ pub(crate) fn naive_opt_mc_last_bytes_iac(hay_bytes: &[u8], nee_bytes: &[u8]) ->
 pub(crate) fn naive_opt_mc_last_rev_bytes_iac(hay_bytes: &[u8], nee_bytes: &[u8]) -> Option<usize> {
     generic_rsearch_iac::<LastByte>(hay_bytes, nee_bytes)
 }
+
+#[inline]
+pub(crate) fn naive_opt_mc_last_count_bytes(
+    hay_bytes: &[u8],
+    nee_bytes: &[u8],
+    overlapping: bool,
+) -> usize {
+    generic_count::<LastByte>(hay_bytes, nee_bytes, overlapping)
+}
+
+#[inline]
+pub(crate) fn naive_opt_mc_last_count_bytes_iac(
+    hay_bytes: &[u8],
+    nee_bytes: &[u8],
+    overlapping: bool,
+) -> usize {
+    generic_count_iac::<LastByte>(hay_bytes, nee_bytes, overlapping)
+}
@@ -0,0 +1,40 @@
+use crate::mc_generic::{generic_count, generic_count_iac};
+use crate::mc_generic::{generic_rsearch, generic_rsearch_iac, generic_search, generic_search_iac, RarestByte};
+
+#[inline]
+pub(crate) fn naive_opt_mc_rare_bytes(hay_bytes: &[u8], nee_bytes: &[u8]) -> Option<usize> {
+    generic_search::<RarestByte>(hay_bytes, nee_bytes)
+}
+
+#[inline]
+pub(crate) fn naive_opt_mc_rare_rev_bytes(hay_bytes: &[u8], nee_bytes: &[u8]) -> Option<usize> {
+    generic_rsearch::<RarestByte>(hay_bytes, nee_bytes)
+}
+
+#[inline]
+pub(crate) fn naive_opt_mc_rare_bytes_iac(hay_bytes: &[u8], nee_bytes: &[u8]) -> Option<usize> {
+    generic_search_iac::<RarestByte>(hay_bytes, nee_bytes)
+}
+
+#[inline]
+pub(crate) fn naive_opt_mc_rare_rev_bytes_iac(hay_bytes: &[u8], nee_bytes: &[u8]) -> Option<usize> {
+    generic_rsearch_iac::<RarestByte>(hay_bytes, nee_bytes)
+}
+
+#[inline]
+pub(crate) fn naive_opt_mc_rare_count_bytes(
+    hay_bytes: &[u8],
+    nee_bytes: &[u8],
+    overlapping: bool,
+) -> usize {
+    generic_count::<RarestByte>(hay_bytes, nee_bytes, overlapping)
+}
+
+#[inline]
+pub(crate) fn naive_opt_mc_rare_count_bytes_iac(
+    hay_bytes: &[u8],
+    nee_bytes: &[u8],
+    overlapping: bool,
+) -> usize {
+    generic_count_iac::<RarestByte>(hay_bytes, nee_bytes, overlapping)
+}
@@ -0,0 +1,393 @@
+//
+// The search in the file, with the `mmap` feature.
+//
+// The regular file is memory-mapped, and searched in place as one haystack.
+// The others, such as the pipes, the special files and the files that report
+// zero length, cannot be mapped, so that they are read by chunks with
+// `StreamSearcher`. Both yield the same matches, that are not overlapping.
+//
+// The mapping is the only `unsafe` of this crate. The `_buffered()` variants
+// never map the file, and always read it by `StreamSearcher`.
+//
+// The empty needle never matches, the same as `StreamSearcher`.
+//
+use crate::{naive_opt_mc_bytes, naive_opt_mc_bytes_iac};
+use crate::{naive_opt_mc_count_bytes, naive_opt_mc_count_bytes_iac};
+use crate::{SearchIndicesBytes, SearchIndicesBytesIgnoreAsciiCase, StreamSearcher};
+use memmap2::Mmap;
+use std::fmt;
+use std::fs::File;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::vec::Vec;
+
+///
+/// The error of the search in the file.
+///
+/// It has the path of the file and the IO error.
+///
+#[derive(Debug)]
+pub enum FileSearchError {
+    /// It failed to open the file, or to get the metadata.
+    Open(PathBuf, io::Error),
+    /// It failed to read the file.
+    Read(PathBuf, io::Error),
+}
+impl FileSearchError {
+    ///
+    /// return the path of the file.
+    ///
+    pub fn path(&self) -> &Path {
+        match self {
+            FileSearchError::Open(path, _) | FileSearchError::Read(path, _) => path,
+        }
+    }
+    ///
+    /// return the IO error.
+    ///
+    pub fn io_error(&self) -> &io::Error {
+        match self {
+            FileSearchError::Open(_, err) | FileSearchError::Read(_, err) => err,
+        }
+    }
+}
+impl fmt::Display for FileSearchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FileSearchError::Open(path, err) => {
+                write!(f, "failed to open {}: {}", path.display(), err)
+            }
+            FileSearchError::Read(path, err) => {
+                write!(f, "failed to read {}: {}", path.display(), err)
+            }
+        }
+    }
+}
+impl std::error::Error for FileSearchError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(self.io_error())
+    }
+}
+impl From<FileSearchError> for io::Error {
+    fn from(err: FileSearchError) -> io::Error {
+        io::Error::new(err.io_error().kind(), err)
+    }
+}
+
+enum Source {
+    Mapped(Mmap),
+    Stream(File),
+}
+
+// open the file, and map it if `map` is true and it is a regular file.
+fn open(path: &Path, map: bool) -> Result<Source, FileSearchError> {
+    let file = File::open(path).map_err(|e| FileSearchError::Open(path.to_path_buf(), e))?;
+    if !map {
+        return Ok(Source::Stream(file));
+    }
+    let meta = file
+        .metadata()
+        .map_err(|e| FileSearchError::Open(path.to_path_buf(), e))?;
+    if meta.is_file() && meta.len() > 0 {
+        // SAFETY: the map is read only and dropped in the search. If the file is
+        // truncated by the other process while searching, it is undefined behavior,
+        // the same as any other memory-mapped reader.
+        if let Ok(map) = unsafe { Mmap::map(&file) } {
+            return Ok(Source::Mapped(map));
+        }
+    }
+    Ok(Source::Stream(file))
+}
+
+fn stream_searcher(file: File, nee_bytes: &[u8], ignore_ascii_case: bool) -> StreamSearcher<File> {
+    if ignore_ascii_case {
+        StreamSearcher::new_ignore_ascii_case(file, nee_bytes)
+    } else {
+        StreamSearcher::new(file, nee_bytes)
+    }
+}
+
+fn search_in_file(
+    path: &Path,
+    nee_bytes: &[u8],
+    ignore_ascii_case: bool,
+    map: bool,
+) -> Result<Option<u64>, FileSearchError> {
+    if nee_bytes.is_empty() {
+        return Ok(None);
+    }
+    match open(path, map)? {
+        Source::Mapped(map) => {
+            let r = if ignore_ascii_case {
+                naive_opt_mc_bytes_iac(&map, nee_bytes)
+            } else {
+                naive_opt_mc_bytes(&map, nee_bytes)
+            };
+            Ok(r.map(|idx| idx as u64))
+        }
+        Source::Stream(file) => stream_searcher(file, nee_bytes, ignore_ascii_case)
+            .search()
+            .map_err(|e| FileSearchError::Read(path.to_path_buf(), e)),
+    }
+}
+
+fn search_indices_in_file(
+    path: &Path,
+    nee_bytes: &[u8],
+    ignore_ascii_case: bool,
+    map: bool,
+) -> Result<Vec<u64>, FileSearchError> {
+    if nee_bytes.is_empty() {
+        return Ok(Vec::new());
+    }
+    match open(path, map)? {
+        Source::Mapped(map) => {
+            let v = if ignore_ascii_case {
+                SearchIndicesBytesIgnoreAsciiCase::new(&map, nee_bytes)
+                    .map(|(idx, _)| idx as u64)
+                    .collect()
+            } else {
+                SearchIndicesBytes::new(&map, nee_bytes)
+                    .map(|(idx, _)| idx as u64)
+                    .collect()
+            };
+            Ok(v)
+        }
+        Source::Stream(file) => stream_searcher(file, nee_bytes, ignore_ascii_case)
+            .collect::<io::Result<_>>()
+            .map_err(|e| FileSearchError::Read(path.to_path_buf(), e)),
+    }
+}
+
+fn count_in_file_bytes(
+    path: &Path,
+    nee_bytes: &[u8],
+    ignore_ascii_case: bool,
+    map: bool,
+) -> Result<usize, FileSearchError> {
+    if nee_bytes.is_empty() {
+        return Ok(0);
+    }
+    match open(path, map)? {
+        Source::Mapped(map) => {
+            let r = if ignore_ascii_case {
+                naive_opt_mc_count_bytes_iac(&map, nee_bytes, false)
+            } else {
+                naive_opt_mc_count_bytes(&map, nee_bytes, false)
+            };
+            Ok(r)
+        }
+        Source::Stream(file) => {
+            let mut count = 0;
+            for r in stream_searcher(file, nee_bytes, ignore_ascii_case) {
+                r.map_err(|e| FileSearchError::Read(path.to_path_buf(), e))?;
+                count += 1;
+            }
+            Ok(count)
+        }
+    }
+}
+
+///
+/// search the needle in the file.
+///
+/// return the offset of the file, if it found the needle. Otherwise return None.
+///
+/// The regular file is memory-mapped. If the other process truncates or
+/// modifies the file while searching, it is undefined behavior, and the process
+/// may be killed by `SIGBUS`. Use [search_file_buffered()] for such a file,
+/// that always reads the file with the buffered IO.
+///
+/// Examples
+///
+/// ```rust
+/// use naive_opt::{search_file, count_in_file};
+///
+/// let path = std::env::temp_dir().join("naive_opt_doc_search_file.txt");
+/// std::fs::write(&path, "abc345abc901abc").unwrap();
+/// assert_eq!(search_file(&path, "901").unwrap(), Some(9));
+/// assert_eq!(count_in_file(&path, "abc").unwrap(), 3);
+/// # std::fs::remove_file(&path).unwrap();
+///
+/// assert!(search_file("/nonexistent/naive_opt", "abc").is_err());
+/// ```
+///
+pub fn search_file<P, B>(path: P, needle: &B) -> Result<Option<u64>, FileSearchError>
+where
+    P: AsRef<Path>,
+    B: ?Sized + AsRef<[u8]>,
+{
+    search_in_file(path.as_ref(), needle.as_ref(), false, true)
+}
+
+///
+/// search the needle in the file, ignore ascii case.
+///
+/// return the offset of the file, if it found the needle. Otherwise return None.
+///
+/// The file is memory-mapped, see [search_file()].
+///
+pub fn search_file_ignore_ascii_case<P, B>(
+    path: P,
+    needle: &B,
+) -> Result<Option<u64>, FileSearchError>
+where
+    P: AsRef<Path>,
+    B: ?Sized + AsRef<[u8]>,
+{
+    search_in_file(path.as_ref(), needle.as_ref(), true, true)
+}
+
+///
+/// return the offsets of the needles in the file, that are not overlapping.
+///
+/// The regular file is memory-mapped, and the truncation of it while searching
+/// is undefined behavior or `SIGBUS`, see [search_file()].
+/// Use [search_file_indices_buffered()] for such a file.
+///
+pub fn search_file_indices<P, B>(path: P, needle: &B) -> Result<Vec<u64>, FileSearchError>
+where
+    P: AsRef<Path>,
+    B: ?Sized + AsRef<[u8]>,
+{
+    search_indices_in_file(path.as_ref(), needle.as_ref(), false, true)
+}
+
+///
+/// return the offsets of the needles in the file, that are not overlapping, ignore ascii case.
+///
+/// The file is memory-mapped, see [search_file_indices()].
+///
+pub fn search_file_indices_ignore_ascii_case<P, B>(
+    path: P,
+    needle: &B,
+) -> Result<Vec<u64>, FileSearchError>
+where
+    P: AsRef<Path>,
+    B: ?Sized + AsRef<[u8]>,
+{
+    search_indices_in_file(path.as_ref(), needle.as_ref(), true, true)
+}
+
+///
+/// return the count of the needles in the file, that are not overlapping.
+///
+/// The regular file is memory-mapped, and the truncation of it while searching
+/// is undefined behavior or `SIGBUS`, see [search_file()].
+/// Use [count_in_file_buffered()] for such a file.
+///
+pub fn count_in_file<P, B>(path: P, needle: &B) -> Result<usize, FileSearchError>
+where
+    P: AsRef<Path>,
+    B: ?Sized + AsRef<[u8]>,
+{
+    count_in_file_bytes(path.as_ref(), needle.as_ref(), false, true)
+}
+
+///
+/// return the count of the needles in the file, that are not overlapping, ignore ascii case.
+///
+/// The file is memory-mapped, see [count_in_file()].
+///
+pub fn count_in_file_ignore_ascii_case<P, B>(path: P, needle: &B) -> Result<usize, FileSearchError>
+where
+    P: AsRef<Path>,
+    B: ?Sized + AsRef<[u8]>,
+{
+    count_in_file_bytes(path.as_ref(), needle.as_ref(), true, true)
+}
+
+///
+/// search the needle in the file, by the buffered IO without the map.
+///
+/// return the offset of the file, if it found the needle. Otherwise return None.
+///
+/// Examples
+///
+/// ```rust
+/// use naive_opt::{search_file_buffered, count_in_file_buffered};
+///
+/// let path = std::env::temp_dir().join("naive_opt_doc_search_file_buffered.txt");
+/// std::fs::write(&path, "abc345abc901abc").unwrap();
+/// assert_eq!(search_file_buffered(&path, "901").unwrap(), Some(9));
+/// assert_eq!(count_in_file_buffered(&path, "abc").unwrap(), 3);
+/// # std::fs::remove_file(&path).unwrap();
+/// ```
+///
+pub fn search_file_buffered<P, B>(path: P, needle: &B) -> Result<Option<u64>, FileSearchError>
+where
+    P: AsRef<Path>,
+    B: ?Sized + AsRef<[u8]>,
+{
+    search_in_file(path.as_ref(), needle.as_ref(), false, false)
+}
+
+///
+/// search the needle in the file, by the buffered IO without the map, ignore ascii case.
+///
+/// return the offset of the file, if it found the needle. Otherwise return None.
+///
+pub fn search_file_buffered_ignore_ascii_case<P, B>(
+    path: P,
+    needle: &B,
+) -> Result<Option<u64>, FileSearchError>
+where
+    P: AsRef<Path>,
+    B: ?Sized + AsRef<[u8]>,
+{
+    search_in_file(path.as_ref(), needle.as_ref(), true, false)
+}
+
+///
+/// return the offsets of the needles in the file, that are not overlapping,
+/// by the buffered IO without the map.
+///
+pub fn search_file_indices_buffered<P, B>(path: P, needle: &B) -> Result<Vec<u64>, FileSearchError>
+where
+    P: AsRef<Path>,
+    B: ?Sized + AsRef<[u8]>,
+{
+    search_indices_in_file(path.as_ref(), needle.as_ref(), false, false)
+}
+
+///
+/// return the offsets of the needles in the file, that are not overlapping,
+/// by the buffered IO without the map, ignore ascii case.
+///
+pub fn search_file_indices_buffered_ignore_ascii_case<P, B>(
+    path: P,
+    needle: &B,
+) -> Result<Vec<u64>, FileSearchError>
+where
+    P: AsRef<Path>,
+    B: ?Sized + AsRef<[u8]>,
+{
+    search_indices_in_file(path.as_ref(), needle.as_ref(), true, false)
+}
+
+///
+/// return the count of the needles in the file, that are not overlapping,
+/// by the buffered IO without the map.
+///
+pub fn count_in_file_buffered<P, B>(path: P, needle: &B) -> Result<usize, FileSearchError>
+where
+    P: AsRef<Path>,
+    B: ?Sized + AsRef<[u8]>,
+{
+    count_in_file_bytes(path.as_ref(), needle.as_ref(), false, false)
+}
+
+///
+/// return the count of the needles in the file, that are not overlapping,
+/// by the buffered IO without the map, ignore ascii case.
+///
+pub fn count_in_file_buffered_ignore_ascii_case<P, B>(
+    path: P,
+    needle: &B,
+) -> Result<usize, FileSearchError>
+where
+    P: AsRef<Path>,
+    B: ?Sized + AsRef<[u8]>,
+{
+    count_in_file_bytes(path.as_ref(), needle.as_ref(), true, false)
+}
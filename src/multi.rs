@@ -0,0 +1,401 @@
+//
+// The multi-needle search.
+//
+// Each needle picks its rarest byte as the pivot, by the stochastics.
+// The pick bytes are shared among the needles as possible, and they are
+// scanned by `PickScanner` in the groups of up to 4 bytes.
+//
+// When a candidate is found, the needles that have the pick byte are
+// confirmed at the offset of its pivot. A needle with larger pivot offset
+// may start before the found match, so the scanning continues
+// until the maximum pivot offset.
+//
+use crate::picks::PickScanner;
+use alloc::vec;
+use alloc::vec::Vec;
+
+// the groups of the pick bytes, for all the 256 bytes.
+const MAX_GROUPS: usize = 64;
+
+///
+/// A searcher of the multiple needles.
+///
+/// It finds the leftmost (or rightmost) match among all the needles in one pass,
+/// and returns the index of the haystack, the index of the matched needle
+/// and the matched slice. If several needles match at the same index,
+/// the needle given first wins.
+///
+/// The matches of the bytes needles in the `str` haystack, that are not on
+/// the char boundaries, are skipped.
+///
+/// Examples
+///
+/// ```rust
+/// use naive_opt::MultiSearch;
+///
+/// let ms = MultiSearch::new(["cat", "dog", "bird"]);
+/// let haystack = "a dog and a cat and a bird";
+/// assert_eq!(ms.search(haystack), Some((2, 1, "dog")));
+/// assert_eq!(ms.rsearch(haystack), Some((22, 2, "bird")));
+///
+/// let v: Vec<_> = ms.search_indices(haystack).collect();
+/// assert_eq!(v, [(2, 1, "dog"), (12, 0, "cat"), (22, 2, "bird")]);
+/// ```
+///
+#[derive(Debug, Clone)]
+pub struct MultiSearch<'n> {
+    needles: Vec<&'n [u8]>,
+    // the (needle id, pivot) sorted by the pick byte.
+    entries: Vec<(usize, usize)>,
+    // the entries of the pick byte `b` are `entries[offsets[b]..offsets[b + 1]]`.
+    offsets: Vec<usize>,
+    picks: Vec<u8>,
+    max_pivot: usize,
+    min_pivot: usize,
+    // the first needle of empty
+    empty_id: Option<usize>,
+}
+
+impl<'n> MultiSearch<'n> {
+    ///
+    /// create a new searcher of the needles.
+    ///
+    pub fn new<I, B>(needles: I) -> MultiSearch<'n>
+    where
+        I: IntoIterator<Item = &'n B>,
+        B: ?Sized + AsRef<[u8]> + 'n,
+    {
+        let needles: Vec<&'n [u8]> = needles.into_iter().map(|b| b.as_ref()).collect();
+        let mut picks: Vec<u8> = Vec::new();
+        let mut pivots: Vec<(u8, usize, usize)> = Vec::with_capacity(needles.len());
+        let mut empty_id = None;
+        for (id, nee_bytes) in needles.iter().enumerate() {
+            if nee_bytes.is_empty() {
+                if empty_id.is_none() {
+                    empty_id = Some(id);
+                }
+                continue;
+            }
+            // the rarest byte, and the pick byte already shared at the same weight.
+            let mut pivot = 0;
+            let mut weight = crate::byte_weight(nee_bytes[0]);
+            let mut shared = picks.contains(&nee_bytes[0]);
+            for (i, &byte) in nee_bytes.iter().enumerate().skip(1) {
+                let w = crate::byte_weight(byte);
+                if w < weight || (w == weight && !shared && picks.contains(&byte)) {
+                    pivot = i;
+                    weight = w;
+                    shared = picks.contains(&byte);
+                }
+            }
+            let pick_byte = nee_bytes[pivot];
+            if !picks.contains(&pick_byte) {
+                picks.push(pick_byte);
+            }
+            pivots.push((pick_byte, id, pivot));
+        }
+        pivots.sort_unstable();
+        let mut offsets = vec![0; 257];
+        for &(pick_byte, _, _) in &pivots {
+            offsets[pick_byte as usize + 1] += 1;
+        }
+        for i in 1..offsets.len() {
+            offsets[i] += offsets[i - 1];
+        }
+        let max_pivot = pivots.iter().map(|a| a.2).max().unwrap_or(0);
+        let min_pivot = pivots.iter().map(|a| a.2).min().unwrap_or(0);
+        let entries = pivots.into_iter().map(|(_, id, pv)| (id, pv)).collect();
+        MultiSearch {
+            needles,
+            entries,
+            offsets,
+            picks,
+            max_pivot,
+            min_pivot,
+            empty_id,
+        }
+    }
+    ///
+    /// return the needles of self.
+    ///
+    #[inline]
+    pub fn needles(&self) -> &[&'n [u8]] {
+        &self.needles
+    }
+    ///
+    /// search the needles in the haystack.
+    ///
+    /// return the index of the haystack, the index of the needle and the matched slice,
+    /// if it found the needle. Otherwise return None.
+    ///
+    #[inline]
+    pub fn search<'h>(&self, haystack: &'h str) -> Option<(usize, usize, &'h str)> {
+        self.search_str_idx(haystack)
+            .map(|(st, id)| (st, id, &haystack[st..st + self.needles[id].len()]))
+    }
+    ///
+    /// reverse search the needles in the haystack.
+    ///
+    /// return the index of the haystack, the index of the needle and the matched slice,
+    /// if it found the needle. Otherwise return None.
+    ///
+    #[inline]
+    pub fn rsearch<'h>(&self, haystack: &'h str) -> Option<(usize, usize, &'h str)> {
+        self.rsearch_by(haystack.as_bytes(), |st, ed| {
+            haystack.is_char_boundary(st) && haystack.is_char_boundary(ed)
+        })
+        .map(|(st, id)| (st, id, &haystack[st..st + self.needles[id].len()]))
+    }
+    ///
+    /// search the needles in the haystack bytes.
+    ///
+    #[inline]
+    pub fn search_bytes<'h>(&self, haystack: &'h [u8]) -> Option<(usize, usize, &'h [u8])> {
+        self.search_bytes_idx(haystack)
+            .map(|(st, id)| (st, id, &haystack[st..st + self.needles[id].len()]))
+    }
+    ///
+    /// reverse search the needles in the haystack bytes.
+    ///
+    #[inline]
+    pub fn rsearch_bytes<'h>(&self, haystack: &'h [u8]) -> Option<(usize, usize, &'h [u8])> {
+        self.rsearch_by(haystack, |_, _| true)
+            .map(|(st, id)| (st, id, &haystack[st..st + self.needles[id].len()]))
+    }
+    ///
+    /// includes any of the needles in the haystack.
+    ///
+    #[inline]
+    pub fn includes(&self, haystack: &str) -> bool {
+        self.search_str_idx(haystack).is_some()
+    }
+    ///
+    /// includes any of the needles in the haystack bytes.
+    ///
+    #[inline]
+    pub fn includes_bytes(&self, haystack: &[u8]) -> bool {
+        self.search_bytes_idx(haystack).is_some()
+    }
+    ///
+    /// An iterator over the matches of the needles in the haystack.
+    ///
+    #[inline]
+    pub fn search_indices<'h, 's>(&'s self, haystack: &'h str) -> MultiSearchIndices<'h, 's, 'n> {
+        MultiSearchIndices {
+            curr_idx: 0,
+            haystack,
+            searcher: self,
+        }
+    }
+    ///
+    /// An iterator over the matches of the needles in the haystack bytes.
+    ///
+    #[inline]
+    pub fn search_indices_bytes<'h, 's>(
+        &'s self,
+        haystack: &'h [u8],
+    ) -> MultiSearchIndicesBytes<'h, 's, 'n> {
+        MultiSearchIndicesBytes {
+            curr_idx: 0,
+            haystack,
+            searcher: self,
+        }
+    }
+}
+
+impl MultiSearch<'_> {
+    // confirm the needles of the pick byte at `pos`, and return the best match.
+    // the best is the smallest index, or the smallest id if at the same index.
+    #[inline]
+    fn confirm<F: Fn(usize, usize) -> bool>(
+        &self,
+        hay_bytes: &[u8],
+        pos: usize,
+        best: &mut Option<(usize, usize)>,
+        accept: &F,
+    ) {
+        let byte = hay_bytes[pos] as usize;
+        for &(id, pivot) in &self.entries[self.offsets[byte]..self.offsets[byte + 1]] {
+            let nee_bytes = self.needles[id];
+            if pos < pivot {
+                continue;
+            }
+            let st = pos - pivot;
+            if st + nee_bytes.len() > hay_bytes.len() {
+                continue;
+            }
+            if let Some((best_st, best_id)) = *best {
+                if st > best_st || (st == best_st && id > best_id) {
+                    continue;
+                }
+            }
+            if ::memx::memeq(nee_bytes, &hay_bytes[st..st + nee_bytes.len()])
+                && accept(st, st + nee_bytes.len())
+            {
+                *best = Some((st, id));
+            }
+        }
+    }
+    // the same as `confirm()`, but the best is the largest index.
+    #[inline]
+    fn rconfirm<F: Fn(usize, usize) -> bool>(
+        &self,
+        hay_bytes: &[u8],
+        pos: usize,
+        best: &mut Option<(usize, usize)>,
+        accept: &F,
+    ) {
+        let byte = hay_bytes[pos] as usize;
+        for &(id, pivot) in &self.entries[self.offsets[byte]..self.offsets[byte + 1]] {
+            let nee_bytes = self.needles[id];
+            if pos < pivot {
+                continue;
+            }
+            let st = pos - pivot;
+            if st + nee_bytes.len() > hay_bytes.len() {
+                continue;
+            }
+            if let Some((best_st, best_id)) = *best {
+                if st < best_st || (st == best_st && id > best_id) {
+                    continue;
+                }
+            }
+            if ::memx::memeq(nee_bytes, &hay_bytes[st..st + nee_bytes.len()])
+                && accept(st, st + nee_bytes.len())
+            {
+                *best = Some((st, id));
+            }
+        }
+    }
+    #[inline]
+    fn search_bytes_idx(&self, hay_bytes: &[u8]) -> Option<(usize, usize)> {
+        self.search_by(hay_bytes, |_, _| true)
+    }
+    #[inline]
+    fn search_str_idx(&self, haystack: &str) -> Option<(usize, usize)> {
+        self.search_by(haystack.as_bytes(), |st, ed| {
+            haystack.is_char_boundary(st) && haystack.is_char_boundary(ed)
+        })
+    }
+    fn search_by<F>(&self, hay_bytes: &[u8], accept: F) -> Option<(usize, usize)>
+    where
+        F: Fn(usize, usize) -> bool,
+    {
+        let mut best = None;
+        if let Some(empty_id) = self.empty_id {
+            // only the needles before the empty needle can win at 0.
+            for (id, nee_bytes) in self.needles[..empty_id].iter().enumerate() {
+                if hay_bytes.starts_with(nee_bytes) && accept(0, nee_bytes.len()) {
+                    return Some((0, id));
+                }
+            }
+            return Some((0, empty_id));
+        }
+        let mut scanner = PickScanner::<MAX_GROUPS>::new(&self.picks);
+        let mut pos = 0;
+        while pos < hay_bytes.len() {
+            if let Some((best_st, _)) = best {
+                if pos > best_st + self.max_pivot {
+                    break;
+                }
+            }
+            match scanner.next_candidate(hay_bytes, pos) {
+                Some(idx) => {
+                    self.confirm(hay_bytes, idx, &mut best, &accept);
+                    pos = idx + 1;
+                }
+                None => break,
+            }
+        }
+        best
+    }
+    fn rsearch_by<F>(&self, hay_bytes: &[u8], accept: F) -> Option<(usize, usize)>
+    where
+        F: Fn(usize, usize) -> bool,
+    {
+        if let Some(empty_id) = self.empty_id {
+            return Some((hay_bytes.len(), empty_id));
+        }
+        let mut scanner = PickScanner::<MAX_GROUPS>::new(&self.picks);
+        let mut best = None;
+        let mut pos = hay_bytes.len();
+        while pos > 0 {
+            if let Some((best_st, _)) = best {
+                if pos <= best_st + self.min_pivot {
+                    break;
+                }
+            }
+            match scanner.prev_candidate(hay_bytes, pos) {
+                Some(idx) => {
+                    self.rconfirm(hay_bytes, idx, &mut best, &accept);
+                    pos = idx;
+                }
+                None => break,
+            }
+        }
+        best
+    }
+}
+
+///
+/// Created with the method [MultiSearch::search_indices()].
+///
+#[derive(Debug, Clone)]
+pub struct MultiSearchIndices<'h, 's, 'n> {
+    curr_idx: usize,
+    haystack: &'h str,
+    searcher: &'s MultiSearch<'n>,
+}
+impl<'h> Iterator for MultiSearchIndices<'h, '_, '_> {
+    type Item = (usize, usize, &'h str);
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.searcher.empty_id.is_some() {
+            return None;
+        }
+        // `curr_idx` is always on the char boundary.
+        match self
+            .searcher
+            .search_str_idx(&self.haystack[self.curr_idx..])
+        {
+            Some((idx, id)) => {
+                let st = self.curr_idx + idx;
+                let ed = st + self.searcher.needles[id].len();
+                self.curr_idx = ed;
+                Some((st, id, &self.haystack[st..ed]))
+            }
+            None => None,
+        }
+    }
+}
+
+///
+/// Created with the method [MultiSearch::search_indices_bytes()].
+///
+#[derive(Debug, Clone)]
+pub struct MultiSearchIndicesBytes<'h, 's, 'n> {
+    curr_idx: usize,
+    haystack: &'h [u8],
+    searcher: &'s MultiSearch<'n>,
+}
+impl<'h> Iterator for MultiSearchIndicesBytes<'h, '_, '_> {
+    type Item = (usize, usize, &'h [u8]);
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.searcher.empty_id.is_some() {
+            return None;
+        }
+        match self
+            .searcher
+            .search_bytes_idx(&self.haystack[self.curr_idx..])
+        {
+            Some((idx, id)) => {
+                let st = self.curr_idx + idx;
+                let ed = st + self.searcher.needles[id].len();
+                self.curr_idx = ed;
+                Some((st, id, &self.haystack[st..ed]))
+            }
+            None => None,
+        }
+    }
+}
@@ -0,0 +1,213 @@
+//
+// The search in `OsStr` and `Path`.
+//
+// On unix, `OsStr` is arbitrary bytes, so that it is searched by the same
+// engine as `SearchBytes`, without the lossy conversion to `str`.
+// The indices are the byte offsets, and the matches are the sub-slices
+// of `OsStr`, built with `OsStrExt::from_bytes()`.
+//
+use crate::container::impl_needle_bytes;
+use crate::{RevSearchIndicesBytes, RevSearchIndicesBytesIgnoreAsciiCase};
+use crate::{SearchInBytes, SearchIndicesBytes, SearchIndicesBytesIgnoreAsciiCase};
+use core::iter::FusedIterator;
+use std::ffi::{OsStr, OsString};
+use std::os::unix::ffi::OsStrExt;
+use std::path::{Path, PathBuf};
+
+///
+/// search the needle in `OsStr`, on unix.
+///
+/// It is implemented for the types of `AsRef<OsStr>`, such as `&OsStr`, `OsString`,
+/// `&Path` and `PathBuf`. The needle is the same as of `SearchBytes`,
+/// and also `&OsStr` and `&Path`.
+///
+/// Examples
+///
+/// ```rust
+/// use naive_opt::SearchOsStr;
+/// use std::ffi::OsStr;
+/// use std::path::Path;
+///
+/// let path = Path::new("/usr/local/lib/liblocal.so");
+/// assert_eq!(path.search_os("local"), Some(5));
+/// assert_eq!(path.rsearch_os(OsStr::new("local")), Some(18));
+/// assert_eq!(path.search_os_ignore_ascii_case("LIB"), Some(11));
+///
+/// let v: Vec<_> = path.search_indices_os('/').map(|(i, _)| i).collect();
+/// assert_eq!(v, [0, 4, 10, 14]);
+/// ```
+///
+pub trait SearchOsStr {
+    ///
+    /// search the needle in self.
+    ///
+    /// return the byte offset of self, if it found the needle. Otherwise return None.
+    ///
+    fn search_os<'a, P: SearchInBytes<'a>>(&'a self, needle: P) -> Option<usize>;
+    ///
+    /// reverse search the needle in self.
+    ///
+    /// return the byte offset of self, if it found the needle. Otherwise return None.
+    ///
+    fn rsearch_os<'a, P: SearchInBytes<'a>>(&'a self, needle: P) -> Option<usize>;
+    ///
+    /// return true if self includes the needle.
+    ///
+    fn includes_os<'a, P: SearchInBytes<'a>>(&'a self, needle: P) -> bool;
+    ///
+    /// an iterator over the matches of the needle in self.
+    ///
+    /// It yields the byte offset and the matched `&OsStr`.
+    ///
+    fn search_indices_os<'a, P>(&'a self, needle: P) -> SearchIndicesOs<SearchIndicesBytes<'a, P>>
+    where
+        P: SearchInBytes<'a>;
+    ///
+    /// an iterator over the matches of the needle in self, in reverse order.
+    ///
+    fn rsearch_indices_os<'a, P>(
+        &'a self,
+        needle: P,
+    ) -> SearchIndicesOs<RevSearchIndicesBytes<'a, P>>
+    where
+        P: SearchInBytes<'a>;
+    //
+    fn search_os_ignore_ascii_case<'a, P: SearchInBytes<'a>>(&'a self, needle: P) -> Option<usize>;
+    fn rsearch_os_ignore_ascii_case<'a, P: SearchInBytes<'a>>(&'a self, needle: P)
+        -> Option<usize>;
+    fn includes_os_ignore_ascii_case<'a, P: SearchInBytes<'a>>(&'a self, needle: P) -> bool;
+    fn search_indices_os_ignore_ascii_case<'a, P>(
+        &'a self,
+        needle: P,
+    ) -> SearchIndicesOs<SearchIndicesBytesIgnoreAsciiCase<'a, P>>
+    where
+        P: SearchInBytes<'a>;
+    fn rsearch_indices_os_ignore_ascii_case<'a, P>(
+        &'a self,
+        needle: P,
+    ) -> SearchIndicesOs<RevSearchIndicesBytesIgnoreAsciiCase<'a, P>>
+    where
+        P: SearchInBytes<'a>;
+}
+impl<T: AsRef<OsStr>> SearchOsStr for T {
+    #[inline]
+    fn search_os<'a, P: SearchInBytes<'a>>(&'a self, needle: P) -> Option<usize> {
+        needle.search_in(self.as_ref().as_bytes())
+    }
+    #[inline]
+    fn rsearch_os<'a, P: SearchInBytes<'a>>(&'a self, needle: P) -> Option<usize> {
+        needle.rsearch_in(self.as_ref().as_bytes())
+    }
+    #[inline]
+    fn includes_os<'a, P: SearchInBytes<'a>>(&'a self, needle: P) -> bool {
+        needle.includes_in(self.as_ref().as_bytes())
+    }
+    #[inline]
+    fn search_indices_os<'a, P>(&'a self, needle: P) -> SearchIndicesOs<SearchIndicesBytes<'a, P>>
+    where
+        P: SearchInBytes<'a>,
+    {
+        SearchIndicesOs::new(SearchIndicesBytes::new(self.as_ref().as_bytes(), needle))
+    }
+    #[inline]
+    fn rsearch_indices_os<'a, P>(
+        &'a self,
+        needle: P,
+    ) -> SearchIndicesOs<RevSearchIndicesBytes<'a, P>>
+    where
+        P: SearchInBytes<'a>,
+    {
+        SearchIndicesOs::new(RevSearchIndicesBytes::new(self.as_ref().as_bytes(), needle))
+    }
+    //
+    #[inline]
+    fn search_os_ignore_ascii_case<'a, P: SearchInBytes<'a>>(&'a self, needle: P) -> Option<usize> {
+        needle.search_in_ignore_ascii_case(self.as_ref().as_bytes())
+    }
+    #[inline]
+    fn rsearch_os_ignore_ascii_case<'a, P: SearchInBytes<'a>>(
+        &'a self,
+        needle: P,
+    ) -> Option<usize> {
+        needle.rsearch_in_ignore_ascii_case(self.as_ref().as_bytes())
+    }
+    #[inline]
+    fn includes_os_ignore_ascii_case<'a, P: SearchInBytes<'a>>(&'a self, needle: P) -> bool {
+        needle.includes_in_ignore_ascii_case(self.as_ref().as_bytes())
+    }
+    #[inline]
+    fn search_indices_os_ignore_ascii_case<'a, P>(
+        &'a self,
+        needle: P,
+    ) -> SearchIndicesOs<SearchIndicesBytesIgnoreAsciiCase<'a, P>>
+    where
+        P: SearchInBytes<'a>,
+    {
+        SearchIndicesOs::new(SearchIndicesBytesIgnoreAsciiCase::new(
+            self.as_ref().as_bytes(),
+            needle,
+        ))
+    }
+    #[inline]
+    fn rsearch_indices_os_ignore_ascii_case<'a, P>(
+        &'a self,
+        needle: P,
+    ) -> SearchIndicesOs<RevSearchIndicesBytesIgnoreAsciiCase<'a, P>>
+    where
+        P: SearchInBytes<'a>,
+    {
+        SearchIndicesOs::new(RevSearchIndicesBytesIgnoreAsciiCase::new(
+            self.as_ref().as_bytes(),
+            needle,
+        ))
+    }
+}
+
+///
+/// Created with the method [SearchOsStr::search_indices_os()]
+/// and the other indices methods of [SearchOsStr].
+///
+/// It converts the matches of the bytes iterator `I` to `&OsStr`.
+///
+#[derive(Debug, Clone)]
+pub struct SearchIndicesOs<I> {
+    matches: I,
+}
+impl<I> SearchIndicesOs<I> {
+    fn new(a_matches: I) -> Self {
+        SearchIndicesOs { matches: a_matches }
+    }
+}
+impl<'a, I: Iterator<Item = (usize, &'a [u8])>> Iterator for SearchIndicesOs<I> {
+    type Item = (usize, &'a OsStr);
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.matches
+            .next()
+            .map(|(idx, s)| (idx, OsStr::from_bytes(s)))
+    }
+}
+impl<'a, I> DoubleEndedIterator for SearchIndicesOs<I>
+where
+    I: DoubleEndedIterator<Item = (usize, &'a [u8])>,
+{
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.matches
+            .next_back()
+            .map(|(idx, s)| (idx, OsStr::from_bytes(s)))
+    }
+}
+impl<'a, I: FusedIterator<Item = (usize, &'a [u8])>> FusedIterator for SearchIndicesOs<I> {}
+
+impl_needle_bytes! {
+    SearchInBytes, [u8], |haystack| haystack, |needle| needle.as_bytes(),
+    [] &OsStr,
+    [] &OsString,
+}
+
+impl_needle_bytes! {
+    SearchInBytes, [u8], |haystack| haystack, |needle| needle.as_os_str().as_bytes(),
+    [] &Path,
+    [] &PathBuf,
+}
@@ -0,0 +1,338 @@
+use crate::{SearchIn, SearchInBytes};
+
+//
+// The overlapping iterators restart the search at the next character
+// (the next byte for bytes) after the start of each match, instead of
+// the end of the match.
+//
+
+///
+/// Created with the method [Search::search_indices_overlapping()](crate::Search::search_indices_overlapping).
+///
+pub struct SearchIndicesOverlapping<'a, P: SearchIn<'a>> {
+    curr_idx: usize,
+    haystack: &'a str,
+    needle: P,
+}
+impl<'a, P: SearchIn<'a>> SearchIndicesOverlapping<'a, P> {
+    pub(crate) fn new(a_haystack: &'a str, a_needle: P) -> SearchIndicesOverlapping<'a, P> {
+        SearchIndicesOverlapping {
+            curr_idx: 0,
+            haystack: a_haystack,
+            needle: a_needle,
+        }
+    }
+}
+impl<'a, P: SearchIn<'a>> Iterator for SearchIndicesOverlapping<'a, P> {
+    type Item = (usize, &'a str);
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.needle.max_match_len() == 0 {
+            return None;
+        }
+        match self.needle.search_in(&self.haystack[self.curr_idx..]) {
+            Some(idx) => {
+                let st = self.curr_idx + idx;
+                let ed = st + self.needle.match_len_at(self.haystack, st);
+                let mut next_idx = st + 1;
+                while !self.haystack.is_char_boundary(next_idx) {
+                    next_idx += 1;
+                }
+                self.curr_idx = next_idx;
+                Some((st, &self.haystack[st..ed]))
+            }
+            None => None,
+        }
+    }
+}
+
+///
+/// Created with the method [SearchBytes::search_indices_overlapping_bytes()](crate::SearchBytes::search_indices_overlapping_bytes).
+///
+pub struct SearchIndicesOverlappingBytes<'a, P: SearchInBytes<'a>> {
+    curr_idx: usize,
+    haystack: &'a [u8],
+    needle: P,
+}
+impl<'a, P: SearchInBytes<'a>> SearchIndicesOverlappingBytes<'a, P> {
+    pub(crate) fn new(a_haystack: &'a [u8], a_needle: P) -> SearchIndicesOverlappingBytes<'a, P> {
+        SearchIndicesOverlappingBytes {
+            curr_idx: 0,
+            haystack: a_haystack,
+            needle: a_needle,
+        }
+    }
+}
+impl<'a, P: SearchInBytes<'a>> Iterator for SearchIndicesOverlappingBytes<'a, P> {
+    type Item = (usize, &'a [u8]);
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.needle.max_match_len() == 0 {
+            return None;
+        }
+        match self.needle.search_in(&self.haystack[self.curr_idx..]) {
+            Some(idx) => {
+                let st = self.curr_idx + idx;
+                let ed = st + self.needle.match_len_at(self.haystack, st);
+                self.curr_idx = st + 1;
+                Some((st, &self.haystack[st..ed]))
+            }
+            None => None,
+        }
+    }
+}
+
+///
+/// Created with the method [Search::rsearch_indices_overlapping()](crate::Search::rsearch_indices_overlapping).
+///
+pub struct RevSearchIndicesOverlapping<'a, P: SearchIn<'a>> {
+    curr_ed: usize,
+    haystack: &'a str,
+    needle: P,
+}
+impl<'a, P: SearchIn<'a>> RevSearchIndicesOverlapping<'a, P> {
+    pub(crate) fn new(a_haystack: &'a str, a_needle: P) -> RevSearchIndicesOverlapping<'a, P> {
+        RevSearchIndicesOverlapping {
+            curr_ed: a_haystack.len(),
+            haystack: a_haystack,
+            needle: a_needle,
+        }
+    }
+}
+impl<'a, P: SearchIn<'a>> Iterator for RevSearchIndicesOverlapping<'a, P> {
+    type Item = (usize, &'a str);
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.needle.max_match_len() == 0 {
+            return None;
+        }
+        match self.needle.rsearch_in(&self.haystack[0..self.curr_ed]) {
+            Some(idx) => {
+                let st = idx;
+                let ed = st + self.needle.match_len_at(self.haystack, st);
+                let mut next_ed = ed - 1;
+                while !self.haystack.is_char_boundary(next_ed) {
+                    next_ed -= 1;
+                }
+                self.curr_ed = next_ed;
+                Some((st, &self.haystack[st..ed]))
+            }
+            None => None,
+        }
+    }
+}
+
+///
+/// Created with the method [SearchBytes::rsearch_indices_overlapping_bytes()](crate::SearchBytes::rsearch_indices_overlapping_bytes).
+///
+pub struct RevSearchIndicesOverlappingBytes<'a, P: SearchInBytes<'a>> {
+    curr_ed: usize,
+    haystack: &'a [u8],
+    needle: P,
+}
+impl<'a, P: SearchInBytes<'a>> RevSearchIndicesOverlappingBytes<'a, P> {
+    pub(crate) fn new(
+        a_haystack: &'a [u8],
+        a_needle: P,
+    ) -> RevSearchIndicesOverlappingBytes<'a, P> {
+        RevSearchIndicesOverlappingBytes {
+            curr_ed: a_haystack.len(),
+            haystack: a_haystack,
+            needle: a_needle,
+        }
+    }
+}
+impl<'a, P: SearchInBytes<'a>> Iterator for RevSearchIndicesOverlappingBytes<'a, P> {
+    type Item = (usize, &'a [u8]);
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.needle.max_match_len() == 0 {
+            return None;
+        }
+        match self.needle.rsearch_in(&self.haystack[0..self.curr_ed]) {
+            Some(idx) => {
+                let st = idx;
+                let ed = st + self.needle.match_len_at(self.haystack, st);
+                self.curr_ed = ed - 1;
+                Some((st, &self.haystack[st..ed]))
+            }
+            None => None,
+        }
+    }
+}
+
+///
+/// Created with the method [Search::search_indices_overlapping_ignore_ascii_case()](crate::Search::search_indices_overlapping_ignore_ascii_case).
+///
+pub struct SearchIndicesOverlappingIgnoreAsciiCase<'a, P: SearchIn<'a>> {
+    curr_idx: usize,
+    haystack: &'a str,
+    needle: P,
+}
+impl<'a, P: SearchIn<'a>> SearchIndicesOverlappingIgnoreAsciiCase<'a, P> {
+    pub(crate) fn new(
+        a_haystack: &'a str,
+        a_needle: P,
+    ) -> SearchIndicesOverlappingIgnoreAsciiCase<'a, P> {
+        SearchIndicesOverlappingIgnoreAsciiCase {
+            curr_idx: 0,
+            haystack: a_haystack,
+            needle: a_needle,
+        }
+    }
+}
+impl<'a, P: SearchIn<'a>> Iterator for SearchIndicesOverlappingIgnoreAsciiCase<'a, P> {
+    type Item = (usize, &'a str);
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.needle.max_match_len() == 0 {
+            return None;
+        }
+        match self
+            .needle
+            .search_in_ignore_ascii_case(&self.haystack[self.curr_idx..])
+        {
+            Some(idx) => {
+                let st = self.curr_idx + idx;
+                let ed = st + self.needle.match_len_at(self.haystack, st);
+                let mut next_idx = st + 1;
+                while !self.haystack.is_char_boundary(next_idx) {
+                    next_idx += 1;
+                }
+                self.curr_idx = next_idx;
+                Some((st, &self.haystack[st..ed]))
+            }
+            None => None,
+        }
+    }
+}
+
+///
+/// Created with the method [SearchBytes::search_indices_overlapping_bytes_ignore_ascii_case()](crate::SearchBytes::search_indices_overlapping_bytes_ignore_ascii_case).
+///
+pub struct SearchIndicesOverlappingBytesIgnoreAsciiCase<'a, P: SearchInBytes<'a>> {
+    curr_idx: usize,
+    haystack: &'a [u8],
+    needle: P,
+}
+impl<'a, P: SearchInBytes<'a>> SearchIndicesOverlappingBytesIgnoreAsciiCase<'a, P> {
+    pub(crate) fn new(
+        a_haystack: &'a [u8],
+        a_needle: P,
+    ) -> SearchIndicesOverlappingBytesIgnoreAsciiCase<'a, P> {
+        SearchIndicesOverlappingBytesIgnoreAsciiCase {
+            curr_idx: 0,
+            haystack: a_haystack,
+            needle: a_needle,
+        }
+    }
+}
+impl<'a, P: SearchInBytes<'a>> Iterator for SearchIndicesOverlappingBytesIgnoreAsciiCase<'a, P> {
+    type Item = (usize, &'a [u8]);
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.needle.max_match_len() == 0 {
+            return None;
+        }
+        match self
+            .needle
+            .search_in_ignore_ascii_case(&self.haystack[self.curr_idx..])
+        {
+            Some(idx) => {
+                let st = self.curr_idx + idx;
+                let ed = st + self.needle.match_len_at(self.haystack, st);
+                self.curr_idx = st + 1;
+                Some((st, &self.haystack[st..ed]))
+            }
+            None => None,
+        }
+    }
+}
+
+///
+/// Created with the method [Search::rsearch_indices_overlapping_ignore_ascii_case()](crate::Search::rsearch_indices_overlapping_ignore_ascii_case).
+///
+pub struct RevSearchIndicesOverlappingIgnoreAsciiCase<'a, P: SearchIn<'a>> {
+    curr_ed: usize,
+    haystack: &'a str,
+    needle: P,
+}
+impl<'a, P: SearchIn<'a>> RevSearchIndicesOverlappingIgnoreAsciiCase<'a, P> {
+    pub(crate) fn new(
+        a_haystack: &'a str,
+        a_needle: P,
+    ) -> RevSearchIndicesOverlappingIgnoreAsciiCase<'a, P> {
+        RevSearchIndicesOverlappingIgnoreAsciiCase {
+            curr_ed: a_haystack.len(),
+            haystack: a_haystack,
+            needle: a_needle,
+        }
+    }
+}
+impl<'a, P: SearchIn<'a>> Iterator for RevSearchIndicesOverlappingIgnoreAsciiCase<'a, P> {
+    type Item = (usize, &'a str);
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.needle.max_match_len() == 0 {
+            return None;
+        }
+        match self
+            .needle
+            .rsearch_in_ignore_ascii_case(&self.haystack[0..self.curr_ed])
+        {
+            Some(idx) => {
+                let st = idx;
+                let ed = st + self.needle.match_len_at(self.haystack, st);
+                let mut next_ed = ed - 1;
+                while !self.haystack.is_char_boundary(next_ed) {
+                    next_ed -= 1;
+                }
+                self.curr_ed = next_ed;
+                Some((st, &self.haystack[st..ed]))
+            }
+            None => None,
+        }
+    }
+}
+
+///
+/// Created with the method [SearchBytes::rsearch_indices_overlapping_bytes_ignore_ascii_case()](crate::SearchBytes::rsearch_indices_overlapping_bytes_ignore_ascii_case).
+///
+pub struct RevSearchIndicesOverlappingBytesIgnoreAsciiCase<'a, P: SearchInBytes<'a>> {
+    curr_ed: usize,
+    haystack: &'a [u8],
+    needle: P,
+}
+impl<'a, P: SearchInBytes<'a>> RevSearchIndicesOverlappingBytesIgnoreAsciiCase<'a, P> {
+    pub(crate) fn new(
+        a_haystack: &'a [u8],
+        a_needle: P,
+    ) -> RevSearchIndicesOverlappingBytesIgnoreAsciiCase<'a, P> {
+        RevSearchIndicesOverlappingBytesIgnoreAsciiCase {
+            curr_ed: a_haystack.len(),
+            haystack: a_haystack,
+            needle: a_needle,
+        }
+    }
+}
+impl<'a, P: SearchInBytes<'a>> Iterator for RevSearchIndicesOverlappingBytesIgnoreAsciiCase<'a, P> {
+    type Item = (usize, &'a [u8]);
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.needle.max_match_len() == 0 {
+            return None;
+        }
+        match self
+            .needle
+            .rsearch_in_ignore_ascii_case(&self.haystack[0..self.curr_ed])
+        {
+            Some(idx) => {
+                let st = idx;
+                let ed = st + self.needle.match_len_at(self.haystack, st);
+                self.curr_ed = ed - 1;
+                Some((st, &self.haystack[st..ed]))
+            }
+            None => None,
+        }
+    }
+}
@@ -0,0 +1,505 @@
+//
+// The parallel search over the large haystack, with the `parallel` feature.
+//
+// The haystack is split into chunks of the start indices, and each chunk is
+// searched on a scoped thread. The slice of a chunk is extended by
+// `needle.len() - 1` bytes, so that it finds the needle spanning the chunk
+// boundary, and a match belongs only to the chunk of its start index,
+// so that no match is found twice.
+//
+// The matches of each chunk are not overlapping from the start of the chunk.
+// When the last match of the previous chunk overlaps the first matches of
+// the chunk, the chunk is searched again from the end of that match, until
+// a match meets one of the first matches of the chunk. From it, the rest of
+// the matches of the chunk are the same as the sequential search.
+//
+// The search of the first match shares the best match so far between the
+// threads, and a chunk is searched block by block, so that it stops at the
+// block that starts after the best match.
+//
+// When it does not meet them, such as on the periodic haystack, the chunk is
+// scanned again from the end of the previous chunk, on the threads with the
+// other such chunks. The end of the chunk that is scanned again is not known
+// yet, so that the next chunk is guessed to meet it, and it is checked in
+// the next round. Each round fixes the first such chunk at least, and usually
+// all of them.
+//
+use crate::{naive_opt_mc_bytes, naive_opt_mc_bytes_iac};
+use crate::{SearchIndicesBytes, SearchIndicesBytesIgnoreAsciiCase};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::vec::Vec;
+
+// the minimum length of a chunk, the smaller haystack is searched on a thread.
+const DEFAULT_MIN_CHUNK_LEN: usize = 1024 * 1024;
+// the count of the first matches of a chunk, that are kept to meet the previous chunk.
+const HEAD_LEN: usize = 64;
+// the length of a block of the search of the first match, between the checks of the best match.
+const SEARCH_BLOCK_LEN: usize = 64 * 1024;
+
+///
+/// A parallel searcher of the needle, over the large haystack.
+///
+/// The results are the same as the sequential search. The indices and
+/// the count are of the matches that are not overlapping, the same as
+/// [SearchBytes::search_indices_bytes()](crate::SearchBytes::search_indices_bytes).
+///
+/// The matches of a chunk may not line up with the end of the previous chunk,
+/// such as `"aa"` in the haystack of `'a'` with the odd chunk boundary.
+/// Then the chunk is scanned again from the end of the previous chunk,
+/// so that the search count and the search indices scan such a haystack
+/// twice or more, but still on the threads.
+///
+/// Examples
+///
+/// ```rust
+/// use naive_opt::ParallelSearcher;
+///
+/// let haystack = "abc345abc901abc".repeat(1000);
+/// let searcher = ParallelSearcher::new("901").with_threads(4).with_min_chunk_len(1024);
+/// assert_eq!(searcher.search(&haystack), Some(9));
+/// assert_eq!(searcher.search_count(&haystack), 1000);
+/// assert_eq!(searcher.search_indices(&haystack)[..2], [9, 24]);
+///
+/// let searcher = ParallelSearcher::new_ignore_ascii_case("ABC");
+/// assert!(searcher.includes(&haystack));
+/// ```
+///
+#[derive(Debug, Clone, Copy)]
+pub struct ParallelSearcher<'n> {
+    needle: &'n [u8],
+    ignore_ascii_case: bool,
+    threads: usize,
+    min_chunk_len: usize,
+}
+
+enum Resync {
+    // the sequential search met the match of the chunk at the index of the head.
+    At(usize),
+    // the sequential search reached the end of the chunk, with the next start index.
+    Done(usize),
+    // the sequential search did not meet the head, the chunk must be scanned
+    // again from the start index.
+    Rescan(usize),
+}
+
+// the matches of a chunk, that are not overlapping from `from`.
+struct ChunkScan {
+    from: usize,
+    // the first matches, or all of them if they are kept.
+    head: Vec<usize>,
+    count: usize,
+    last: Option<usize>,
+}
+
+// the chunk merged into the sequential search, the matches found by the
+// sequential search, and the index of the head, the matches from it follow.
+struct Merged {
+    scan: ChunkScan,
+    found: Vec<usize>,
+    rest: Option<usize>,
+}
+
+impl<'n> ParallelSearcher<'n> {
+    ///
+    /// create a new parallel searcher of the needle.
+    ///
+    pub fn new<B: ?Sized + AsRef<[u8]>>(needle: &'n B) -> ParallelSearcher<'n> {
+        ParallelSearcher {
+            needle: needle.as_ref(),
+            ignore_ascii_case: false,
+            threads: 0,
+            min_chunk_len: DEFAULT_MIN_CHUNK_LEN,
+        }
+    }
+    ///
+    /// create a new parallel searcher of the needle, ignore ascii case.
+    ///
+    pub fn new_ignore_ascii_case<B: ?Sized + AsRef<[u8]>>(needle: &'n B) -> ParallelSearcher<'n> {
+        ParallelSearcher {
+            ignore_ascii_case: true,
+            ..Self::new(needle)
+        }
+    }
+    ///
+    /// set the count of the threads.
+    ///
+    /// The `0` is the default, that is `std::thread::available_parallelism()`.
+    ///
+    pub fn with_threads(mut self, threads: usize) -> ParallelSearcher<'n> {
+        self.threads = threads;
+        self
+    }
+    ///
+    /// set the minimum length of a chunk, the default is 1 MiB.
+    ///
+    /// The haystack is split into the chunks of this length at least,
+    /// so that the small haystack is searched without the threads.
+    ///
+    pub fn with_min_chunk_len(mut self, min_chunk_len: usize) -> ParallelSearcher<'n> {
+        self.min_chunk_len = min_chunk_len;
+        self
+    }
+    ///
+    /// return the needle of self.
+    ///
+    #[inline]
+    pub fn needle(&self) -> &'n [u8] {
+        self.needle
+    }
+    ///
+    /// search the needle in the haystack.
+    ///
+    /// return index of the haystack, if it found the needle. Otherwise return None.
+    ///
+    /// The chunks after the match that is found first are not scanned to the end.
+    ///
+    pub fn search<H: ?Sized + AsRef<[u8]>>(&self, haystack: &H) -> Option<usize> {
+        let hay_bytes = haystack.as_ref();
+        if self.needle.is_empty() {
+            return Some(0);
+        }
+        let chunks = self.chunks(hay_bytes.len());
+        let best = AtomicUsize::new(usize::MAX);
+        // a chunk stops only after a match before it is found,
+        // so that the 1st chunk that found a match has the first match.
+        self.run(&chunks, |st, ed| {
+            self.search_chunk(hay_bytes, st, ed, &best)
+        })
+        .into_iter()
+        .flatten()
+        .next()
+    }
+    ///
+    /// return true if the haystack includes the needle.
+    ///
+    pub fn includes<H: ?Sized + AsRef<[u8]>>(&self, haystack: &H) -> bool {
+        self.search(haystack).is_some()
+    }
+    ///
+    /// return the count of the needles in the haystack, that are not overlapping.
+    ///
+    pub fn search_count<H: ?Sized + AsRef<[u8]>>(&self, haystack: &H) -> usize {
+        let hay_bytes = haystack.as_ref();
+        if self.needle.is_empty() {
+            return 0;
+        }
+        self.merge(hay_bytes, false)
+            .into_iter()
+            .map(|m| m.found.len() + m.rest.map_or(0, |k| m.scan.count - k))
+            .sum()
+    }
+    ///
+    /// return the indices of the needles in the haystack, that are not overlapping.
+    ///
+    pub fn search_indices<H: ?Sized + AsRef<[u8]>>(&self, haystack: &H) -> Vec<usize> {
+        let hay_bytes = haystack.as_ref();
+        if self.needle.is_empty() {
+            return Vec::new();
+        }
+        let mut indices = Vec::new();
+        for m in self.merge(hay_bytes, true) {
+            indices.extend_from_slice(&m.found);
+            if let Some(k) = m.rest {
+                indices.extend_from_slice(&m.scan.head[k..]);
+            }
+        }
+        indices
+    }
+    // split the start indices of the haystack into the chunks.
+    fn chunks(&self, hay_len: usize) -> Vec<(usize, usize)> {
+        let threads = if self.threads == 0 {
+            thread::available_parallelism().map_or(1, |n| n.get())
+        } else {
+            self.threads
+        };
+        let min_chunk_len = self.min_chunk_len.max(self.needle.len()).max(1);
+        let chunk_count = threads.min(hay_len / min_chunk_len).max(1);
+        let chunk_len = (hay_len + chunk_count - 1) / chunk_count;
+        (0..chunk_count)
+            .map(|i| (i * chunk_len, hay_len.min((i + 1) * chunk_len)))
+            .filter(|(st, ed)| st < ed)
+            .collect()
+    }
+    // run the closure for each chunk, on the scoped threads.
+    fn run<T, F>(&self, chunks: &[(usize, usize)], f: F) -> Vec<T>
+    where
+        T: Send,
+        F: Fn(usize, usize) -> T + Sync,
+    {
+        if chunks.len() <= 1 {
+            return chunks.iter().map(|&(st, ed)| f(st, ed)).collect();
+        }
+        let f = &f;
+        thread::scope(|scope| {
+            let handles: Vec<_> = chunks
+                .iter()
+                .map(|&(st, ed)| scope.spawn(move || f(st, ed)))
+                .collect();
+            handles
+                .into_iter()
+                .map(|h| h.join().unwrap_or_else(|e| std::panic::resume_unwind(e)))
+                .collect()
+        })
+    }
+    // the slice of the start indices `st..ed`, extended by `needle.len() - 1`.
+    #[inline]
+    fn chunk_slice<'h>(&self, hay_bytes: &'h [u8], st: usize, ed: usize) -> &'h [u8] {
+        &hay_bytes[st..hay_bytes.len().min(ed + self.needle.len() - 1)]
+    }
+    // the first match that starts in `st..ed`.
+    #[inline]
+    fn search_range(&self, hay_bytes: &[u8], st: usize, ed: usize) -> Option<usize> {
+        if st >= ed {
+            return None;
+        }
+        let hay_part = self.chunk_slice(hay_bytes, st, ed);
+        let r = if self.ignore_ascii_case {
+            naive_opt_mc_bytes_iac(hay_part, self.needle)
+        } else {
+            naive_opt_mc_bytes(hay_part, self.needle)
+        };
+        r.map(|idx| st + idx)
+    }
+    // the first match that starts in `st..ed`, searched block by block. it stops
+    // at the block that starts after the best match, that the other chunks found.
+    fn search_chunk(
+        &self,
+        hay_bytes: &[u8],
+        st: usize,
+        ed: usize,
+        best: &AtomicUsize,
+    ) -> Option<usize> {
+        let block_len = SEARCH_BLOCK_LEN.max(self.needle.len());
+        let mut pos = st;
+        while pos < ed && pos < best.load(Ordering::Relaxed) {
+            let block_ed = ed.min(pos + block_len);
+            if let Some(idx) = self.search_range(hay_bytes, pos, block_ed) {
+                best.fetch_min(idx, Ordering::Relaxed);
+                return Some(idx);
+            }
+            pos = block_ed;
+        }
+        None
+    }
+    // the matches that are not overlapping from `from`, and start in `from..ed`.
+    fn for_each_in_chunk<F: FnMut(usize)>(
+        &self,
+        hay_bytes: &[u8],
+        from: usize,
+        ed: usize,
+        mut f: F,
+    ) {
+        let hay_part = self.chunk_slice(hay_bytes, from, ed);
+        if self.ignore_ascii_case {
+            SearchIndicesBytesIgnoreAsciiCase::new(hay_part, self.needle)
+                .for_each(|(idx, _)| f(from + idx));
+        } else {
+            SearchIndicesBytes::new(hay_part, self.needle).for_each(|(idx, _)| f(from + idx));
+        }
+    }
+    // scan the chunk from `from`, and keep the first matches, or all of them.
+    fn scan_chunk(&self, hay_bytes: &[u8], from: usize, ed: usize, keep_all: bool) -> ChunkScan {
+        let mut head = Vec::new();
+        let mut count = 0;
+        let mut last = None;
+        self.for_each_in_chunk(hay_bytes, from, ed, |idx| {
+            if keep_all || head.len() < HEAD_LEN {
+                head.push(idx);
+            }
+            count += 1;
+            last = Some(idx);
+        });
+        ChunkScan {
+            from,
+            head,
+            count,
+            last,
+        }
+    }
+    // scan the chunks on the threads, and merge them into the sequential search.
+    // the chunks that do not meet it are scanned again, until all of them meet.
+    fn merge(&self, hay_bytes: &[u8], keep_all: bool) -> Vec<Merged> {
+        let nee_len = self.needle.len();
+        let chunks = self.chunks(hay_bytes.len());
+        let mut scans = self.run(&chunks, |st, ed| {
+            self.scan_chunk(hay_bytes, st, ed, keep_all)
+        });
+        loop {
+            let mut merged = Vec::with_capacity(chunks.len());
+            let mut rescans = Vec::new();
+            // the end of the previous chunk, or None if it is scanned again.
+            let mut pos = Some(0);
+            for (i, (&(st, ed), scan)) in chunks.iter().zip(&scans).enumerate() {
+                let mut found = Vec::new();
+                // guess that the chunk meets the previous chunk, that is scanned again.
+                let entry = pos.unwrap_or(scan.from);
+                match self.resync(hay_bytes, st, ed, entry, scan, &mut found) {
+                    Resync::At(k) => {
+                        pos = scan.last.map(|idx| idx + nee_len);
+                        merged.push((found, Some(k)));
+                    }
+                    Resync::Done(next_pos) => {
+                        pos = Some(next_pos);
+                        merged.push((found, None));
+                    }
+                    Resync::Rescan(from) => {
+                        pos = None;
+                        rescans.push((i, from));
+                    }
+                }
+            }
+            if rescans.is_empty() {
+                return scans
+                    .into_iter()
+                    .zip(merged)
+                    .map(|(scan, (found, rest))| Merged { scan, found, rest })
+                    .collect();
+            }
+            let ranges: Vec<_> = rescans
+                .iter()
+                .map(|&(i, from)| (from, chunks[i].1))
+                .collect();
+            let rescanned = self.run(&ranges, |from, ed| {
+                self.scan_chunk(hay_bytes, from, ed, keep_all)
+            });
+            for ((i, _), scan) in rescans.into_iter().zip(rescanned) {
+                scans[i] = scan;
+            }
+        }
+    }
+    // search the chunk sequentially from `pos`, until it meets the match of the scan
+    // in the head. the matches before it are pushed into `found`.
+    fn resync(
+        &self,
+        hay_bytes: &[u8],
+        st: usize,
+        ed: usize,
+        pos: usize,
+        scan: &ChunkScan,
+        found: &mut Vec<usize>,
+    ) -> Resync {
+        let entry = pos.max(st);
+        if entry == scan.from {
+            return if scan.count == 0 {
+                Resync::Done(entry)
+            } else {
+                Resync::At(0)
+            };
+        }
+        // no match starts in `scan_ed..ed`, the scan passed it.
+        let scan_ed = ed.min(scan.last.map_or(scan.from, |idx| idx + self.needle.len()));
+        let head = &scan.head;
+        let mut pos = entry;
+        let mut k = 0;
+        while let Some(idx) = self.search_range(hay_bytes, pos, scan_ed) {
+            while k < head.len() && head[k] < idx {
+                k += 1;
+            }
+            if k < head.len() && head[k] == idx {
+                return Resync::At(k);
+            }
+            if (k == head.len() && head.len() < scan.count) || found.len() == HEAD_LEN {
+                found.clear();
+                return Resync::Rescan(entry);
+            }
+            found.push(idx);
+            pos = idx + self.needle.len();
+        }
+        Resync::Done(pos)
+    }
+}
+
+///
+/// search the needle in the haystack, in parallel.
+///
+/// return index of the haystack, if it found the needle. Otherwise return None.
+///
+pub fn par_search<H, B>(haystack: &H, needle: &B) -> Option<usize>
+where
+    H: ?Sized + AsRef<[u8]>,
+    B: ?Sized + AsRef<[u8]>,
+{
+    ParallelSearcher::new(needle).search(haystack)
+}
+
+///
+/// return true if the haystack includes the needle, in parallel.
+///
+pub fn par_includes<H, B>(haystack: &H, needle: &B) -> bool
+where
+    H: ?Sized + AsRef<[u8]>,
+    B: ?Sized + AsRef<[u8]>,
+{
+    ParallelSearcher::new(needle).includes(haystack)
+}
+
+///
+/// return the count of the needles in the haystack, that are not overlapping, in parallel.
+///
+pub fn par_search_count<H, B>(haystack: &H, needle: &B) -> usize
+where
+    H: ?Sized + AsRef<[u8]>,
+    B: ?Sized + AsRef<[u8]>,
+{
+    ParallelSearcher::new(needle).search_count(haystack)
+}
+
+///
+/// return the indices of the needles in the haystack, that are not overlapping, in parallel.
+///
+pub fn par_search_indices<H, B>(haystack: &H, needle: &B) -> Vec<usize>
+where
+    H: ?Sized + AsRef<[u8]>,
+    B: ?Sized + AsRef<[u8]>,
+{
+    ParallelSearcher::new(needle).search_indices(haystack)
+}
+
+///
+/// search the needle in the haystack, in parallel, ignore ascii case.
+///
+/// return index of the haystack, if it found the needle. Otherwise return None.
+///
+pub fn par_search_ignore_ascii_case<H, B>(haystack: &H, needle: &B) -> Option<usize>
+where
+    H: ?Sized + AsRef<[u8]>,
+    B: ?Sized + AsRef<[u8]>,
+{
+    ParallelSearcher::new_ignore_ascii_case(needle).search(haystack)
+}
+
+///
+/// return true if the haystack includes the needle, in parallel, ignore ascii case.
+///
+pub fn par_includes_ignore_ascii_case<H, B>(haystack: &H, needle: &B) -> bool
+where
+    H: ?Sized + AsRef<[u8]>,
+    B: ?Sized + AsRef<[u8]>,
+{
+    ParallelSearcher::new_ignore_ascii_case(needle).includes(haystack)
+}
+
+///
+/// return the count of the needles in the haystack, that are not overlapping,
+/// in parallel, ignore ascii case.
+///
+pub fn par_search_count_ignore_ascii_case<H, B>(haystack: &H, needle: &B) -> usize
+where
+    H: ?Sized + AsRef<[u8]>,
+    B: ?Sized + AsRef<[u8]>,
+{
+    ParallelSearcher::new_ignore_ascii_case(needle).search_count(haystack)
+}
+
+///
+/// return the indices of the needles in the haystack, that are not overlapping,
+/// in parallel, ignore ascii case.
+///
+pub fn par_search_indices_ignore_ascii_case<H, B>(haystack: &H, needle: &B) -> Vec<usize>
+where
+    H: ?Sized + AsRef<[u8]>,
+    B: ?Sized + AsRef<[u8]>,
+{
+    ParallelSearcher::new_ignore_ascii_case(needle).search_indices(haystack)
+}
@@ -0,0 +1,93 @@
+//
+// The scanning of the pick bytes.
+//
+// The pick bytes are scanned by `memchr` in the groups of up to 4 bytes.
+// The next (or the previous) candidate of each group is cached, so that
+// each group scans the haystack only once in a search, even if the
+// candidates are consumed one by one.
+//
+
+const MAX_MEMCHR_PICKS: usize = 4;
+
+// the scanner of up to `N` groups of the pick bytes.
+#[derive(Debug, Clone)]
+pub(crate) struct PickScanner<'p, const N: usize> {
+    picks: &'p [u8],
+    // the cached candidate of each group, or the haystack length if not found.
+    cache: [Option<usize>; N],
+}
+impl<'p, const N: usize> PickScanner<'p, N> {
+    #[inline]
+    pub(crate) fn new(picks: &'p [u8]) -> PickScanner<'p, N> {
+        debug_assert!(picks.len() <= N * MAX_MEMCHR_PICKS);
+        PickScanner {
+            picks,
+            cache: [None; N],
+        }
+    }
+    // the next candidate of the pick bytes from `from`.
+    // `from` must not decrease over the calls.
+    #[inline]
+    pub(crate) fn next_candidate(&mut self, hay_bytes: &[u8], from: usize) -> Option<usize> {
+        let mut r = hay_bytes.len();
+        for (group, cached) in self.picks.chunks(MAX_MEMCHR_PICKS).zip(&mut self.cache) {
+            let idx = match *cached {
+                Some(idx) if idx >= from => idx,
+                _ => {
+                    let idx = memchr_picks(&hay_bytes[from..], group)
+                        .map_or(hay_bytes.len(), |idx| from + idx);
+                    *cached = Some(idx);
+                    idx
+                }
+            };
+            r = r.min(idx);
+        }
+        if r < hay_bytes.len() {
+            Some(r)
+        } else {
+            None
+        }
+    }
+    // the previous candidate of the pick bytes before `to`.
+    // `to` must not increase over the calls.
+    #[inline]
+    pub(crate) fn prev_candidate(&mut self, hay_bytes: &[u8], to: usize) -> Option<usize> {
+        let mut r = None;
+        for (group, cached) in self.picks.chunks(MAX_MEMCHR_PICKS).zip(&mut self.cache) {
+            let idx = match *cached {
+                Some(idx) if idx < to || idx == hay_bytes.len() => idx,
+                _ => {
+                    let idx = memrchr_picks(&hay_bytes[..to], group).unwrap_or(hay_bytes.len());
+                    *cached = Some(idx);
+                    idx
+                }
+            };
+            if idx < hay_bytes.len() && r.map_or(true, |r| idx > r) {
+                r = Some(idx);
+            }
+        }
+        r
+    }
+}
+
+#[inline]
+fn memchr_picks(buf: &[u8], group: &[u8]) -> Option<usize> {
+    match *group {
+        [b1] => ::memx::memchr(buf, b1),
+        [b1, b2] => ::memx::memchr_dbl(buf, b1, b2),
+        [b1, b2, b3] => ::memx::memchr_tpl(buf, b1, b2, b3),
+        [b1, b2, b3, b4] => ::memx::memchr_qpl(buf, b1, b2, b3, b4),
+        _ => unreachable!(),
+    }
+}
+
+#[inline]
+fn memrchr_picks(buf: &[u8], group: &[u8]) -> Option<usize> {
+    match *group {
+        [b1] => ::memx::memrchr(buf, b1),
+        [b1, b2] => ::memx::memrchr_dbl(buf, b1, b2),
+        [b1, b2, b3] => ::memx::memrchr_tpl(buf, b1, b2, b3),
+        [b1, b2, b3, b4] => ::memx::memrchr_qpl(buf, b1, b2, b3, b4),
+        _ => unreachable!(),
+    }
+}
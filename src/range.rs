@@ -0,0 +1,103 @@
+//
+// The search within a range of the haystack.
+//
+// The returned index is absolute in the haystack, not relative to the range.
+// If the range is out of bounds, or its ends are not on a char boundary of
+// the `str` haystack, the search returns None instead of panicking.
+//
+use crate::{SearchIn, SearchInBytes};
+use core::ops::{Bound, RangeBounds};
+
+#[inline]
+fn to_bounds<R: RangeBounds<usize>>(range: R, len: usize) -> Option<(usize, usize)> {
+    let st = match range.start_bound() {
+        Bound::Included(&n) => n,
+        Bound::Excluded(&n) => n.checked_add(1)?,
+        Bound::Unbounded => 0,
+    };
+    let ed = match range.end_bound() {
+        Bound::Included(&n) => n.checked_add(1)?,
+        Bound::Excluded(&n) => n,
+        Bound::Unbounded => len,
+    };
+    if st <= ed && ed <= len {
+        Some((st, ed))
+    } else {
+        None
+    }
+}
+
+#[inline]
+pub(crate) fn search_from<'a, P: SearchIn<'a>>(
+    haystack: &'a str,
+    needle: P,
+    start: usize,
+) -> Option<usize> {
+    if !haystack.is_char_boundary(start) {
+        return None;
+    }
+    needle.search_in(&haystack[start..]).map(|idx| start + idx)
+}
+
+#[inline]
+pub(crate) fn rsearch_before<'a, P: SearchIn<'a>>(
+    haystack: &'a str,
+    needle: P,
+    end: usize,
+) -> Option<usize> {
+    if !haystack.is_char_boundary(end) {
+        return None;
+    }
+    needle.rsearch_in(&haystack[..end])
+}
+
+#[inline]
+pub(crate) fn search_in_range<'a, P, R>(haystack: &'a str, needle: P, range: R) -> Option<usize>
+where
+    P: SearchIn<'a>,
+    R: RangeBounds<usize>,
+{
+    let (st, ed) = to_bounds(range, haystack.len())?;
+    if !haystack.is_char_boundary(st) || !haystack.is_char_boundary(ed) {
+        return None;
+    }
+    needle.search_in(&haystack[st..ed]).map(|idx| st + idx)
+}
+
+#[inline]
+pub(crate) fn search_from_bytes<'a, P: SearchInBytes<'a>>(
+    haystack: &'a [u8],
+    needle: P,
+    start: usize,
+) -> Option<usize> {
+    if start > haystack.len() {
+        return None;
+    }
+    needle.search_in(&haystack[start..]).map(|idx| start + idx)
+}
+
+#[inline]
+pub(crate) fn rsearch_before_bytes<'a, P: SearchInBytes<'a>>(
+    haystack: &'a [u8],
+    needle: P,
+    end: usize,
+) -> Option<usize> {
+    if end > haystack.len() {
+        return None;
+    }
+    needle.rsearch_in(&haystack[..end])
+}
+
+#[inline]
+pub(crate) fn search_in_range_bytes<'a, P, R>(
+    haystack: &'a [u8],
+    needle: P,
+    range: R,
+) -> Option<usize>
+where
+    P: SearchInBytes<'a>,
+    R: RangeBounds<usize>,
+{
+    let (st, ed) = to_bounds(range, haystack.len())?;
+    needle.search_in(&haystack[st..ed]).map(|idx| st + idx)
+}
@@ -0,0 +1,78 @@
+//
+// The replacement is built on the match iterators,
+// such as `SearchIndices` and `SearchIndicesBytes`.
+//
+// If nothing matched, the haystack is returned as `Cow::Borrowed`
+// without the allocation.
+//
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+pub(crate) fn replace_into<'a, I>(haystack: &'a str, matches: I, to: &str, dst: &mut String)
+where
+    I: Iterator<Item = (usize, &'a str)>,
+{
+    let mut last_ed = 0;
+    for (idx, s) in matches {
+        dst.push_str(&haystack[last_ed..idx]);
+        dst.push_str(to);
+        last_ed = idx + s.len();
+    }
+    dst.push_str(&haystack[last_ed..]);
+}
+
+pub(crate) fn replace<'a, I>(haystack: &'a str, mut matches: I, to: &str) -> Cow<'a, str>
+where
+    I: Iterator<Item = (usize, &'a str)>,
+{
+    match matches.next() {
+        Some(first) => {
+            let mut dst = String::with_capacity(haystack.len());
+            replace_into(
+                haystack,
+                Some(first).into_iter().chain(matches),
+                to,
+                &mut dst,
+            );
+            Cow::Owned(dst)
+        }
+        None => Cow::Borrowed(haystack),
+    }
+}
+
+pub(crate) fn replace_into_bytes<'a, I>(
+    haystack: &'a [u8],
+    matches: I,
+    to: &[u8],
+    dst: &mut Vec<u8>,
+) where
+    I: Iterator<Item = (usize, &'a [u8])>,
+{
+    let mut last_ed = 0;
+    for (idx, s) in matches {
+        dst.extend_from_slice(&haystack[last_ed..idx]);
+        dst.extend_from_slice(to);
+        last_ed = idx + s.len();
+    }
+    dst.extend_from_slice(&haystack[last_ed..]);
+}
+
+pub(crate) fn replace_bytes<'a, I>(haystack: &'a [u8], mut matches: I, to: &[u8]) -> Cow<'a, [u8]>
+where
+    I: Iterator<Item = (usize, &'a [u8])>,
+{
+    match matches.next() {
+        Some(first) => {
+            let mut dst = Vec::with_capacity(haystack.len());
+            replace_into_bytes(
+                haystack,
+                Some(first).into_iter().chain(matches),
+                to,
+                &mut dst,
+            );
+            Cow::Owned(dst)
+        }
+        None => Cow::Borrowed(haystack),
+    }
+}
@@ -0,0 +1,468 @@
+//
+// The needles of a set of chars, a set of bytes and a predicate.
+//
+// These match one char (one byte for the byte set and the byte predicate),
+// so the length of the match depends on the matched char,
+// see `SearchIn::match_len_at()`.
+//
+// The ascii set of up to 4 bytes, after adding the other ascii case, is scanned
+// by `memchr()`, `memchr_dbl()`, `memchr_tpl()` or `memchr_qpl()`. The larger
+// ascii set is scanned by a table of the bytes, and the set that has
+// a non-ascii char is checked char by char.
+//
+use crate::sealed;
+use crate::word::char_after;
+use crate::{DoubleEndedSearchIn, DoubleEndedSearchInBytes, SearchIn, SearchInBytes};
+
+const MAX_MEMCHR_PICKS: usize = 4;
+
+///
+/// A set of bytes as the needle, that matches any one of the bytes.
+///
+/// `&[u8]` itself is the needle of the byte sequence, therefore
+/// the set of bytes is wrapped by this.
+///
+/// Examples
+///
+/// ```rust
+/// use naive_opt::{ByteSet, SearchBytes};
+///
+/// let haystack: &[u8] = b"key=value;\xffnext";
+/// assert_eq!(haystack.search_bytes(ByteSet(b";=")), Some(3));
+/// assert_eq!(haystack.rsearch_bytes(ByteSet(b";=")), Some(9));
+/// assert_eq!(haystack.search_bytes(ByteSet(b"\xff")), Some(10));
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ByteSet<'s>(pub &'s [u8]);
+
+// the scanner of the set of bytes.
+struct ByteScanner {
+    picks: [u8; MAX_MEMCHR_PICKS],
+    picks_len: usize,
+    table: [bool; 256],
+}
+impl ByteScanner {
+    fn new<I: Iterator<Item = u8>>(bytes: I, ignore_ascii_case: bool) -> ByteScanner {
+        let mut r = ByteScanner {
+            picks: [0; MAX_MEMCHR_PICKS],
+            picks_len: 0,
+            table: [false; 256],
+        };
+        for byte in bytes {
+            if ignore_ascii_case {
+                r.add(byte.to_ascii_lowercase());
+                r.add(byte.to_ascii_uppercase());
+            } else {
+                r.add(byte);
+            }
+        }
+        r
+    }
+    #[inline]
+    fn add(&mut self, byte: u8) {
+        if self.table[byte as usize] {
+            return;
+        }
+        self.table[byte as usize] = true;
+        if self.picks_len < MAX_MEMCHR_PICKS {
+            self.picks[self.picks_len] = byte;
+        }
+        self.picks_len += 1;
+    }
+    #[inline]
+    fn find(&self, buf: &[u8]) -> Option<usize> {
+        if self.picks_len > MAX_MEMCHR_PICKS {
+            return buf.iter().position(|&b| self.table[b as usize]);
+        }
+        match self.picks[..self.picks_len] {
+            [] => None,
+            [b1] => ::memx::memchr(buf, b1),
+            [b1, b2] => ::memx::memchr_dbl(buf, b1, b2),
+            [b1, b2, b3] => ::memx::memchr_tpl(buf, b1, b2, b3),
+            [b1, b2, b3, b4] => ::memx::memchr_qpl(buf, b1, b2, b3, b4),
+            _ => unreachable!(),
+        }
+    }
+    #[inline]
+    fn rfind(&self, buf: &[u8]) -> Option<usize> {
+        if self.picks_len > MAX_MEMCHR_PICKS {
+            return buf.iter().rposition(|&b| self.table[b as usize]);
+        }
+        match self.picks[..self.picks_len] {
+            [] => None,
+            [b1] => ::memx::memrchr(buf, b1),
+            [b1, b2] => ::memx::memrchr_dbl(buf, b1, b2),
+            [b1, b2, b3] => ::memx::memrchr_tpl(buf, b1, b2, b3),
+            [b1, b2, b3, b4] => ::memx::memrchr_qpl(buf, b1, b2, b3, b4),
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[inline]
+fn contains_char(chars: &[char], c: char, ignore_ascii_case: bool) -> bool {
+    if ignore_ascii_case {
+        chars.iter().any(|x| x.eq_ignore_ascii_case(&c))
+    } else {
+        chars.contains(&c)
+    }
+}
+
+#[inline]
+fn ascii_scanner(chars: &[char], ignore_ascii_case: bool) -> Option<ByteScanner> {
+    if chars.iter().all(char::is_ascii) {
+        Some(ByteScanner::new(
+            chars.iter().map(|&c| c as u8),
+            ignore_ascii_case,
+        ))
+    } else {
+        None
+    }
+}
+
+#[inline]
+fn is_char_start(byte: u8) -> bool {
+    (byte as i8) >= -0x40
+}
+
+fn search_chars(hay_bytes: &[u8], chars: &[char], ignore_ascii_case: bool) -> Option<usize> {
+    if let Some(scanner) = ascii_scanner(chars, ignore_ascii_case) {
+        return scanner.find(hay_bytes);
+    }
+    (0..hay_bytes.len()).find(|&i| {
+        is_char_start(hay_bytes[i])
+            && char_after(hay_bytes, i)
+                .map(|c| contains_char(chars, c, ignore_ascii_case))
+                .unwrap_or(false)
+    })
+}
+
+fn rsearch_chars(hay_bytes: &[u8], chars: &[char], ignore_ascii_case: bool) -> Option<usize> {
+    if let Some(scanner) = ascii_scanner(chars, ignore_ascii_case) {
+        return scanner.rfind(hay_bytes);
+    }
+    (0..hay_bytes.len()).rev().find(|&i| {
+        is_char_start(hay_bytes[i])
+            && char_after(hay_bytes, i)
+                .map(|c| contains_char(chars, c, ignore_ascii_case))
+                .unwrap_or(false)
+    })
+}
+
+// the length of the char at `idx`, or 1 if it is not valid UTF-8.
+#[inline]
+fn char_len_at(hay_bytes: &[u8], idx: usize) -> usize {
+    char_after(hay_bytes, idx).map(char::len_utf8).unwrap_or(1)
+}
+
+#[inline]
+fn max_char_len(chars: &[char]) -> usize {
+    chars.iter().map(|c| c.len_utf8()).max().unwrap_or(0)
+}
+
+#[inline]
+fn pred_char<F: Fn(char) -> bool>(f: &F, c: char, ignore_ascii_case: bool) -> bool {
+    if ignore_ascii_case {
+        f(c.to_ascii_lowercase()) || f(c.to_ascii_uppercase())
+    } else {
+        f(c)
+    }
+}
+
+#[inline]
+fn pred_byte<F: Fn(u8) -> bool>(f: &F, byte: u8, ignore_ascii_case: bool) -> bool {
+    if ignore_ascii_case {
+        f(byte.to_ascii_lowercase()) || f(byte.to_ascii_uppercase())
+    } else {
+        f(byte)
+    }
+}
+
+impl<'a> SearchIn<'a> for &[char] {
+    #[inline]
+    fn search_in(&self, haystack: &'a str) -> Option<usize> {
+        search_chars(haystack.as_bytes(), self, false)
+    }
+    #[inline]
+    fn rsearch_in(&self, haystack: &'a str) -> Option<usize> {
+        rsearch_chars(haystack.as_bytes(), self, false)
+    }
+    #[inline]
+    fn search_in_ignore_ascii_case(&self, haystack: &'a str) -> Option<usize> {
+        search_chars(haystack.as_bytes(), self, true)
+    }
+    #[inline]
+    fn rsearch_in_ignore_ascii_case(&self, haystack: &'a str) -> Option<usize> {
+        rsearch_chars(haystack.as_bytes(), self, true)
+    }
+    #[inline]
+    fn max_match_len(&self) -> usize {
+        max_char_len(self)
+    }
+    #[inline]
+    fn match_len_at(&self, haystack: &'a str, idx: usize) -> usize {
+        char_len_at(haystack.as_bytes(), idx)
+    }
+}
+
+impl<'a, const N: usize> SearchIn<'a> for [char; N] {
+    #[inline]
+    fn search_in(&self, haystack: &'a str) -> Option<usize> {
+        search_chars(haystack.as_bytes(), self, false)
+    }
+    #[inline]
+    fn rsearch_in(&self, haystack: &'a str) -> Option<usize> {
+        rsearch_chars(haystack.as_bytes(), self, false)
+    }
+    #[inline]
+    fn search_in_ignore_ascii_case(&self, haystack: &'a str) -> Option<usize> {
+        search_chars(haystack.as_bytes(), self, true)
+    }
+    #[inline]
+    fn rsearch_in_ignore_ascii_case(&self, haystack: &'a str) -> Option<usize> {
+        rsearch_chars(haystack.as_bytes(), self, true)
+    }
+    #[inline]
+    fn max_match_len(&self) -> usize {
+        max_char_len(self)
+    }
+    #[inline]
+    fn match_len_at(&self, haystack: &'a str, idx: usize) -> usize {
+        char_len_at(haystack.as_bytes(), idx)
+    }
+}
+
+impl<'a, const N: usize> SearchIn<'a> for &[char; N] {
+    #[inline]
+    fn search_in(&self, haystack: &'a str) -> Option<usize> {
+        search_chars(haystack.as_bytes(), *self, false)
+    }
+    #[inline]
+    fn rsearch_in(&self, haystack: &'a str) -> Option<usize> {
+        rsearch_chars(haystack.as_bytes(), *self, false)
+    }
+    #[inline]
+    fn search_in_ignore_ascii_case(&self, haystack: &'a str) -> Option<usize> {
+        search_chars(haystack.as_bytes(), *self, true)
+    }
+    #[inline]
+    fn rsearch_in_ignore_ascii_case(&self, haystack: &'a str) -> Option<usize> {
+        rsearch_chars(haystack.as_bytes(), *self, true)
+    }
+    #[inline]
+    fn max_match_len(&self) -> usize {
+        max_char_len(*self)
+    }
+    #[inline]
+    fn match_len_at(&self, haystack: &'a str, idx: usize) -> usize {
+        char_len_at(haystack.as_bytes(), idx)
+    }
+}
+
+///
+/// The predicate matches a char, for which it returns true.
+///
+/// With ignore ascii case, it is called with the ascii lowercase
+/// and then the ascii uppercase of the char.
+///
+impl<'a, F: Fn(char) -> bool> SearchIn<'a> for F {
+    #[inline]
+    fn search_in(&self, haystack: &'a str) -> Option<usize> {
+        haystack
+            .char_indices()
+            .find(|&(_, c)| pred_char(self, c, false))
+            .map(|(i, _)| i)
+    }
+    #[inline]
+    fn rsearch_in(&self, haystack: &'a str) -> Option<usize> {
+        haystack
+            .char_indices()
+            .rev()
+            .find(|&(_, c)| pred_char(self, c, false))
+            .map(|(i, _)| i)
+    }
+    #[inline]
+    fn search_in_ignore_ascii_case(&self, haystack: &'a str) -> Option<usize> {
+        haystack
+            .char_indices()
+            .find(|&(_, c)| pred_char(self, c, true))
+            .map(|(i, _)| i)
+    }
+    #[inline]
+    fn rsearch_in_ignore_ascii_case(&self, haystack: &'a str) -> Option<usize> {
+        haystack
+            .char_indices()
+            .rev()
+            .find(|&(_, c)| pred_char(self, c, true))
+            .map(|(i, _)| i)
+    }
+    #[inline]
+    fn max_match_len(&self) -> usize {
+        4
+    }
+    #[inline]
+    fn match_len_at(&self, haystack: &'a str, idx: usize) -> usize {
+        char_len_at(haystack.as_bytes(), idx)
+    }
+}
+
+impl<'a> SearchInBytes<'a> for &[char] {
+    #[inline]
+    fn search_in(&self, haystack: &'a [u8]) -> Option<usize> {
+        search_chars(haystack, self, false)
+    }
+    #[inline]
+    fn rsearch_in(&self, haystack: &'a [u8]) -> Option<usize> {
+        rsearch_chars(haystack, self, false)
+    }
+    #[inline]
+    fn search_in_ignore_ascii_case(&self, haystack: &'a [u8]) -> Option<usize> {
+        search_chars(haystack, self, true)
+    }
+    #[inline]
+    fn rsearch_in_ignore_ascii_case(&self, haystack: &'a [u8]) -> Option<usize> {
+        rsearch_chars(haystack, self, true)
+    }
+    #[inline]
+    fn max_match_len(&self) -> usize {
+        max_char_len(self)
+    }
+    #[inline]
+    fn match_len_at(&self, haystack: &'a [u8], idx: usize) -> usize {
+        char_len_at(haystack, idx)
+    }
+}
+
+impl<'a, const N: usize> SearchInBytes<'a> for [char; N] {
+    #[inline]
+    fn search_in(&self, haystack: &'a [u8]) -> Option<usize> {
+        search_chars(haystack, self, false)
+    }
+    #[inline]
+    fn rsearch_in(&self, haystack: &'a [u8]) -> Option<usize> {
+        rsearch_chars(haystack, self, false)
+    }
+    #[inline]
+    fn search_in_ignore_ascii_case(&self, haystack: &'a [u8]) -> Option<usize> {
+        search_chars(haystack, self, true)
+    }
+    #[inline]
+    fn rsearch_in_ignore_ascii_case(&self, haystack: &'a [u8]) -> Option<usize> {
+        rsearch_chars(haystack, self, true)
+    }
+    #[inline]
+    fn max_match_len(&self) -> usize {
+        max_char_len(self)
+    }
+    #[inline]
+    fn match_len_at(&self, haystack: &'a [u8], idx: usize) -> usize {
+        char_len_at(haystack, idx)
+    }
+}
+
+impl<'a, const N: usize> SearchInBytes<'a> for &[char; N] {
+    #[inline]
+    fn search_in(&self, haystack: &'a [u8]) -> Option<usize> {
+        search_chars(haystack, *self, false)
+    }
+    #[inline]
+    fn rsearch_in(&self, haystack: &'a [u8]) -> Option<usize> {
+        rsearch_chars(haystack, *self, false)
+    }
+    #[inline]
+    fn search_in_ignore_ascii_case(&self, haystack: &'a [u8]) -> Option<usize> {
+        search_chars(haystack, *self, true)
+    }
+    #[inline]
+    fn rsearch_in_ignore_ascii_case(&self, haystack: &'a [u8]) -> Option<usize> {
+        rsearch_chars(haystack, *self, true)
+    }
+    #[inline]
+    fn max_match_len(&self) -> usize {
+        max_char_len(*self)
+    }
+    #[inline]
+    fn match_len_at(&self, haystack: &'a [u8], idx: usize) -> usize {
+        char_len_at(haystack, idx)
+    }
+}
+
+impl<'a> SearchInBytes<'a> for ByteSet<'_> {
+    #[inline]
+    fn search_in(&self, haystack: &'a [u8]) -> Option<usize> {
+        ByteScanner::new(self.0.iter().copied(), false).find(haystack)
+    }
+    #[inline]
+    fn rsearch_in(&self, haystack: &'a [u8]) -> Option<usize> {
+        ByteScanner::new(self.0.iter().copied(), false).rfind(haystack)
+    }
+    #[inline]
+    fn search_in_ignore_ascii_case(&self, haystack: &'a [u8]) -> Option<usize> {
+        ByteScanner::new(self.0.iter().copied(), true).find(haystack)
+    }
+    #[inline]
+    fn rsearch_in_ignore_ascii_case(&self, haystack: &'a [u8]) -> Option<usize> {
+        ByteScanner::new(self.0.iter().copied(), true).rfind(haystack)
+    }
+    #[inline]
+    fn max_match_len(&self) -> usize {
+        if self.0.is_empty() {
+            0
+        } else {
+            1
+        }
+    }
+}
+
+///
+/// The predicate matches a byte, for which it returns true.
+///
+/// With ignore ascii case, it is called with the ascii lowercase
+/// and then the ascii uppercase of the byte.
+///
+/// It is `Fn` for the same reason as the predicate of chars,
+/// see [SearchIn].
+///
+impl<'a, F: Fn(u8) -> bool> SearchInBytes<'a> for F {
+    #[inline]
+    fn search_in(&self, haystack: &'a [u8]) -> Option<usize> {
+        haystack.iter().position(|&b| pred_byte(self, b, false))
+    }
+    #[inline]
+    fn rsearch_in(&self, haystack: &'a [u8]) -> Option<usize> {
+        haystack.iter().rposition(|&b| pred_byte(self, b, false))
+    }
+    #[inline]
+    fn search_in_ignore_ascii_case(&self, haystack: &'a [u8]) -> Option<usize> {
+        haystack.iter().position(|&b| pred_byte(self, b, true))
+    }
+    #[inline]
+    fn rsearch_in_ignore_ascii_case(&self, haystack: &'a [u8]) -> Option<usize> {
+        haystack.iter().rposition(|&b| pred_byte(self, b, true))
+    }
+    #[inline]
+    fn max_match_len(&self) -> usize {
+        1
+    }
+}
+
+// a match is a char or a byte, so that the matches never overlap.
+impl<'a> DoubleEndedSearchIn<'a> for &[char] {}
+impl<'a, const N: usize> DoubleEndedSearchIn<'a> for [char; N] {}
+impl<'a, const N: usize> DoubleEndedSearchIn<'a> for &[char; N] {}
+impl<'a, F: Fn(char) -> bool> DoubleEndedSearchIn<'a> for F {}
+impl<'a> DoubleEndedSearchInBytes<'a> for &[char] {}
+impl<'a, const N: usize> DoubleEndedSearchInBytes<'a> for [char; N] {}
+impl<'a, const N: usize> DoubleEndedSearchInBytes<'a> for &[char; N] {}
+impl<'a> DoubleEndedSearchInBytes<'a> for ByteSet<'_> {}
+impl<'a, F: Fn(u8) -> bool> DoubleEndedSearchInBytes<'a> for F {}
+impl sealed::DoubleEnded for &[char] {}
+impl<const N: usize> sealed::DoubleEnded for [char; N] {}
+impl<const N: usize> sealed::DoubleEnded for &[char; N] {}
+impl<F: Fn(char) -> bool> sealed::DoubleEnded for F {}
+impl sealed::DoubleEndedBytes for &[char] {}
+impl<const N: usize> sealed::DoubleEndedBytes for [char; N] {}
+impl<const N: usize> sealed::DoubleEndedBytes for &[char; N] {}
+impl sealed::DoubleEndedBytes for ByteSet<'_> {}
+impl<F: Fn(u8) -> bool> sealed::DoubleEndedBytes for F {}
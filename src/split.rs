@@ -0,0 +1,267 @@
+//
+// The split iterators are built on the match iterators,
+// such as `SearchIndices` and `RevSearchIndices`.
+//
+// An empty needle never matches in the match iterators,
+// therefore the split by an empty needle yields the whole haystack.
+//
+
+///
+/// Created with the method [Search::search_split()](crate::Search::search_split)
+/// and [Search::search_split_terminator()](crate::Search::search_split_terminator).
+///
+#[derive(Debug, Clone)]
+pub struct SearchSplit<'a, I> {
+    start: usize,
+    end: usize,
+    haystack: &'a str,
+    matches: I,
+    allow_trailing_empty: bool,
+    finished: bool,
+}
+impl<'a, I: Iterator<Item = (usize, &'a str)>> SearchSplit<'a, I> {
+    pub(crate) fn new(a_haystack: &'a str, a_matches: I, a_allow_trailing_empty: bool) -> Self {
+        SearchSplit {
+            start: 0,
+            end: a_haystack.len(),
+            haystack: a_haystack,
+            matches: a_matches,
+            allow_trailing_empty: a_allow_trailing_empty,
+            finished: false,
+        }
+    }
+    #[inline]
+    fn get_end(&mut self) -> Option<&'a str> {
+        if !self.finished {
+            self.finished = true;
+            if self.allow_trailing_empty || self.end > self.start {
+                return Some(&self.haystack[self.start..self.end]);
+            }
+        }
+        None
+    }
+}
+impl<'a, I: Iterator<Item = (usize, &'a str)>> Iterator for SearchSplit<'a, I> {
+    type Item = &'a str;
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        match self.matches.next() {
+            Some((idx, s)) => {
+                let elt = &self.haystack[self.start..idx];
+                self.start = idx + s.len();
+                Some(elt)
+            }
+            None => self.get_end(),
+        }
+    }
+}
+
+///
+/// Created with the method [SearchBytes::search_split_bytes()](crate::SearchBytes::search_split_bytes)
+/// and [SearchBytes::search_split_terminator_bytes()](crate::SearchBytes::search_split_terminator_bytes).
+///
+#[derive(Debug, Clone)]
+pub struct SearchSplitBytes<'a, I> {
+    start: usize,
+    end: usize,
+    haystack: &'a [u8],
+    matches: I,
+    allow_trailing_empty: bool,
+    finished: bool,
+}
+impl<'a, I: Iterator<Item = (usize, &'a [u8])>> SearchSplitBytes<'a, I> {
+    pub(crate) fn new(a_haystack: &'a [u8], a_matches: I, a_allow_trailing_empty: bool) -> Self {
+        SearchSplitBytes {
+            start: 0,
+            end: a_haystack.len(),
+            haystack: a_haystack,
+            matches: a_matches,
+            allow_trailing_empty: a_allow_trailing_empty,
+            finished: false,
+        }
+    }
+    #[inline]
+    fn get_end(&mut self) -> Option<&'a [u8]> {
+        if !self.finished {
+            self.finished = true;
+            if self.allow_trailing_empty || self.end > self.start {
+                return Some(&self.haystack[self.start..self.end]);
+            }
+        }
+        None
+    }
+}
+impl<'a, I: Iterator<Item = (usize, &'a [u8])>> Iterator for SearchSplitBytes<'a, I> {
+    type Item = &'a [u8];
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        match self.matches.next() {
+            Some((idx, s)) => {
+                let elt = &self.haystack[self.start..idx];
+                self.start = idx + s.len();
+                Some(elt)
+            }
+            None => self.get_end(),
+        }
+    }
+}
+
+///
+/// Created with the method [Search::search_rsplit()](crate::Search::search_rsplit).
+///
+#[derive(Debug, Clone)]
+pub struct SearchRSplit<'a, I> {
+    start: usize,
+    end: usize,
+    haystack: &'a str,
+    matches: I,
+    finished: bool,
+}
+impl<'a, I: Iterator<Item = (usize, &'a str)>> SearchRSplit<'a, I> {
+    pub(crate) fn new(a_haystack: &'a str, a_matches: I) -> Self {
+        SearchRSplit {
+            start: 0,
+            end: a_haystack.len(),
+            haystack: a_haystack,
+            matches: a_matches,
+            finished: false,
+        }
+    }
+}
+impl<'a, I: Iterator<Item = (usize, &'a str)>> Iterator for SearchRSplit<'a, I> {
+    type Item = &'a str;
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        match self.matches.next() {
+            Some((idx, s)) => {
+                let elt = &self.haystack[idx + s.len()..self.end];
+                self.end = idx;
+                Some(elt)
+            }
+            None => {
+                self.finished = true;
+                Some(&self.haystack[self.start..self.end])
+            }
+        }
+    }
+}
+
+///
+/// Created with the method [SearchBytes::search_rsplit_bytes()](crate::SearchBytes::search_rsplit_bytes).
+///
+#[derive(Debug, Clone)]
+pub struct SearchRSplitBytes<'a, I> {
+    start: usize,
+    end: usize,
+    haystack: &'a [u8],
+    matches: I,
+    finished: bool,
+}
+impl<'a, I: Iterator<Item = (usize, &'a [u8])>> SearchRSplitBytes<'a, I> {
+    pub(crate) fn new(a_haystack: &'a [u8], a_matches: I) -> Self {
+        SearchRSplitBytes {
+            start: 0,
+            end: a_haystack.len(),
+            haystack: a_haystack,
+            matches: a_matches,
+            finished: false,
+        }
+    }
+}
+impl<'a, I: Iterator<Item = (usize, &'a [u8])>> Iterator for SearchRSplitBytes<'a, I> {
+    type Item = &'a [u8];
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        match self.matches.next() {
+            Some((idx, s)) => {
+                let elt = &self.haystack[idx + s.len()..self.end];
+                self.end = idx;
+                Some(elt)
+            }
+            None => {
+                self.finished = true;
+                Some(&self.haystack[self.start..self.end])
+            }
+        }
+    }
+}
+
+///
+/// Created with the method [Search::search_splitn()](crate::Search::search_splitn).
+///
+#[derive(Debug, Clone)]
+pub struct SearchSplitN<'a, I> {
+    iter: SearchSplit<'a, I>,
+    count: usize,
+}
+impl<'a, I: Iterator<Item = (usize, &'a str)>> SearchSplitN<'a, I> {
+    pub(crate) fn new(a_iter: SearchSplit<'a, I>, a_count: usize) -> Self {
+        SearchSplitN {
+            iter: a_iter,
+            count: a_count,
+        }
+    }
+}
+impl<'a, I: Iterator<Item = (usize, &'a str)>> Iterator for SearchSplitN<'a, I> {
+    type Item = &'a str;
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.count {
+            0 => None,
+            1 => {
+                self.count = 0;
+                self.iter.get_end()
+            }
+            _ => {
+                self.count -= 1;
+                self.iter.next()
+            }
+        }
+    }
+}
+
+///
+/// Created with the method [SearchBytes::search_splitn_bytes()](crate::SearchBytes::search_splitn_bytes).
+///
+#[derive(Debug, Clone)]
+pub struct SearchSplitNBytes<'a, I> {
+    iter: SearchSplitBytes<'a, I>,
+    count: usize,
+}
+impl<'a, I: Iterator<Item = (usize, &'a [u8])>> SearchSplitNBytes<'a, I> {
+    pub(crate) fn new(a_iter: SearchSplitBytes<'a, I>, a_count: usize) -> Self {
+        SearchSplitNBytes {
+            iter: a_iter,
+            count: a_count,
+        }
+    }
+}
+impl<'a, I: Iterator<Item = (usize, &'a [u8])>> Iterator for SearchSplitNBytes<'a, I> {
+    type Item = &'a [u8];
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.count {
+            0 => None,
+            1 => {
+                self.count = 0;
+                self.iter.get_end()
+            }
+            _ => {
+                self.count -= 1;
+                self.iter.next()
+            }
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use std::io::{ErrorKind, Read, Result};
+use std::vec;
+use std::vec::Vec;
+
+const DEFAULT_BUF_SIZE: usize = 64 * 1024;
+
+///
+/// A searcher of the needle in the stream.
+///
+/// It reads the stream by chunks, and keeps the last `needle.len() - 1` bytes
+/// of each chunk, so that it finds the needle spanning chunk boundaries.
+/// The matches are not overlapping, the same as [Search::search_indices()](crate::Search::search_indices).
+///
+/// Examples
+///
+/// ```rust
+/// use naive_opt::StreamSearcher;
+///
+/// let reader: &[u8] = b"abc345abc901abc";
+/// let v: Vec<u64> = StreamSearcher::new(reader, "abc")
+///     .collect::<std::io::Result<_>>()
+///     .unwrap();
+/// assert_eq!(v, [0, 6, 12]);
+///
+/// let reader: &[u8] = b"abc345aBc901";
+/// let mut searcher = StreamSearcher::new_ignore_ascii_case(reader, "ABC");
+/// assert_eq!(searcher.search().unwrap(), Some(0));
+/// assert_eq!(searcher.search().unwrap(), Some(6));
+/// assert_eq!(searcher.search().unwrap(), None);
+/// ```
+///
+#[derive(Debug)]
+pub struct StreamSearcher<R> {
+    reader: R,
+    needle: Vec<u8>,
+    ignore_ascii_case: bool,
+    buf: Vec<u8>,
+    buf_len: usize,
+    // the offset of `buf[0]` in the stream.
+    buf_offset: u64,
+    curr_idx: usize,
+    eof: bool,
+}
+
+impl<R: Read> StreamSearcher<R> {
+    ///
+    /// create a new searcher of the needle in the reader.
+    ///
+    pub fn new<B: ?Sized + AsRef<[u8]>>(reader: R, needle: &B) -> StreamSearcher<R> {
+        Self::with_capacity(DEFAULT_BUF_SIZE, reader, needle, false)
+    }
+    ///
+    /// create a new searcher of the needle in the reader, ignore ascii case.
+    ///
+    pub fn new_ignore_ascii_case<B: ?Sized + AsRef<[u8]>>(
+        reader: R,
+        needle: &B,
+    ) -> StreamSearcher<R> {
+        Self::with_capacity(DEFAULT_BUF_SIZE, reader, needle, true)
+    }
+    ///
+    /// create a new searcher with the chunk buffer of the capacity.
+    ///
+    /// The capacity is enlarged to twice of the needle length at least.
+    ///
+    pub fn with_capacity<B: ?Sized + AsRef<[u8]>>(
+        capacity: usize,
+        reader: R,
+        needle: &B,
+        ignore_ascii_case: bool,
+    ) -> StreamSearcher<R> {
+        let needle = needle.as_ref().to_vec();
+        let capacity = capacity.max(needle.len() * 2).max(1);
+        StreamSearcher {
+            reader,
+            needle,
+            ignore_ascii_case,
+            buf: vec![0; capacity],
+            buf_len: 0,
+            buf_offset: 0,
+            curr_idx: 0,
+            eof: false,
+        }
+    }
+    ///
+    /// return the inner reader.
+    ///
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+    ///
+    /// search the next needle in the stream.
+    ///
+    /// return the offset of the stream, if it found the needle. Otherwise return None.
+    ///
+    pub fn search(&mut self) -> Result<Option<u64>> {
+        let nee_len = self.needle.len();
+        if nee_len == 0 {
+            return Ok(None);
+        }
+        loop {
+            let hay_bytes = &self.buf[self.curr_idx..self.buf_len];
+            let r = if self.ignore_ascii_case {
+                crate::naive_opt_mc_bytes_iac(hay_bytes, &self.needle)
+            } else {
+                crate::naive_opt_mc_bytes(hay_bytes, &self.needle)
+            };
+            if let Some(idx) = r {
+                let st = self.curr_idx + idx;
+                self.curr_idx = st + nee_len;
+                return Ok(Some(self.buf_offset + st as u64));
+            }
+            if self.eof {
+                self.curr_idx = self.buf_len;
+                return Ok(None);
+            }
+            self.fill_buf()?;
+        }
+    }
+    ///
+    /// includes the needle in the rest of the stream.
+    ///
+    /// It stops reading the stream, when it found the needle.
+    ///
+    pub fn includes(&mut self) -> Result<bool> {
+        Ok(self.search()?.is_some())
+    }
+    // keep the last `needle.len() - 1` bytes, and read the next chunk.
+    fn fill_buf(&mut self) -> Result<()> {
+        let keep_len = self.needle.len() - 1;
+        let keep_st = self.curr_idx.max(self.buf_len.saturating_sub(keep_len));
+        self.buf.copy_within(keep_st..self.buf_len, 0);
+        self.buf_len -= keep_st;
+        self.buf_offset += keep_st as u64;
+        self.curr_idx = 0;
+        loop {
+            match self.reader.read(&mut self.buf[self.buf_len..]) {
+                Ok(0) => {
+                    self.eof = true;
+                    return Ok(());
+                }
+                Ok(n) => {
+                    self.buf_len += n;
+                    return Ok(());
+                }
+                Err(ref e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+impl<R: Read> Iterator for StreamSearcher<R> {
+    type Item = Result<u64>;
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.search().transpose()
+    }
+}
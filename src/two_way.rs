@@ -0,0 +1,272 @@
+//
+// The Crochemore-Perrin Two-Way string matching.
+//
+// It runs in O(n + m) time and O(1) space, for the any haystack and needle.
+// This is the fallback of the naive search, when the verification work
+// exceeds the budget on the pathological inputs,
+// such as the needle "aaa...ab" in the haystack "aaaa...aaab".
+//
+// The reverse search runs the same algorithm over the reversed view of
+// the haystack and the needle. The ignore ascii case search compares
+// the ascii lowercase bytes, and the order of the bytes is also on them.
+//
+
+// The view of the bytes, forward or reverse, with or without ignore ascii case.
+trait View {
+    fn len(&self) -> usize;
+    fn at(&self, i: usize) -> u8;
+}
+
+#[derive(Clone, Copy)]
+struct Fwd<'a, const IAC: bool>(&'a [u8]);
+impl<const IAC: bool> View for Fwd<'_, IAC> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+    #[inline(always)]
+    fn at(&self, i: usize) -> u8 {
+        if IAC {
+            self.0[i].to_ascii_lowercase()
+        } else {
+            self.0[i]
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Rev<'a, const IAC: bool>(&'a [u8]);
+impl<const IAC: bool> View for Rev<'_, IAC> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+    #[inline(always)]
+    fn at(&self, i: usize) -> u8 {
+        let byte = self.0[self.0.len() - 1 - i];
+        if IAC {
+            byte.to_ascii_lowercase()
+        } else {
+            byte
+        }
+    }
+}
+
+// return the start index and the period of the maximal suffix of the needle.
+// if `reversed` is true, the maximal suffix by the reversed order of the bytes.
+fn maximal_suffix<V: View>(nee: &V, reversed: bool) -> (usize, usize) {
+    let nee_len = nee.len();
+    let mut left = 0;
+    let mut right = 1;
+    let mut offset = 0;
+    let mut period = 1;
+    while right + offset < nee_len {
+        let a = nee.at(right + offset);
+        let b = nee.at(left + offset);
+        if (a < b && !reversed) || (a > b && reversed) {
+            // the suffix is smaller, the period is the whole prefix so far.
+            right += offset + 1;
+            offset = 0;
+            period = right - left;
+        } else if a == b {
+            if offset + 1 == period {
+                right += offset + 1;
+                offset = 0;
+            } else {
+                offset += 1;
+            }
+        } else {
+            // the suffix is larger, start over from it.
+            left = right;
+            right += 1;
+            offset = 0;
+            period = 1;
+        }
+    }
+    (left, period)
+}
+
+// The critical factorization of the needle, which is not empty.
+struct TwoWay {
+    crit: usize,
+    period: usize,
+    // the needle is periodic, if the left part is a suffix of the period.
+    periodic: bool,
+}
+impl TwoWay {
+    fn new<V: View>(nee: &V) -> TwoWay {
+        let nee_len = nee.len();
+        let (crit_a, period_a) = maximal_suffix(nee, false);
+        let (crit_b, period_b) = maximal_suffix(nee, true);
+        let (crit, period) = if crit_a > crit_b {
+            (crit_a, period_a)
+        } else {
+            (crit_b, period_b)
+        };
+        let periodic =
+            crit + period <= nee_len && (0..crit).all(|i| nee.at(i) == nee.at(i + period));
+        TwoWay {
+            crit,
+            period,
+            periodic,
+        }
+    }
+    // search the needle in the haystack from the `pos`, that is accepted by
+    // `accept`. the rejected match shifts the needle as the mismatch does,
+    // so that the search keeps its state and is O(n + m).
+    fn find<V: View, F: FnMut(usize) -> bool>(
+        &self,
+        hay: &V,
+        nee: &V,
+        mut pos: usize,
+        mut accept: F,
+    ) -> Option<usize> {
+        let hay_len = hay.len();
+        let nee_len = nee.len();
+        let crit = self.crit;
+        if self.periodic {
+            let period = self.period;
+            // the length of the prefix that is known to be matched.
+            let mut memory = 0;
+            while pos + nee_len <= hay_len {
+                let mut i = crit.max(memory);
+                while i < nee_len && nee.at(i) == hay.at(pos + i) {
+                    i += 1;
+                }
+                if i < nee_len {
+                    pos += i - crit + 1;
+                    memory = 0;
+                    continue;
+                }
+                let mut j = crit;
+                while j > memory && nee.at(j - 1) == hay.at(pos + j - 1) {
+                    j -= 1;
+                }
+                if j <= memory && accept(pos) {
+                    return Some(pos);
+                }
+                pos += period;
+                memory = nee_len - period;
+            }
+        } else {
+            let shift = crit.max(nee_len - crit) + 1;
+            while pos + nee_len <= hay_len {
+                let mut i = crit;
+                while i < nee_len && nee.at(i) == hay.at(pos + i) {
+                    i += 1;
+                }
+                if i < nee_len {
+                    pos += i - crit + 1;
+                    continue;
+                }
+                let mut j = crit;
+                while j > 0 && nee.at(j - 1) == hay.at(pos + j - 1) {
+                    j -= 1;
+                }
+                if j == 0 && accept(pos) {
+                    return Some(pos);
+                }
+                pos += shift;
+            }
+        }
+        None
+    }
+}
+
+// search the first match that is accepted by `accept`.
+fn two_way_by<V: View, F: FnMut(usize) -> bool>(hay: V, nee: V, accept: F) -> Option<usize> {
+    if nee.len() == 0 {
+        return Some(0);
+    }
+    if hay.len() < nee.len() {
+        return None;
+    }
+    TwoWay::new(&nee).find(&hay, &nee, 0, accept)
+}
+
+// count the matches. the overlapping matches are counted by rejecting
+// all of them, otherwise the next search starts at the end of the match.
+fn two_way_count_view<V: View>(hay: V, nee: V, overlapping: bool) -> usize {
+    let nee_len = nee.len();
+    if nee_len == 0 || hay.len() < nee_len {
+        return 0;
+    }
+    let two_way = TwoWay::new(&nee);
+    let mut count = 0;
+    if overlapping {
+        two_way.find(&hay, &nee, 0, |_| {
+            count += 1;
+            false
+        });
+        return count;
+    }
+    let mut pos = 0;
+    while let Some(idx) = two_way.find(&hay, &nee, pos, |_| true) {
+        count += 1;
+        pos = idx + nee_len;
+    }
+    count
+}
+
+#[inline]
+pub(crate) fn two_way_count(hay_bytes: &[u8], nee_bytes: &[u8], overlapping: bool) -> usize {
+    two_way_count_view(
+        Fwd::<false>(hay_bytes),
+        Fwd::<false>(nee_bytes),
+        overlapping,
+    )
+}
+
+#[inline]
+pub(crate) fn two_way_count_iac(hay_bytes: &[u8], nee_bytes: &[u8], overlapping: bool) -> usize {
+    two_way_count_view(Fwd::<true>(hay_bytes), Fwd::<true>(nee_bytes), overlapping)
+}
+
+//
+// The searches with `accept`, which is given the index of the match
+// in the haystack, and rejects the match by returning false.
+//
+
+#[inline]
+pub(crate) fn two_way_search_by<F: FnMut(usize) -> bool>(
+    hay_bytes: &[u8],
+    nee_bytes: &[u8],
+    accept: F,
+) -> Option<usize> {
+    two_way_by(Fwd::<false>(hay_bytes), Fwd::<false>(nee_bytes), accept)
+}
+
+#[inline]
+pub(crate) fn two_way_rsearch_by<F: FnMut(usize) -> bool>(
+    hay_bytes: &[u8],
+    nee_bytes: &[u8],
+    mut accept: F,
+) -> Option<usize> {
+    let last = hay_bytes.len().wrapping_sub(nee_bytes.len());
+    two_way_by(Rev::<false>(hay_bytes), Rev::<false>(nee_bytes), |idx| {
+        accept(last - idx)
+    })
+    .map(|idx| last - idx)
+}
+
+#[inline]
+pub(crate) fn two_way_search_iac_by<F: FnMut(usize) -> bool>(
+    hay_bytes: &[u8],
+    nee_bytes: &[u8],
+    accept: F,
+) -> Option<usize> {
+    two_way_by(Fwd::<true>(hay_bytes), Fwd::<true>(nee_bytes), accept)
+}
+
+#[inline]
+pub(crate) fn two_way_rsearch_iac_by<F: FnMut(usize) -> bool>(
+    hay_bytes: &[u8],
+    nee_bytes: &[u8],
+    mut accept: F,
+) -> Option<usize> {
+    let last = hay_bytes.len().wrapping_sub(nee_bytes.len());
+    two_way_by(Rev::<true>(hay_bytes), Rev::<true>(nee_bytes), |idx| {
+        accept(last - idx)
+    })
+    .map(|idx| last - idx)
+}
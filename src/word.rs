@@ -0,0 +1,263 @@
+//
+// The whole word match.
+//
+// The candidates are verified on the same loop as the search, and then
+// the match is rejected if the neighbor before or after it is a word character.
+// The rejected match does not stop the loop, the search continues to
+// the next candidate.
+//
+// The empty needle never matches.
+//
+use crate::mc_generic::{pivot_rsearch_by, pivot_rsearch_iac_by};
+use crate::mc_generic::{pivot_search_by, pivot_search_iac_by};
+
+///
+/// The definition of the word characters for the whole word match.
+///
+/// Examples
+///
+/// ```rust
+/// use naive_opt::{Search, WordChars};
+///
+/// assert_eq!("width id".search_word("id", WordChars::Ascii), Some(6));
+/// assert_eq!("café".search_word("caf", WordChars::Ascii), Some(0));
+/// assert_eq!("café".search_word("caf", WordChars::Unicode), None);
+/// ```
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WordChars {
+    /// The ascii alphanumeric characters and `_`.
+    #[default]
+    Ascii,
+    /// The unicode alphanumeric characters and `_`.
+    Unicode,
+}
+impl WordChars {
+    ///
+    /// return true if the character is a word character.
+    ///
+    #[inline]
+    pub fn is_word_char(self, c: char) -> bool {
+        match self {
+            WordChars::Ascii => c.is_ascii_alphanumeric() || c == '_',
+            WordChars::Unicode => c.is_alphanumeric() || c == '_',
+        }
+    }
+}
+
+// the character that ends at `idx`, if it is valid UTF-8.
+fn char_before(hay_bytes: &[u8], idx: usize) -> Option<char> {
+    let lower = idx.saturating_sub(4);
+    let st = (lower..idx)
+        .rev()
+        .find(|&i| (hay_bytes[i] as i8) >= -0x40)?;
+    let s = core::str::from_utf8(&hay_bytes[st..idx]).ok()?;
+    s.chars().next()
+}
+
+// the character that starts at `idx`, if it is valid UTF-8.
+pub(crate) fn char_after(hay_bytes: &[u8], idx: usize) -> Option<char> {
+    let bytes = &hay_bytes[idx..hay_bytes.len().min(idx + 4)];
+    let s = match core::str::from_utf8(bytes) {
+        Ok(s) => s,
+        Err(e) => core::str::from_utf8(&bytes[..e.valid_up_to()]).ok()?,
+    };
+    s.chars().next()
+}
+
+// return true if the neighbors of `st..ed` are not word characters.
+#[inline]
+fn is_word_bounded(hay_bytes: &[u8], st: usize, ed: usize, word: WordChars) -> bool {
+    let (word_before, word_after) = match word {
+        WordChars::Ascii => {
+            let is_word = |b: u8| b.is_ascii_alphanumeric() || b == b'_';
+            (
+                st > 0 && is_word(hay_bytes[st - 1]),
+                ed < hay_bytes.len() && is_word(hay_bytes[ed]),
+            )
+        }
+        WordChars::Unicode => {
+            let is_word = |c: Option<char>| c.map(|c| word.is_word_char(c)).unwrap_or(false);
+            (
+                st > 0 && is_word(char_before(hay_bytes, st)),
+                ed < hay_bytes.len() && is_word(char_after(hay_bytes, ed)),
+            )
+        }
+    };
+    !word_before && !word_after
+}
+
+// the pivot and the guard, the same as the default search. the two rarest bytes
+// of the needle of `DUAL_PIVOT_MIN_LEN` bytes or more, otherwise the 1st byte
+// or the last byte. the needle must not be empty.
+#[inline]
+fn word_pivots(nee_bytes: &[u8]) -> (usize, Option<usize>) {
+    if nee_bytes.len() >= crate::DUAL_PIVOT_MIN_LEN {
+        crate::select_pivots(nee_bytes)
+    } else if crate::prefer_1st_strategy(nee_bytes) {
+        (0, None)
+    } else {
+        (nee_bytes.len() - 1, None)
+    }
+}
+
+///
+/// search the whole word of the needle in the haystack, from the `start`.
+///
+/// The neighbors are checked in the whole haystack, not only after the `start`.
+///
+pub(crate) fn search_word_from(
+    hay_bytes: &[u8],
+    nee_bytes: &[u8],
+    start: usize,
+    word: WordChars,
+    ignore_ascii_case: bool,
+) -> Option<usize> {
+    let nee_len = nee_bytes.len();
+    if nee_len == 0 {
+        return None;
+    }
+    let hay_part = &hay_bytes[start..];
+    let accept = |idx: usize| is_word_bounded(hay_bytes, start + idx, start + idx + nee_len, word);
+    let (pivot, guard) = word_pivots(nee_bytes);
+    let pick_byte = nee_bytes[pivot];
+    let r = if ignore_ascii_case {
+        pivot_search_iac_by(
+            hay_part,
+            nee_bytes,
+            pivot,
+            pick_byte.to_ascii_uppercase(),
+            pick_byte.to_ascii_lowercase(),
+            guard,
+            accept,
+        )
+    } else {
+        pivot_search_by(hay_part, nee_bytes, pivot, pick_byte, guard, accept)
+    };
+    r.map(|idx| start + idx)
+}
+
+///
+/// reverse search the whole word of the needle in the haystack, before the `end`.
+///
+/// The neighbors are checked in the whole haystack, not only before the `end`.
+///
+pub(crate) fn rsearch_word_before(
+    hay_bytes: &[u8],
+    nee_bytes: &[u8],
+    end: usize,
+    word: WordChars,
+    ignore_ascii_case: bool,
+) -> Option<usize> {
+    let nee_len = nee_bytes.len();
+    if nee_len == 0 {
+        return None;
+    }
+    let hay_part = &hay_bytes[..end];
+    let accept = |idx: usize| is_word_bounded(hay_bytes, idx, idx + nee_len, word);
+    let (pivot, guard) = word_pivots(nee_bytes);
+    let pick_byte = nee_bytes[pivot];
+    if ignore_ascii_case {
+        pivot_rsearch_iac_by(
+            hay_part,
+            nee_bytes,
+            pivot,
+            pick_byte.to_ascii_uppercase(),
+            pick_byte.to_ascii_lowercase(),
+            guard,
+            accept,
+        )
+    } else {
+        pivot_rsearch_by(hay_part, nee_bytes, pivot, pick_byte, guard, accept)
+    }
+}
+
+///
+/// Created with the method [Search::search_indices_word()](crate::Search::search_indices_word)
+/// and [Search::search_indices_word_ignore_ascii_case()](crate::Search::search_indices_word_ignore_ascii_case).
+///
+#[derive(Debug, Clone)]
+pub struct SearchIndicesWord<'a, 'n> {
+    curr_idx: usize,
+    haystack: &'a str,
+    needle: &'n str,
+    word: WordChars,
+    ignore_ascii_case: bool,
+}
+impl<'a, 'n> SearchIndicesWord<'a, 'n> {
+    pub(crate) fn new(
+        a_haystack: &'a str,
+        a_needle: &'n str,
+        a_word: WordChars,
+        a_ignore_ascii_case: bool,
+    ) -> SearchIndicesWord<'a, 'n> {
+        SearchIndicesWord {
+            curr_idx: 0,
+            haystack: a_haystack,
+            needle: a_needle,
+            word: a_word,
+            ignore_ascii_case: a_ignore_ascii_case,
+        }
+    }
+}
+impl<'a> Iterator for SearchIndicesWord<'a, '_> {
+    type Item = (usize, &'a str);
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        let st = search_word_from(
+            self.haystack.as_bytes(),
+            self.needle.as_bytes(),
+            self.curr_idx,
+            self.word,
+            self.ignore_ascii_case,
+        )?;
+        let ed = st + self.needle.len();
+        self.curr_idx = ed;
+        Some((st, &self.haystack[st..ed]))
+    }
+}
+
+///
+/// Created with the method [SearchBytes::search_indices_word_bytes()](crate::SearchBytes::search_indices_word_bytes)
+/// and [SearchBytes::search_indices_word_bytes_ignore_ascii_case()](crate::SearchBytes::search_indices_word_bytes_ignore_ascii_case).
+///
+#[derive(Debug, Clone)]
+pub struct SearchIndicesWordBytes<'a, 'n> {
+    curr_idx: usize,
+    haystack: &'a [u8],
+    needle: &'n [u8],
+    word: WordChars,
+    ignore_ascii_case: bool,
+}
+impl<'a, 'n> SearchIndicesWordBytes<'a, 'n> {
+    pub(crate) fn new(
+        a_haystack: &'a [u8],
+        a_needle: &'n [u8],
+        a_word: WordChars,
+        a_ignore_ascii_case: bool,
+    ) -> SearchIndicesWordBytes<'a, 'n> {
+        SearchIndicesWordBytes {
+            curr_idx: 0,
+            haystack: a_haystack,
+            needle: a_needle,
+            word: a_word,
+            ignore_ascii_case: a_ignore_ascii_case,
+        }
+    }
+}
+impl<'a> Iterator for SearchIndicesWordBytes<'a, '_> {
+    type Item = (usize, &'a [u8]);
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        let st = search_word_from(
+            self.haystack,
+            self.needle,
+            self.curr_idx,
+            self.word,
+            self.ignore_ascii_case,
+        )?;
+        let ed = st + self.needle.len();
+        self.curr_idx = ed;
+        Some((st, &self.haystack[st..ed]))
+    }
+}
@@ -0,0 +1,76 @@
+#![cfg(feature = "alloc")]
+
+//
+// The haystacks that the pivot byte of the needle is frequent in,
+// in spite of the stochastics. The adaptive strategy switches the pivot
+// on these, and the others must find the same.
+//
+use naive_opt::{Search, SearchBytes};
+
+fn repeated(unit: &str, n: usize) -> String {
+    let mut s = String::new();
+    for _ in 0..n {
+        s.push_str(unit);
+    }
+    s
+}
+
+#[test]
+fn test_frequent_pivot() {
+    // 'z' is rare by the stochastics, but frequent here.
+    let hay = repeated("zzzzzzzq", 100) + "zzzzzxyz" + &repeated("zzzzzzzq", 100);
+    for nee in ["zxyz", "zzxyz", "xyz", "zzzzxyzz", "zzzzzzzz", "qz", "zzz"] {
+        assert_eq!(hay.search(nee), hay.find(nee), "{:?}", nee);
+        assert_eq!(hay.rsearch(nee), hay.rfind(nee), "{:?}", nee);
+    }
+    let nee = "zzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzz";
+    assert_eq!(hay.search(nee), None);
+    assert_eq!(hay.rsearch(nee), None);
+    // at the both ends
+    let hay = "xyz".to_string() + &repeated("zzzzzzzq", 100) + "xyz";
+    assert_eq!(hay.search("xyz"), Some(0));
+    assert_eq!(hay.rsearch("xyz"), Some(hay.len() - 3));
+}
+
+#[test]
+fn test_frequent_pivot_indices() {
+    let hay = repeated("qqqqqqqz", 64) + "qz" + &repeated("qqqqqqqq", 64) + "qz";
+    let v1: Vec<_> = hay.search_indices("qz").collect();
+    let v2: Vec<_> = hay.match_indices("qz").collect();
+    assert_eq!(v1, v2);
+    let v1: Vec<_> = hay.rsearch_indices("qz").collect();
+    let v2: Vec<_> = hay.rmatch_indices("qz").collect();
+    assert_eq!(v1, v2);
+}
+
+#[test]
+fn test_frequent_pivot_bytes() {
+    let mut hay = vec![b'\xff'; 1000];
+    hay[500..504].copy_from_slice(b"\xffab\xff");
+    let hay = &hay[..];
+    assert_eq!(hay.search_bytes(&b"\xffab\xff"[..]), Some(500));
+    assert_eq!(hay.rsearch_bytes(&b"\xffab\xff"[..]), Some(500));
+    assert_eq!(hay.search_bytes(&b"\xffba\xff"[..]), None);
+    assert_eq!(hay.rsearch_bytes(&b"\xffba\xff"[..]), None);
+}
+
+#[test]
+fn test_frequent_pivot_ignore_ascii_case() {
+    let hay = repeated("ZzZzZzZq", 100) + "zXyZ" + &repeated("zZzZzZzQ", 100);
+    assert_eq!(hay.search_ignore_ascii_case("ZxYz"), Some(800));
+    assert_eq!(hay.rsearch_ignore_ascii_case("ZxYz"), Some(800));
+    assert_eq!(hay.search_ignore_ascii_case("qzx"), Some(799));
+    assert_eq!(hay.rsearch_ignore_ascii_case("ZZZZZZZZZ"), None);
+}
+
+#[test]
+fn test_switched_at_the_start() {
+    // the pivot is switched at the candidate of the index 0.
+    let hay = "bbbbbbbbbbb";
+    assert_eq!(hay.rsearch("baaa"), None);
+    assert_eq!(hay.rsearch_ignore_ascii_case("BAAA"), None);
+    assert_eq!(hay.search("baaa"), None);
+    let hay = "baaabbbbbbbbbbb";
+    assert_eq!(hay.rsearch("baaa"), Some(0));
+    assert_eq!(hay.rsearch_ignore_ascii_case("BAAA"), Some(0));
+}
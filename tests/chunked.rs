@@ -0,0 +1,148 @@
+#![cfg(feature = "alloc")]
+
+use naive_opt::{chunked_rsearch, chunked_rsearch_ignore_ascii_case};
+use naive_opt::{chunked_search, chunked_search_ignore_ascii_case, ChunkedHaystack};
+use std::collections::VecDeque;
+
+#[test]
+fn test_empty() {
+    let haystack: [&str; 0] = [];
+    assert_eq!(chunked_search(&haystack, "abc"), None);
+    assert_eq!(chunked_rsearch(&haystack, "abc"), None);
+    assert_eq!(chunked_search(&haystack, ""), Some(0));
+    assert_eq!(chunked_rsearch(&haystack, ""), Some(0));
+    let haystack = ["", "abc", ""];
+    assert_eq!(chunked_search(&haystack, ""), Some(0));
+    assert_eq!(chunked_rsearch(&haystack, ""), Some(3));
+    assert_eq!(haystack.total_len(), 3);
+}
+
+#[test]
+fn test_search() {
+    let haystack = ["abc3", "45a", "bc901", "abc"];
+    assert_eq!(chunked_search(&haystack, "abc"), Some(0));
+    assert_eq!(chunked_search(&haystack, "345"), Some(3));
+    assert_eq!(chunked_search(&haystack, "45abc9"), Some(4));
+    assert_eq!(chunked_search(&haystack, "3"), Some(3));
+    assert_eq!(chunked_search(&haystack, "abc345abc901abc"), Some(0));
+    assert_eq!(chunked_search(&haystack, "xyz"), None);
+    //
+    assert_eq!(chunked_rsearch(&haystack, "abc"), Some(12));
+    assert_eq!(chunked_rsearch(&haystack, "1abc"), Some(11));
+    assert_eq!(chunked_rsearch(&haystack, "c345a"), Some(2));
+    assert_eq!(chunked_rsearch(&haystack, "abc345abc901abc"), Some(0));
+    assert_eq!(chunked_rsearch(&haystack, "xyz"), None);
+}
+
+#[test]
+fn test_spanning_many_chunks() {
+    let haystack = ["a", "b", "", "c", "3", "4", "5"];
+    assert_eq!(chunked_search(&haystack, "bc34"), Some(1));
+    assert_eq!(chunked_rsearch(&haystack, "bc34"), Some(1));
+    assert_eq!(chunked_search(&haystack, "abc345"), Some(0));
+    assert_eq!(chunked_rsearch(&haystack, "abc345"), Some(0));
+    let haystack: Vec<&[u8]> = vec![b"\xff", b"\xfe\xff", b"\xfe"];
+    assert_eq!(chunked_search(&haystack, b"\xff\xfe"), Some(0));
+    assert_eq!(chunked_rsearch(&haystack, b"\xff\xfe"), Some(2));
+}
+
+#[test]
+fn test_ignore_ascii_case() {
+    let haystack = vec!["abC3".to_string(), "45A".to_string(), "Bc901".to_string()];
+    assert_eq!(chunked_search_ignore_ascii_case(&haystack, "ABC"), Some(0));
+    assert_eq!(chunked_rsearch_ignore_ascii_case(&haystack, "ABC"), Some(6));
+    assert_eq!(
+        chunked_search_ignore_ascii_case(&haystack, "c345ab"),
+        Some(2)
+    );
+    assert_eq!(chunked_search(&haystack, "c345ab"), None);
+}
+
+#[test]
+fn test_vec_deque() {
+    let mut deque: VecDeque<u8> = VecDeque::new();
+    deque.extend(b"345abc");
+    deque.push_front(b'c');
+    deque.push_front(b'b');
+    deque.push_front(b'a');
+    let (front, back) = deque.as_slices();
+    assert!(!front.is_empty() && !back.is_empty());
+    assert_eq!(deque.total_len(), 9);
+    assert_eq!(chunked_search(&deque, "c34"), Some(2));
+    assert_eq!(chunked_search(&deque, "abc"), Some(0));
+    assert_eq!(chunked_rsearch(&deque, "abc"), Some(6));
+    assert_eq!(chunked_rsearch(&deque, "bc3"), Some(1));
+}
+
+#[test]
+fn test_like_contiguous() {
+    let text = "The quick brown fox jumps over the lazy dog. αβγ The end.";
+    let nees = [
+        "The",
+        "the",
+        "o",
+        "fox jumps",
+        "dog. α",
+        "βγ T",
+        "end.",
+        "xyz",
+        "T",
+    ];
+    for size in 1..8 {
+        let chunks: Vec<&[u8]> = text.as_bytes().chunks(size).collect();
+        for nee in nees {
+            assert_eq!(
+                chunked_search(&chunks, nee),
+                text.find(nee),
+                "{} {:?}",
+                size,
+                nee
+            );
+            assert_eq!(
+                chunked_rsearch(&chunks, nee),
+                text.rfind(nee),
+                "{} {:?}",
+                size,
+                nee
+            );
+            let lc = text.to_ascii_lowercase();
+            let nee_lc = nee.to_ascii_lowercase();
+            assert_eq!(
+                chunked_search_ignore_ascii_case(&chunks, nee),
+                lc.find(&nee_lc),
+                "{} {:?}",
+                size,
+                nee
+            );
+            assert_eq!(
+                chunked_rsearch_ignore_ascii_case(&chunks, nee),
+                lc.rfind(&nee_lc),
+                "{} {:?}",
+                size,
+                nee
+            );
+        }
+    }
+}
+
+#[test]
+fn test_one_byte_chunks() {
+    // the rope of the 1 byte chunks and the long needle.
+    let text = "a".repeat(200_000) + "b" + &"a".repeat(1_000);
+    let chunks: Vec<&[u8]> = text.as_bytes().chunks(1).collect();
+    let needle = "a".repeat(2_000) + "b";
+    let tm = std::time::Instant::now();
+    assert_eq!(chunked_search(&chunks, &needle), Some(198_000));
+    assert_eq!(chunked_rsearch(&chunks, &needle), Some(198_000));
+    assert_eq!(chunked_search(&chunks, "ab"), Some(199_999));
+    assert_eq!(chunked_rsearch(&chunks, "ba"), Some(200_000));
+    assert_eq!(chunked_search(&chunks, &("b".to_string() + &needle)), None);
+    assert!(tm.elapsed().as_secs() < 5, "{:?}", tm.elapsed());
+    //
+    // the short chunks between the long chunks.
+    let chunks = ["xxabc", "1", "", "2", "3abcyy", "a", "bc"];
+    assert_eq!(chunked_search(&chunks, "c123a"), Some(4));
+    assert_eq!(chunked_rsearch(&chunks, "abc"), Some(13));
+    assert_eq!(chunked_rsearch(&chunks, "yyab"), Some(11));
+    assert_eq!(chunked_search(&chunks, "bc1"), Some(3));
+}
@@ -0,0 +1,97 @@
+#![cfg(feature = "alloc")]
+
+use naive_opt::{Search, SearchBytes};
+use std::borrow::Cow;
+use std::rc::Rc;
+use std::sync::Arc;
+
+#[test]
+fn test_str_haystacks() {
+    let s = "aaa bbb ccc bbb";
+    let boxed: Box<str> = s.into();
+    let rc: Rc<str> = s.into();
+    let arc: Arc<str> = s.into();
+    let cow: Cow<str> = Cow::Borrowed(s);
+    let owned: Cow<str> = Cow::Owned(s.to_string());
+    assert_eq!(boxed.search("bbb"), Some(4));
+    assert_eq!(rc.rsearch("bbb"), Some(12));
+    assert_eq!(arc.search_count("bbb"), 2);
+    assert!(cow.includes("ccc"));
+    assert_eq!(owned.search_ignore_ascii_case("CCC"), Some(8));
+    let v: Vec<_> = arc.search_indices("bbb").collect();
+    assert_eq!(v, [(4, "bbb"), (12, "bbb")]);
+    let v: Vec<_> = rc.search_split(' ').collect();
+    assert_eq!(v, ["aaa", "bbb", "ccc", "bbb"]);
+    assert_eq!(boxed.search_replace("bbb", "x"), "aaa x ccc x");
+    // the references
+    let string = s.to_string();
+    let string_ref: &String = &string;
+    assert_eq!(string_ref.search("ccc"), Some(8));
+    let arc_ref: &&Arc<str> = &&arc;
+    assert_eq!(arc_ref.search("ccc"), Some(8));
+}
+
+#[test]
+fn test_str_needles() {
+    let haystack = "aaa bbb ccc bbb";
+    let boxed: Box<str> = "bbb".into();
+    let rc: Rc<str> = "bbb".into();
+    let arc: Arc<str> = "bbb".into();
+    let cow: Cow<str> = Cow::Borrowed("bbb");
+    assert_eq!(haystack.search(&boxed), Some(4));
+    assert_eq!(haystack.rsearch(&rc), Some(12));
+    assert_eq!(haystack.search_count(&arc), 2);
+    assert!(haystack.includes(&cow));
+    assert_eq!(haystack.search_ignore_ascii_case(&boxed), Some(4));
+    let v: Vec<_> = haystack.search_indices(&arc).collect();
+    assert_eq!(v, [(4, "bbb"), (12, "bbb")]);
+    assert_eq!(arc.search(&arc), Some(0));
+    //
+    let hay_bytes: &[u8] = b"aaa bbb\xff ccc bbb";
+    assert_eq!(hay_bytes.search_bytes(&boxed), Some(4));
+    assert_eq!(hay_bytes.rsearch_bytes(&rc), Some(13));
+    assert_eq!(hay_bytes.search_count_bytes(&cow), 2);
+}
+
+#[test]
+fn test_bytes_haystacks() {
+    let b: &[u8] = b"aaa bbb\xff ccc bbb";
+    let vec: Vec<u8> = b.to_vec();
+    let array: [u8; 16] = b.try_into().unwrap();
+    let boxed: Box<[u8]> = b.into();
+    let arc: Arc<[u8]> = b.into();
+    let cow: Cow<[u8]> = Cow::Borrowed(b);
+    assert_eq!(vec.search_bytes(b"bbb"), Some(4));
+    assert_eq!(array.rsearch_bytes(b"bbb"), Some(13));
+    assert_eq!(boxed.search_count_bytes(b"bbb"), 2);
+    assert!(arc.includes_bytes(b"ccc"));
+    assert_eq!(cow.search_bytes_ignore_ascii_case(b"CCC"), Some(9));
+    let v: Vec<_> = vec.search_indices_bytes(b"bbb").collect();
+    assert_eq!(v, [(4, &b"bbb"[..]), (13, &b"bbb"[..])]);
+    assert_eq!(
+        vec.search_replace_bytes(b"bbb", b"x"),
+        b"aaa x\xff ccc x".to_vec()
+    );
+}
+
+#[test]
+fn test_bytes_needles() {
+    let haystack: &[u8] = b"aaa bbb\xff ccc bbb";
+    let vec: Vec<u8> = b"bbb".to_vec();
+    let boxed: Box<[u8]> = b"bbb"[..].into();
+    let rc: Rc<[u8]> = b"bbb"[..].into();
+    let arc: Arc<[u8]> = b"bbb"[..].into();
+    let cow: Cow<[u8]> = Cow::Owned(b"\xff".to_vec());
+    assert_eq!(haystack.search_bytes(b"bbb"), Some(4));
+    assert_eq!(haystack.search_bytes(*b"bbb"), Some(4));
+    assert_eq!(haystack.rsearch_bytes(&vec), Some(13));
+    assert_eq!(haystack.search_count_bytes(&boxed), 2);
+    assert_eq!(haystack.search_count_overlapping_bytes(&rc), 2);
+    assert!(haystack.includes_bytes(&arc));
+    assert_eq!(haystack.search_bytes(&cow), Some(7));
+    assert_eq!(haystack.search_bytes_ignore_ascii_case(b"CCC"), Some(9));
+    let v: Vec<_> = haystack.rsearch_indices_bytes(&vec).collect();
+    assert_eq!(v, [(13, &b"bbb"[..]), (4, &b"bbb"[..])]);
+    assert_eq!(vec.search_bytes(&vec), Some(0));
+    assert_eq!(haystack.search_bytes(b""), Some(0));
+}
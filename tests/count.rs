@@ -0,0 +1,147 @@
+use naive_opt::{string_search_count, string_search_count_bytes};
+use naive_opt::{
+    string_search_count_bytes_ignore_ascii_case, string_search_count_ignore_ascii_case,
+};
+use naive_opt::{string_search_count_overlapping, string_search_count_overlapping_bytes};
+use naive_opt::{
+    string_search_count_overlapping_bytes_ignore_ascii_case,
+    string_search_count_overlapping_ignore_ascii_case,
+};
+use naive_opt::{Search, SearchBytes, SearchIn};
+
+#[test]
+fn test_empty() {
+    assert_eq!("abc".search_count(""), 0);
+    assert_eq!("abc".search_count_overlapping(""), 0);
+    assert_eq!("".search_count("abc"), 0);
+    assert_eq!("ab".search_count("abc"), 0);
+    let hay: &[u8] = b"abc";
+    assert_eq!(hay.search_count_bytes(""), 0);
+    assert_eq!(hay.search_count_overlapping_bytes(""), 0);
+}
+
+#[test]
+fn test_count() {
+    assert_eq!("abc345abc901abc".search_count("abc"), 3);
+    assert_eq!("abc345abc901abc".search_count('c'), 3);
+    assert_eq!("ababa".search_count("aba"), 1);
+    assert_eq!("aaaa".search_count("aa"), 2);
+    assert_eq!("αβγαβγ".search_count("βγ"), 2);
+    assert_eq!(string_search_count("abc345abc901abc", "abc"), 3);
+    //
+    assert_eq!("ababa".search_count_overlapping("aba"), 2);
+    assert_eq!("aaaa".search_count_overlapping("aa"), 3);
+    assert_eq!("ααα".search_count_overlapping("αα"), 2);
+    assert_eq!(string_search_count_overlapping("aaaa", "aa"), 3);
+}
+
+#[test]
+fn test_count_ignore_ascii_case() {
+    assert_eq!("abcABCaBc".search_count_ignore_ascii_case("AbC"), 3);
+    assert_eq!("AaAa".search_count_ignore_ascii_case("aa"), 2);
+    assert_eq!("AaAa".search_count_overlapping_ignore_ascii_case("aa"), 3);
+    assert_eq!(string_search_count_ignore_ascii_case("abcABC", "abc"), 2);
+    assert_eq!(
+        string_search_count_overlapping_ignore_ascii_case("AaAa", "aa"),
+        3
+    );
+}
+
+#[test]
+fn test_count_bytes() {
+    let hay: &[u8] = b"\xffab\xffAB\xffab";
+    assert_eq!(hay.search_count_bytes("ab"), 2);
+    assert_eq!(hay.search_count_bytes(&b"\xffa"[..]), 2);
+    assert_eq!(hay.search_count_bytes_ignore_ascii_case("ab"), 3);
+    assert_eq!(string_search_count_bytes(hay, "ab"), 2);
+    assert_eq!(string_search_count_bytes_ignore_ascii_case(hay, "AB"), 3);
+    //
+    let hay: &[u8] = b"\xff\xff\xff\xff";
+    assert_eq!(hay.search_count_bytes(&b"\xff\xff"[..]), 2);
+    assert_eq!(hay.search_count_overlapping_bytes(&b"\xff\xff"[..]), 3);
+    assert_eq!(
+        string_search_count_overlapping_bytes(hay, &b"\xff\xff"[..]),
+        3
+    );
+    let hay: &[u8] = b"aAaA";
+    assert_eq!(
+        hay.search_count_overlapping_bytes_ignore_ascii_case("aa"),
+        3
+    );
+    assert_eq!(
+        string_search_count_overlapping_bytes_ignore_ascii_case(hay, "AA"),
+        3
+    );
+}
+
+#[test]
+fn test_count_like_indices() {
+    let hays = [
+        "abc345abc901abc",
+        "aaaaaaaaa",
+        "abababababa",
+        "αβγαβγααβγ",
+        "The quick brown fox jumps over the lazy dog",
+    ];
+    let nees = ["a", "aa", "aba", "abc", "αβγ", "α", "the", "o", " "];
+    for hay in hays {
+        for nee in nees {
+            assert_eq!(
+                hay.search_count(nee),
+                hay.search_indices(nee).count(),
+                "{:?} {:?}",
+                hay,
+                nee
+            );
+            assert_eq!(
+                hay.search_count_overlapping(nee),
+                hay.search_indices_overlapping(nee).count(),
+                "{:?} {:?}",
+                hay,
+                nee
+            );
+            assert_eq!(
+                hay.search_count_ignore_ascii_case(nee),
+                hay.search_indices_ignore_ascii_case(nee).count(),
+                "{:?} {:?}",
+                hay,
+                nee
+            );
+            assert_eq!(
+                hay.search_count_overlapping_ignore_ascii_case(nee),
+                hay.search_indices_overlapping_ignore_ascii_case(nee)
+                    .count(),
+                "{:?} {:?}",
+                hay,
+                nee
+            );
+        }
+    }
+}
+
+// a needle without the own counting loop, that counts by the repeated searches.
+struct Digit;
+impl<'a> SearchIn<'a> for Digit {
+    fn search_in(&self, haystack: &'a str) -> Option<usize> {
+        haystack.find(|c: char| c.is_ascii_digit())
+    }
+    fn rsearch_in(&self, haystack: &'a str) -> Option<usize> {
+        haystack.rfind(|c: char| c.is_ascii_digit())
+    }
+    fn search_in_ignore_ascii_case(&self, haystack: &'a str) -> Option<usize> {
+        self.search_in(haystack)
+    }
+    fn rsearch_in_ignore_ascii_case(&self, haystack: &'a str) -> Option<usize> {
+        self.rsearch_in(haystack)
+    }
+    fn max_match_len(&self) -> usize {
+        1
+    }
+}
+
+#[test]
+fn test_count_default() {
+    assert_eq!("a1b22c333".search_count(Digit), 6);
+    assert_eq!("a1b22c333".search_count_overlapping(Digit), 6);
+    assert_eq!("abc".search_count(Digit), 0);
+}
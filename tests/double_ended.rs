@@ -0,0 +1,121 @@
+use naive_opt::{ByteSet, Search, SearchBytes};
+
+#[test]
+fn test_rev() {
+    let v: Vec<_> = "abc345abc901abc".search_indices('b').rev().collect();
+    assert_eq!(v, [(13, "b"), (7, "b"), (1, "b")]);
+    let v: Vec<_> = "abc345abc901abc".rsearch_indices('b').rev().collect();
+    assert_eq!(v, [(1, "b"), (7, "b"), (13, "b")]);
+    //
+    let hay: &[u8] = b"abc345abc901abc";
+    let v: Vec<_> = hay.search_indices_bytes('b').rev().collect();
+    assert_eq!(v, [(13, &b"b"[..]), (7, b"b"), (1, b"b")]);
+    let v: Vec<_> = hay.rsearch_indices_bytes('b').rev().collect();
+    assert_eq!(v, [(1, &b"b"[..]), (7, b"b"), (13, b"b")]);
+}
+
+#[test]
+fn test_rev_ignore_ascii_case() {
+    let hay = "abc345aBc901ABC";
+    let v: Vec<_> = hay.search_indices_ignore_ascii_case('b').rev().collect();
+    assert_eq!(v, [(13, "B"), (7, "B"), (1, "b")]);
+    let v: Vec<_> = hay.rsearch_indices_ignore_ascii_case('b').rev().collect();
+    assert_eq!(v, [(1, "b"), (7, "B"), (13, "B")]);
+    //
+    let hay: &[u8] = b"abc345aBc901ABC";
+    let v: Vec<_> = hay
+        .search_indices_bytes_ignore_ascii_case('b')
+        .rev()
+        .collect();
+    assert_eq!(v, [(13, &b"B"[..]), (7, b"B"), (1, b"b")]);
+    let v: Vec<_> = hay
+        .rsearch_indices_bytes_ignore_ascii_case('b')
+        .rev()
+        .collect();
+    assert_eq!(v, [(1, &b"b"[..]), (7, b"B"), (13, b"B")]);
+}
+
+#[test]
+fn test_rev_set() {
+    let hay = "a,b;c,d";
+    let v: Vec<_> = hay.search_indices([',', ';']).rev().collect();
+    assert_eq!(v, [(5, ","), (3, ";"), (1, ",")]);
+    let v: Vec<_> = hay.search_indices(|c: char| c == ';').rev().collect();
+    assert_eq!(v, [(3, ";")]);
+    //
+    let hay: &[u8] = b"a,b;c,d";
+    let v: Vec<_> = hay.search_indices_bytes(ByteSet(b",;")).rev().collect();
+    assert_eq!(v, [(5, &b","[..]), (3, b";"), (1, b",")]);
+    let v: Vec<_> = hay.search_indices_bytes(|b: u8| b == b';').rev().collect();
+    assert_eq!(v, [(3, &b";"[..])]);
+}
+
+#[test]
+fn test_front_and_back() {
+    let mut iter = "abc345abc901abc".search_indices('c');
+    assert_eq!(iter.next(), Some((2, "c")));
+    assert_eq!(iter.next_back(), Some((14, "c")));
+    assert_eq!(iter.next_back(), Some((8, "c")));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+    //
+    let mut iter = "abc345abc901abc".rsearch_indices('c');
+    assert_eq!(iter.next(), Some((14, "c")));
+    assert_eq!(iter.next_back(), Some((2, "c")));
+    assert_eq!(iter.next(), Some((8, "c")));
+    assert_eq!(iter.next_back(), None);
+}
+
+#[test]
+fn test_self_overlapping() {
+    // the single char matches are the same from the front and the back.
+    let mut iter = "aaaaa".search_indices('a');
+    assert_eq!(iter.next(), Some((0, "a")));
+    assert_eq!(iter.next_back(), Some((4, "a")));
+    assert_eq!(iter.next(), Some((1, "a")));
+    assert_eq!(iter.next_back(), Some((3, "a")));
+    assert_eq!(iter.next(), Some((2, "a")));
+    assert_eq!(iter.next_back(), None);
+    assert_eq!(iter.next(), None);
+    //
+    let hay: &[u8] = b"aaaaa";
+    let mut iter = hay.search_indices_bytes(ByteSet(b"a"));
+    assert_eq!(iter.next_back(), Some((4, &b"a"[..])));
+    assert_eq!(iter.next(), Some((0, &b"a"[..])));
+    assert_eq!(iter.next_back(), Some((3, &b"a"[..])));
+    assert_eq!(iter.next_back(), Some((2, &b"a"[..])));
+    assert_eq!(iter.next(), Some((1, &b"a"[..])));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+    //
+    // the substring matches are different from the front and the back,
+    // so that the iterator of them is not double-ended.
+    let v: Vec<_> = "aaaaa".search_indices("aa").map(|(i, _)| i).collect();
+    assert_eq!(v, [0, 2]);
+    let v: Vec<_> = "aaaaa".rsearch_indices("aa").map(|(i, _)| i).collect();
+    assert_eq!(v, [3, 1]);
+}
+
+#[test]
+fn test_fused() {
+    let mut iter = "abc".search_indices('b');
+    assert_eq!(iter.next(), Some((1, "b")));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+    //
+    let mut iter = "abc".search_indices("");
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn test_char_boundary() {
+    let hay = "αβγαβγ";
+    let v: Vec<_> = hay.search_indices('β').rev().collect();
+    assert_eq!(v, [(8, "β"), (2, "β")]);
+    let mut iter = hay.search_indices('γ');
+    assert_eq!(iter.next_back(), Some((10, "γ")));
+    assert_eq!(iter.next(), Some((4, "γ")));
+    assert_eq!(iter.next(), None);
+}
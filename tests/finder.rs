@@ -0,0 +1,169 @@
+use naive_opt::{Finder, FinderRev, Search};
+
+#[test]
+fn test_finder_empty_needle() {
+    let finder = Finder::new("");
+    assert_eq!(finder.find(""), Some(0));
+    assert_eq!(finder.find("abc"), Some(0));
+    assert_eq!(finder.find_bytes(b"abc"), Some(0));
+    assert!(finder.includes("abc"));
+    assert_eq!(finder.find_iter("abc").next(), None);
+    //
+    let finder = FinderRev::new("");
+    assert_eq!(finder.rfind(""), Some(0));
+    assert_eq!(finder.rfind("abc"), Some(3));
+    assert_eq!(finder.rfind_bytes(b"abc"), Some(3));
+    assert!(finder.includes("abc"));
+    assert_eq!(finder.rfind_iter("abc").next(), None);
+}
+
+#[test]
+fn test_finder_same_as_search() {
+    let haystack = "111 a 111b 你好世界 hello world 你好世界";
+    for needle in [
+        "a",
+        "1",
+        "111b",
+        "b",
+        "xxx",
+        "好",
+        "世界 hello",
+        " ",
+        "d",
+        "界",
+    ] {
+        let finder = Finder::new(needle);
+        assert_eq!(finder.find(haystack), haystack.search(needle));
+        assert_eq!(
+            finder.find_bytes(haystack.as_bytes()),
+            haystack.search(needle)
+        );
+        assert_eq!(finder.includes(haystack), haystack.includes(needle));
+        let v1: Vec<_> = finder.find_iter(haystack).collect();
+        let v2: Vec<_> = haystack.search_indices(needle).collect();
+        assert_eq!(v1, v2);
+        //
+        let finder = FinderRev::new(needle);
+        assert_eq!(finder.rfind(haystack), haystack.rsearch(needle));
+        assert_eq!(
+            finder.rfind_bytes(haystack.as_bytes()),
+            haystack.rsearch(needle)
+        );
+        let v1: Vec<_> = finder.rfind_iter(haystack).collect();
+        let v2: Vec<_> = haystack.rsearch_indices(needle).collect();
+        assert_eq!(v1, v2);
+    }
+}
+
+#[test]
+fn test_finder_ignore_ascii_case() {
+    let haystack = "abc345aBc901ABC";
+    let finder = Finder::new_ignore_ascii_case("Abc");
+    assert_eq!(finder.find(haystack), Some(0));
+    let v: Vec<_> = finder.find_iter(haystack).collect();
+    assert_eq!(v, [(0, "abc"), (6, "aBc"), (12, "ABC")]);
+    let v: Vec<_> = finder.find_iter_bytes(haystack.as_bytes()).collect();
+    assert_eq!(v, [(0, &b"abc"[..]), (6, &b"aBc"[..]), (12, &b"ABC"[..])]);
+    //
+    let finder = FinderRev::new_ignore_ascii_case("Abc");
+    assert_eq!(finder.rfind(haystack), Some(12));
+    let v: Vec<_> = finder.rfind_iter(haystack).collect();
+    assert_eq!(v, [(12, "ABC"), (6, "aBc"), (0, "abc")]);
+    let v: Vec<_> = finder.rfind_iter_bytes(haystack.as_bytes()).collect();
+    assert_eq!(v, [(12, &b"ABC"[..]), (6, &b"aBc"[..]), (0, &b"abc"[..])]);
+    assert!(!finder.includes("ab"));
+}
+
+#[test]
+fn test_finder_shared() {
+    struct Holder {
+        finder: Finder<'static>,
+    }
+    let holder = Holder {
+        finder: Finder::new("needle"),
+    };
+    assert_eq!(holder.finder.needle(), b"needle");
+    std::thread::scope(|s| {
+        for _ in 0..2 {
+            s.spawn(|| {
+                assert_eq!(holder.finder.find("a needle in haystack"), Some(2));
+            });
+        }
+    });
+}
+
+#[test]
+fn test_finder_interior_pivot() {
+    let haystack = "eeee eeXe eeeeXee eeeeXee";
+    let finder = Finder::new("eeeeXee");
+    assert_eq!(finder.find(haystack), Some(10));
+    let v: Vec<_> = finder.find_iter(haystack).collect();
+    assert_eq!(v, [(10, "eeeeXee"), (18, "eeeeXee")]);
+    let finder = FinderRev::new("eeeeXee");
+    assert_eq!(finder.rfind(haystack), Some(18));
+    //
+    let finder = Finder::new_ignore_ascii_case("EEEExEE");
+    assert_eq!(finder.find(haystack), Some(10));
+    let finder = FinderRev::new_ignore_ascii_case("EEEExEE");
+    assert_eq!(finder.rfind(haystack), Some(18));
+    //
+    let haystack = "ここではここでにここではない";
+    let finder = Finder::new("ここでは");
+    assert_eq!(finder.find(haystack), Some(0));
+    let finder = FinderRev::new("ここでは");
+    assert_eq!(finder.rfind(haystack), Some(24));
+}
+
+#[test]
+fn test_finder_dual_pivot() {
+    let haystack = "zqa zqb zqc zQc zqC";
+    for needle in ["zqc", "zq", "qc", "q c", "zqC"] {
+        let finder = Finder::new(needle);
+        assert_eq!(finder.find(haystack), haystack.search(needle));
+        let finder = FinderRev::new(needle);
+        assert_eq!(finder.rfind(haystack), haystack.rsearch(needle));
+        //
+        let finder = Finder::new_ignore_ascii_case(needle);
+        assert_eq!(
+            finder.find(haystack),
+            haystack.search_ignore_ascii_case(needle)
+        );
+        let finder = FinderRev::new_ignore_ascii_case(needle);
+        assert_eq!(
+            finder.rfind(haystack),
+            haystack.rsearch_ignore_ascii_case(needle)
+        );
+    }
+    let finder = Finder::new_ignore_ascii_case("ZQC");
+    let v: Vec<_> = finder.find_iter(haystack).collect();
+    assert_eq!(v, [(8, "zqc"), (12, "zQc"), (16, "zqC")]);
+}
+
+#[test]
+fn test_finder_bytes_needle_not_char_boundary() {
+    // "é" is "\xc3\xa9", the bytes needle matches in the middle of the char.
+    let finder = Finder::new(&b"\xa9"[..]);
+    assert_eq!(finder.find_iter("café").next(), None);
+    assert_eq!(finder.find_bytes("café".as_bytes()), Some(4));
+    let finder = Finder::new(&b"\xa9x"[..]);
+    let v: Vec<_> = finder.find_iter("éx").collect();
+    assert!(v.is_empty());
+    let finder = Finder::new(&b"\xa9 c"[..]);
+    let v: Vec<_> = finder.find_iter("é c").collect();
+    assert!(v.is_empty());
+    let finder = Finder::new(&b"a\xc3"[..]);
+    let v: Vec<_> = finder.find_iter("aé aé").collect();
+    assert!(v.is_empty());
+    let finder = Finder::new(&b"a"[..]);
+    let v: Vec<_> = finder.find_iter("aé aé").collect();
+    assert_eq!(v, [(0, "a"), (4, "a")]);
+    //
+    let finder = FinderRev::new(&b"\xa9"[..]);
+    assert_eq!(finder.rfind_iter("café").next(), None);
+    let finder = FinderRev::new(&b"\xc3\xa9"[..]);
+    let v: Vec<_> = finder.rfind_iter("éaé").collect();
+    assert_eq!(v, [(3, "é"), (0, "é")]);
+    let finder = FinderRev::new(&b"a\xc3"[..]);
+    let v: Vec<_> = finder.rfind_iter("aé aé").collect();
+    assert!(v.is_empty());
+}
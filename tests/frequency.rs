@@ -0,0 +1,190 @@
+use naive_opt::{ByteFrequency, Search, SearchBytes};
+
+const FREQ_TABLE: [u8; 256] = {
+    let mut table = [128u8; 256];
+    table[b'x' as usize] = 1;
+    table
+};
+const FREQ: ByteFrequency = ByteFrequency::from_table(FREQ_TABLE);
+
+#[test]
+fn test_builtin() {
+    assert_eq!(ByteFrequency::default(), ByteFrequency::BUILTIN);
+    // the space is the most frequent in ascii
+    assert_eq!(ByteFrequency::BUILTIN.weight(b' '), 255);
+    assert!(ByteFrequency::BUILTIN.weight(b'z') < ByteFrequency::BUILTIN.weight(b'e'));
+}
+
+#[test]
+fn test_builtin_non_ascii() {
+    let freq = ByteFrequency::BUILTIN;
+    // the lead byte of hiragana and katakana is frequent in japanese
+    assert!(freq.weight(0xE3) > freq.weight(0xE6));
+    assert!(freq.weight(0x81) > freq.weight(0xBF));
+    // never in UTF-8
+    for byte in [0xC0, 0xC1, 0xF5, 0xFF] {
+        assert_eq!(freq.weight(byte), 0);
+    }
+    // all other non-ascii bytes may appear in UTF-8, and are 1 at least
+    for byte in (0x80..=0xBF).chain(0xC2..=0xF4) {
+        assert!(freq.weight(byte) > 0, "{:#x}", byte);
+    }
+}
+
+#[test]
+fn test_search_non_ascii() {
+    let hay = "吾輩は猫である。名前はまだ無い。どこで生れたかとんと見当がつかぬ。";
+    for nee in ["猫", "である", "名前は", "無い。", "見当がつかぬ。", "吾輩"] {
+        assert_eq!(hay.search(nee), hay.find(nee), "{:?}", nee);
+        assert_eq!(hay.rsearch(nee), hay.rfind(nee), "{:?}", nee);
+    }
+    let hay = "αβγ、Ελλάδα — Россия 🍣🍺";
+    for nee in ["β", "Ελλ", "ия", "🍺", "— Р"] {
+        assert_eq!(hay.search(nee), hay.find(nee), "{:?}", nee);
+        assert_eq!(hay.rsearch(nee), hay.rfind(nee), "{:?}", nee);
+    }
+}
+
+#[test]
+fn test_table() {
+    assert_eq!(FREQ.to_table(), FREQ_TABLE);
+    assert_eq!(FREQ.weight(b'x'), 1);
+    assert_eq!(FREQ.weight(b'a'), 128);
+    let freq = ByteFrequency::from_table(ByteFrequency::BUILTIN.to_table());
+    assert_eq!(freq, ByteFrequency::BUILTIN);
+}
+
+#[test]
+fn test_from_corpus() {
+    let freq = ByteFrequency::from_corpus(b"aaaabbc");
+    assert_eq!(freq.weight(b'a'), 255);
+    assert_eq!(freq.weight(b'b'), 128);
+    assert_eq!(freq.weight(b'c'), 64);
+    assert_eq!(freq.weight(b'd'), 0);
+    //
+    let freq = ByteFrequency::from_corpus(b"");
+    assert_eq!(freq.to_table(), [0u8; 256]);
+    //
+    let mut counts = [0u64; 256];
+    counts[0] = u64::MAX;
+    counts[1] = 1;
+    let freq = ByteFrequency::from_counts(&counts);
+    assert_eq!(freq.weight(0), 255);
+    assert_eq!(freq.weight(1), 1);
+    assert_eq!(freq.weight(2), 0);
+}
+
+#[test]
+fn test_search_with_frequency() {
+    let haystack = "abc345abcx01abc";
+    assert_eq!(haystack.search_with_frequency("abc", &FREQ), Some(0));
+    assert_eq!(haystack.search_with_frequency("cx", &FREQ), Some(8));
+    assert_eq!(haystack.search_with_frequency("xa", &FREQ), None);
+    assert_eq!(haystack.search_with_frequency("", &FREQ), Some(0));
+    assert_eq!(haystack.rsearch_with_frequency("abc", &FREQ), Some(12));
+    assert_eq!(haystack.rsearch_with_frequency("cx", &FREQ), Some(8));
+    assert_eq!(haystack.rsearch_with_frequency("", &FREQ), Some(15));
+    //
+    let freq = ByteFrequency::BUILTIN;
+    assert_eq!(haystack.search_with_frequency("bcx", &freq), Some(7));
+    assert_eq!("".search_with_frequency("a", &freq), None);
+}
+
+#[test]
+fn test_search_with_frequency_ignore_ascii_case() {
+    let haystack = "ABC345abcX01Abc";
+    assert_eq!(
+        haystack.search_with_frequency_ignore_ascii_case("aBc", &FREQ),
+        Some(0)
+    );
+    assert_eq!(
+        haystack.search_with_frequency_ignore_ascii_case("cx0", &FREQ),
+        Some(8)
+    );
+    assert_eq!(
+        haystack.search_with_frequency_ignore_ascii_case("xa", &FREQ),
+        None
+    );
+    assert_eq!(
+        haystack.rsearch_with_frequency_ignore_ascii_case("ABC", &FREQ),
+        Some(12)
+    );
+    assert_eq!(
+        haystack.rsearch_with_frequency_ignore_ascii_case("Cx", &FREQ),
+        Some(8)
+    );
+    assert_eq!(
+        haystack.rsearch_with_frequency_ignore_ascii_case("", &FREQ),
+        Some(15)
+    );
+    //
+    let haystack: &[u8] = b"abc\xff45ABCX01abc";
+    assert_eq!(
+        haystack.search_bytes_with_frequency_ignore_ascii_case("Cx", &FREQ),
+        Some(8)
+    );
+    assert_eq!(
+        haystack.rsearch_bytes_with_frequency_ignore_ascii_case(b"\xff4", &FREQ),
+        Some(3)
+    );
+    assert_eq!(
+        haystack.rsearch_bytes_with_frequency_ignore_ascii_case("abc", &FREQ),
+        Some(12)
+    );
+}
+
+#[test]
+fn test_pivots() {
+    // the built-in table picks 'z' and 'q', the custom table picks 'x' and the 1st byte.
+    let needle = b"quixotez";
+    assert_eq!(ByteFrequency::BUILTIN.pivots(needle), Some((7, Some(0))));
+    assert_eq!(FREQ.pivots(needle), Some((3, Some(0))));
+    let haystack = "a quixotez, the quixotez";
+    assert_eq!(haystack.search_with_frequency("quixotez", &FREQ), Some(2));
+    assert_eq!(haystack.rsearch_with_frequency("quixotez", &FREQ), Some(16));
+    //
+    // the short needle has the 1st byte or the last byte, and no guard.
+    assert_eq!(ByteFrequency::BUILTIN.pivots(b"xe"), Some((0, None)));
+    assert_eq!(FREQ.pivots(b"ex"), Some((1, None)));
+    assert_eq!(FREQ.pivots(b"x"), Some((0, None)));
+    assert_eq!(FREQ.pivots(b""), None);
+}
+
+#[test]
+fn test_search_bytes_with_frequency() {
+    let haystack: &[u8] = b"abc\xff45abcx01abc";
+    assert_eq!(haystack.search_bytes_with_frequency("cx", &FREQ), Some(8));
+    assert_eq!(
+        haystack.search_bytes_with_frequency(b"\xff4", &FREQ),
+        Some(3)
+    );
+    assert_eq!(
+        haystack.rsearch_bytes_with_frequency("abc", &FREQ),
+        Some(12)
+    );
+    assert_eq!("abcx".search_bytes_with_frequency("cx", &FREQ), Some(2));
+    assert_eq!("abcx".rsearch_bytes_with_frequency("ab", &FREQ), Some(0));
+}
+
+#[test]
+fn test_japanese() {
+    let corpus = "日本語のテキストのサンプルです。のののの".as_bytes();
+    let freq = ByteFrequency::from_corpus(corpus);
+    let haystack = "これは日本語のテキストです。";
+    assert_eq!(haystack.search_with_frequency("テキスト", &freq), Some(21));
+    assert_eq!(haystack.rsearch_with_frequency("の", &freq), Some(18));
+    assert_eq!(haystack.search_with_frequency("英語", &freq), None);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_string() {
+    let haystack = "abc345abcx01abc".to_string();
+    assert_eq!(haystack.search_with_frequency("cx", &FREQ), Some(8));
+    assert_eq!(haystack.rsearch_with_frequency("abc", &FREQ), Some(12));
+    assert_eq!(haystack.search_bytes_with_frequency("cx", &FREQ), Some(8));
+    assert_eq!(
+        haystack.rsearch_bytes_with_frequency("abc", &FREQ),
+        Some(12)
+    );
+}
@@ -0,0 +1,156 @@
+use naive_opt::Search;
+use naive_opt::{string_rsearch_ignore_case, string_search_ignore_case};
+use naive_opt::{string_rsearch_indices_ignore_case, string_search_indices_ignore_case};
+
+#[test]
+fn test_empty_needle() {
+    assert_eq!("".search_ignore_case(""), Some(0));
+    assert_eq!("abc".search_ignore_case(""), Some(0));
+    assert_eq!("abc".rsearch_ignore_case(""), Some(3));
+    assert_eq!("abc".search_indices_ignore_case("").next(), None);
+    assert_eq!("abc".rsearch_indices_ignore_case("").next(), None);
+}
+
+#[test]
+fn test_ascii() {
+    let haystack = "111 a 111b";
+    assert_eq!(haystack.search_ignore_case("A"), Some(4));
+    assert_eq!(haystack.search_ignore_case("111B"), Some(6));
+    assert_eq!(haystack.rsearch_ignore_case("1"), Some(8));
+    assert_eq!(haystack.search_ignore_case("xxx"), None);
+    assert!(haystack.includes_ignore_case("A 111"));
+    assert!(!haystack.includes_ignore_case("A  111"));
+}
+
+#[test]
+fn test_german() {
+    let haystack = "Die STRASSE und die Straße";
+    assert_eq!(haystack.search_ignore_case("straße"), Some(4));
+    assert_eq!(haystack.rsearch_ignore_case("STRASSE"), Some(20));
+    let v: Vec<_> = haystack.search_indices_ignore_case("strasse").collect();
+    assert_eq!(v, [(4, "STRASSE"), (20, "Straße")]);
+    let v: Vec<_> = haystack.rsearch_indices_ignore_case("strasse").collect();
+    assert_eq!(v, [(20, "Straße"), (4, "STRASSE")]);
+    //
+    assert_eq!(string_search_ignore_case("Äpfel", "äPFEL"), Some(0));
+    assert_eq!(string_rsearch_ignore_case("äpfel ÄPFEL", "Äpfel"), Some(7));
+    // a half of the folded character does not match.
+    assert_eq!("ß".search_ignore_case("s"), None);
+    assert_eq!("ss".search_ignore_case("ß"), Some(0));
+}
+
+#[test]
+fn test_greek_cyrillic() {
+    let haystack = "ΟΔΟΣ οδος Οδός";
+    let v: Vec<_> = string_search_indices_ignore_case(haystack, "οδος").collect();
+    assert_eq!(v, [(0, "ΟΔΟΣ"), (9, "οδος")]);
+    let v: Vec<_> = string_rsearch_indices_ignore_case(haystack, "ΟΔΟΣ").collect();
+    assert_eq!(v, [(9, "οδος"), (0, "ΟΔΟΣ")]);
+    //
+    let haystack = "Привет, МИР! мир";
+    assert_eq!(haystack.search_ignore_case("мир"), Some(14));
+    assert_eq!(haystack.rsearch_ignore_case("МИР"), Some(22));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_string() {
+    let s = "Привет, МИР! мир".to_string();
+    assert_eq!(s.search_ignore_case("привет"), Some(0));
+    assert_eq!(s.rsearch_ignore_case("МИР"), Some(22));
+}
+
+#[test]
+fn test_non_ascii_fold_to_ascii() {
+    // KELVIN SIGN is folded to 'k'
+    let haystack = "1\u{212A}m";
+    assert_eq!(haystack.search_ignore_case("km"), Some(1));
+    let v: Vec<_> = haystack.search_indices_ignore_case("KM").collect();
+    assert_eq!(v, [(1, "\u{212A}m")]);
+}
+
+#[test]
+fn test_all_chars_fold_to_ascii() {
+    // every char, that is folded to begin with an ascii char, is a candidate.
+    for c in (0..=0x10FFFF).filter_map(char::from_u32) {
+        let fold = |c: char| c.to_uppercase().flat_map(char::to_lowercase);
+        // 'ẞ' is lowercased to 'ß', that is folded again to "ss".
+        let folded: String = fold(c).flat_map(fold).collect();
+        if c.is_ascii() || !folded.starts_with(|f: char| f.is_ascii()) {
+            continue;
+        }
+        let haystack = format!("a{}-{}b", c, c);
+        let needle = folded.to_uppercase();
+        assert_eq!(
+            haystack.search_ignore_case(&needle),
+            Some(1),
+            "{:?}",
+            (c, &needle)
+        );
+        assert_eq!(
+            haystack.rsearch_ignore_case(&needle),
+            Some(2 + c.len_utf8()),
+            "{:?}",
+            (c, &needle)
+        );
+    }
+}
+
+#[test]
+fn test_ascii_first_scanning() {
+    let haystack = "Die Straße, die STRASSE und die strasse. ﬁne FINE";
+    let v: Vec<_> = haystack.search_indices_ignore_case("strasse").collect();
+    assert_eq!(v, [(4, "Straße"), (17, "STRASSE"), (33, "strasse")]);
+    let v: Vec<_> = haystack.rsearch_indices_ignore_case("fine").collect();
+    assert_eq!(v, [(48, "FINE"), (42, "ﬁne")]);
+    assert_eq!(haystack.search_ignore_case("E, DIE"), Some(10));
+    assert_eq!(haystack.search_ignore_case("xyz"), None);
+    assert_eq!(haystack.rsearch_ignore_case("xyz"), None);
+}
+
+#[test]
+fn test_sharp_s() {
+    // 'ß', 'ẞ' and "ss" are folded to the same "ss".
+    let haystack = "ß ẞ ss SS";
+    let v: Vec<_> = haystack.search_indices_ignore_case("ß").collect();
+    assert_eq!(v, [(0, "ß"), (3, "ẞ"), (7, "ss"), (10, "SS")]);
+    let v: Vec<_> = haystack.rsearch_indices_ignore_case("ẞ").collect();
+    assert_eq!(v, [(10, "SS"), (7, "ss"), (3, "ẞ"), (0, "ß")]);
+    let v: Vec<_> = haystack.search_indices_ignore_case("ss").collect();
+    assert_eq!(v, [(0, "ß"), (3, "ẞ"), (7, "ss"), (10, "SS")]);
+    assert_eq!("STRAẞE".search_ignore_case("strasse"), Some(0));
+    assert_eq!("strasse".search_ignore_case("STRAẞE"), Some(0));
+    assert_eq!("ẞ".search_ignore_case("s"), None);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_pathological() {
+    // each candidate fails at the end of the needle.
+    let mut haystack = "a".repeat(200_000);
+    let needle = format!("{}B", "A".repeat(2_000));
+    let tm = std::time::Instant::now();
+    assert_eq!(haystack.search_ignore_case(&needle), None);
+    assert_eq!(haystack.rsearch_ignore_case(&needle), None);
+    haystack.push('b');
+    let st = 200_000 - 2_000;
+    assert_eq!(haystack.search_ignore_case(&needle), Some(st));
+    assert_eq!(haystack.rsearch_ignore_case(&needle), Some(st));
+    let v: Vec<_> = haystack.search_indices_ignore_case(&needle).collect();
+    assert_eq!(v, [(st, &haystack[st..200_000 + 1])]);
+    //
+    // the non-ascii needle, and the folded chars that are longer than the original.
+    let haystack = "ﬀ".repeat(50_000);
+    let needle = format!("{}x", "F".repeat(2_000));
+    assert_eq!(haystack.search_ignore_case(&needle), None);
+    assert_eq!(haystack.rsearch_ignore_case(&needle), None);
+    let haystack = format!("{}ᾳ{}", "σ".repeat(50_000), "ς".repeat(3_000));
+    // 'ᾳ' is folded to "αι".
+    let needle = format!("{}ΑΙ", "Σ".repeat(1_000));
+    assert_eq!(haystack.search_ignore_case(&needle), Some(98_000));
+    assert_eq!(haystack.rsearch_ignore_case(&needle), Some(98_000));
+    let needle = format!("ΑΙ{}", "Σ".repeat(1_000));
+    assert_eq!(haystack.search_ignore_case(&needle), Some(100_000));
+    assert_eq!(haystack.rsearch_ignore_case(&needle), Some(100_000));
+    assert!(tm.elapsed().as_secs() < 5, "{:?}", tm.elapsed());
+}
@@ -0,0 +1,167 @@
+use naive_opt::{LineMatch, Search, SearchBytes};
+
+// the line of the match by rescanning the haystack.
+fn rescan<'a>(haystack: &'a str, st: usize) -> LineMatch<'a> {
+    let line_start = haystack[..st].rfind('\n').map(|i| i + 1).unwrap_or(0);
+    let line_end = haystack[st..]
+        .find('\n')
+        .map(|i| st + i)
+        .unwrap_or(haystack.len());
+    let line = &haystack[line_start..line_end];
+    LineMatch {
+        line_no: haystack[..st].matches('\n').count() + 1,
+        line_start,
+        column_byte: st - line_start,
+        column_char: haystack[line_start..st].chars().count(),
+        line: line.strip_suffix('\r').unwrap_or(line),
+    }
+}
+
+#[test]
+fn test_empty() {
+    assert_eq!("".search_lines("abc").next(), None);
+    assert_eq!("abc\nabc".search_lines("").next(), None);
+    assert_eq!("abc\nabc".search_lines("xyz").next(), None);
+}
+
+#[test]
+fn test_lines() {
+    let haystack = "abc\n\nxabcabc\r\n  abc";
+    let v: Vec<_> = haystack.search_lines("abc").collect();
+    assert_eq!(
+        v,
+        [
+            LineMatch {
+                line_no: 1,
+                line_start: 0,
+                column_byte: 0,
+                column_char: 0,
+                line: "abc",
+            },
+            LineMatch {
+                line_no: 3,
+                line_start: 5,
+                column_byte: 1,
+                column_char: 1,
+                line: "xabcabc",
+            },
+            LineMatch {
+                line_no: 3,
+                line_start: 5,
+                column_byte: 4,
+                column_char: 4,
+                line: "xabcabc",
+            },
+            LineMatch {
+                line_no: 4,
+                line_start: 14,
+                column_byte: 2,
+                column_char: 2,
+                line: "  abc",
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_column_char() {
+    let haystack = "αβγ abc αβγ abc\nα abc";
+    let v: Vec<_> = haystack
+        .search_lines("abc")
+        .map(|m| (m.line_no, m.column_byte, m.column_char))
+        .collect();
+    assert_eq!(v, [(1, 7, 4), (1, 18, 12), (2, 3, 2)]);
+}
+
+#[test]
+fn test_needle_with_newline() {
+    let haystack = "ab\ncd\nab\ncd";
+    let v: Vec<_> = haystack
+        .search_lines("\nc")
+        .map(|m| (m.line_no, m.column_byte, m.line))
+        .collect();
+    assert_eq!(v, [(1, 2, "ab"), (3, 2, "ab")]);
+    let v: Vec<_> = haystack
+        .search_lines("\n")
+        .map(|m| (m.line_no, m.column_byte))
+        .collect();
+    assert_eq!(v, [(1, 2), (2, 2), (3, 2)]);
+}
+
+#[test]
+fn test_ignore_ascii_case() {
+    let haystack = "Abc\nxaBC";
+    let v: Vec<_> = haystack
+        .search_lines_ignore_ascii_case("abc")
+        .map(|m| (m.line_no, m.column_byte))
+        .collect();
+    assert_eq!(v, [(1, 0), (2, 1)]);
+    let hay: &[u8] = b"Abc\nxaBC";
+    let v: Vec<_> = hay
+        .search_lines_bytes_ignore_ascii_case("abc")
+        .map(|m| (m.line_no, m.column_byte, m.line))
+        .collect();
+    assert_eq!(v, [(1, 0, &b"Abc"[..]), (2, 1, &b"xaBC"[..])]);
+}
+
+#[test]
+fn test_bytes() {
+    let hay: &[u8] = b"\xffabc\n\xce\xb1abc\r\n";
+    let v: Vec<_> = hay
+        .search_lines_bytes("abc")
+        .map(|m| {
+            (
+                m.line_no,
+                m.line_start,
+                m.column_byte,
+                m.column_char,
+                m.line,
+            )
+        })
+        .collect();
+    assert_eq!(
+        v,
+        [
+            (1, 0, 1, 1, &b"\xffabc"[..]),
+            (2, 5, 2, 1, &b"\xce\xb1abc"[..])
+        ]
+    );
+}
+
+#[test]
+fn test_like_rescan() {
+    let haystack = "fn main() {\n    let x = 1; // x\r\n\n    x += x;\n}\nx";
+    for nee in ["x", "x ", "\n", "\n\n", " x", "{", "}\nx", "main"] {
+        let v: Vec<_> = haystack.search_lines(nee).collect();
+        let w: Vec<_> = haystack
+            .search_indices(nee)
+            .map(|(st, _)| rescan(haystack, st))
+            .collect();
+        assert_eq!(v, w, "{:?}", nee);
+        let v: Vec<_> = haystack
+            .search_lines_bytes(nee)
+            .map(|m| {
+                (
+                    m.line_no,
+                    m.line_start,
+                    m.column_byte,
+                    m.column_char,
+                    m.line,
+                )
+            })
+            .collect();
+        let w: Vec<_> = w
+            .iter()
+            .map(|m| {
+                (
+                    m.line_no,
+                    m.line_start,
+                    m.column_byte,
+                    m.column_char,
+                    m.line.as_bytes(),
+                )
+            })
+            .collect();
+        assert_eq!(v, w, "{:?}", nee);
+    }
+}
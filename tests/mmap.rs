@@ -0,0 +1,193 @@
+#![cfg(feature = "mmap")]
+
+use naive_opt::{count_in_file, count_in_file_ignore_ascii_case, FileSearchError};
+use naive_opt::{count_in_file_buffered, count_in_file_buffered_ignore_ascii_case};
+use naive_opt::{search_file, search_file_ignore_ascii_case};
+use naive_opt::{search_file_buffered, search_file_buffered_ignore_ascii_case};
+use naive_opt::{search_file_indices, search_file_indices_ignore_ascii_case};
+use naive_opt::{search_file_indices_buffered, search_file_indices_buffered_ignore_ascii_case};
+use naive_opt::{SearchBytes, StreamSearcher};
+use std::path::PathBuf;
+
+// a file in the temporary directory, removed on drop.
+struct TempFile(PathBuf);
+impl TempFile {
+    fn new(name: &str, contents: &[u8]) -> TempFile {
+        let path =
+            std::env::temp_dir().join(format!("naive_opt_test_{}_{}", std::process::id(), name));
+        std::fs::write(&path, contents).unwrap();
+        TempFile(path)
+    }
+}
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+#[test]
+fn test_search_file() {
+    let file = TempFile::new("search", b"abc345aBc901abc\xff");
+    assert_eq!(search_file(&file.0, "abc").unwrap(), Some(0));
+    assert_eq!(search_file(&file.0, "901").unwrap(), Some(9));
+    assert_eq!(search_file(&file.0, &b"c\xff"[..]).unwrap(), Some(14));
+    assert_eq!(search_file(&file.0, "xyz").unwrap(), None);
+    assert_eq!(search_file(&file.0, "").unwrap(), None);
+    assert_eq!(search_file(&file.0, "ABC901").unwrap(), None);
+    assert_eq!(
+        search_file_ignore_ascii_case(&file.0, "ABC901").unwrap(),
+        Some(6)
+    );
+}
+
+#[test]
+fn test_indices_and_count() {
+    let file = TempFile::new("indices", b"abc345aBc901abc aaaa");
+    assert_eq!(search_file_indices(&file.0, "abc").unwrap(), [0, 12]);
+    assert_eq!(
+        search_file_indices_ignore_ascii_case(&file.0, "abc").unwrap(),
+        [0, 6, 12]
+    );
+    // not overlapping
+    assert_eq!(search_file_indices(&file.0, "aa").unwrap(), [16, 18]);
+    assert_eq!(count_in_file(&file.0, "aa").unwrap(), 2);
+    assert_eq!(count_in_file(&file.0, "abc").unwrap(), 2);
+    assert_eq!(count_in_file_ignore_ascii_case(&file.0, "ABC").unwrap(), 3);
+    assert_eq!(count_in_file(&file.0, "").unwrap(), 0);
+    assert!(search_file_indices(&file.0, "").unwrap().is_empty());
+}
+
+#[test]
+fn test_empty_file() {
+    let file = TempFile::new("empty", b"");
+    assert_eq!(search_file(&file.0, "abc").unwrap(), None);
+    assert!(search_file_indices(&file.0, "abc").unwrap().is_empty());
+    assert_eq!(count_in_file(&file.0, "abc").unwrap(), 0);
+}
+
+#[test]
+fn test_like_stream() {
+    let mut contents = Vec::new();
+    for i in 0..20_000 {
+        contents.extend_from_slice(format!("line {} NEEDLE needle\n", i).as_bytes());
+    }
+    let file = TempFile::new("large", &contents);
+    for needle in ["needle", "NEEDLE", "le", "\n", "9 N", "line 19999"] {
+        let v = search_file_indices(&file.0, needle).unwrap();
+        let w: Vec<u64> = StreamSearcher::new(&contents[..], needle)
+            .collect::<std::io::Result<_>>()
+            .unwrap();
+        assert_eq!(v, w, "{:?}", needle);
+        assert_eq!(count_in_file(&file.0, needle).unwrap(), w.len());
+        assert_eq!(
+            search_file(&file.0, needle).unwrap(),
+            contents.search_bytes(needle).map(|idx| idx as u64)
+        );
+        let v = search_file_indices_ignore_ascii_case(&file.0, needle).unwrap();
+        let w: Vec<u64> = StreamSearcher::new_ignore_ascii_case(&contents[..], needle)
+            .collect::<std::io::Result<_>>()
+            .unwrap();
+        assert_eq!(v, w, "{:?}", needle);
+    }
+}
+
+#[test]
+fn test_buffered() {
+    // the same matches as the mapped file, without the map.
+    let mut contents = Vec::new();
+    for i in 0..20_000 {
+        contents.extend_from_slice(format!("line {} NEEDLE needle\n", i).as_bytes());
+    }
+    let file = TempFile::new("buffered", &contents);
+    for needle in [
+        "needle",
+        "NEEDLE",
+        "le",
+        "\n",
+        "9 N",
+        "line 19999",
+        "xyz",
+        "",
+    ] {
+        assert_eq!(
+            search_file_buffered(&file.0, needle).unwrap(),
+            search_file(&file.0, needle).unwrap(),
+            "{:?}",
+            needle
+        );
+        assert_eq!(
+            search_file_buffered_ignore_ascii_case(&file.0, needle).unwrap(),
+            search_file_ignore_ascii_case(&file.0, needle).unwrap(),
+            "{:?}",
+            needle
+        );
+        assert_eq!(
+            search_file_indices_buffered(&file.0, needle).unwrap(),
+            search_file_indices(&file.0, needle).unwrap(),
+            "{:?}",
+            needle
+        );
+        assert_eq!(
+            search_file_indices_buffered_ignore_ascii_case(&file.0, needle).unwrap(),
+            search_file_indices_ignore_ascii_case(&file.0, needle).unwrap(),
+            "{:?}",
+            needle
+        );
+        assert_eq!(
+            count_in_file_buffered(&file.0, needle).unwrap(),
+            count_in_file(&file.0, needle).unwrap(),
+            "{:?}",
+            needle
+        );
+        assert_eq!(
+            count_in_file_buffered_ignore_ascii_case(&file.0, needle).unwrap(),
+            count_in_file_ignore_ascii_case(&file.0, needle).unwrap(),
+            "{:?}",
+            needle
+        );
+    }
+    let path = std::env::temp_dir().join("naive_opt_test_nonexistent/file");
+    let err = search_file_buffered(&path, "abc").unwrap_err();
+    assert!(matches!(err, FileSearchError::Open(_, _)));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_special_file() {
+    // the character device is read by the stream, instead of the map.
+    assert_eq!(search_file("/dev/null", "abc").unwrap(), None);
+    assert_eq!(count_in_file("/dev/null", "abc").unwrap(), 0);
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_zero_length_file() {
+    // the file in `/proc` reports zero length, but it has the contents.
+    assert_eq!(search_file("/proc/self/status", "Name:").unwrap(), Some(0));
+    assert_eq!(count_in_file("/proc/self/status", "Name:").unwrap(), 1);
+}
+
+#[test]
+fn test_error() {
+    let path = std::env::temp_dir().join("naive_opt_test_nonexistent/file");
+    let err = search_file(&path, "abc").unwrap_err();
+    assert!(matches!(err, FileSearchError::Open(_, _)));
+    assert_eq!(err.path(), path);
+    assert_eq!(err.io_error().kind(), std::io::ErrorKind::NotFound);
+    assert!(err.to_string().starts_with("failed to open "));
+    assert!(std::error::Error::source(&err).is_some());
+    let io_err: std::io::Error = err.into();
+    assert_eq!(io_err.kind(), std::io::ErrorKind::NotFound);
+    //
+    assert!(search_file_indices(&path, "abc").is_err());
+    assert!(count_in_file(&path, "abc").is_err());
+}
+
+#[cfg(unix)]
+#[test]
+fn test_read_error() {
+    // the directory is opened, but it fails to read.
+    let err = search_file(std::env::temp_dir(), "abc").unwrap_err();
+    assert!(matches!(err, FileSearchError::Read(_, _)));
+    assert!(err.to_string().starts_with("failed to read "));
+}
@@ -21,6 +21,7 @@ fn test_unicode_characters() {
 }
 
 // Test with longer strings
+#[cfg(feature = "alloc")]
 #[test]
 fn test_longer_strings() {
     let haystack = "a".repeat(1000) + "b" + &"a".repeat(1000);
@@ -1,7 +1,6 @@
-use naive_opt::{
-    includes_bytes, string_rsearch_bytes, string_search_bytes, Search, SearchBytes, SearchIn,
-    SearchInBytes,
-};
+use naive_opt::{includes_bytes, string_rsearch_bytes, string_search_bytes, Search, SearchBytes};
+#[cfg(feature = "alloc")]
+use naive_opt::{SearchIn, SearchInBytes};
 
 // Test needle at the very beginning or end of the haystack
 #[test]
@@ -138,6 +137,7 @@ fn test_longer_substring_needle() {
 }
 
 // Test SearchIn and SearchInBytes traits with different types of needles
+#[cfg(feature = "alloc")]
 #[test]
 fn test_search_in_with_string_and_char() {
     let haystack = "testing string and char needles";
@@ -184,6 +184,7 @@ fn test_search_in_with_string_and_char() {
     ));
 }
 
+#[cfg(feature = "alloc")]
 #[test]
 fn test_search_in_bytes_with_string_and_char() {
     let haystack: &[u8] = b"testing string and char needles";
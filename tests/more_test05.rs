@@ -1,7 +1,6 @@
-use naive_opt::{
-    includes_bytes, string_rsearch_bytes, string_search_bytes, Search, SearchBytes, SearchIn,
-    SearchInBytes,
-};
+use naive_opt::{includes_bytes, string_rsearch_bytes, string_search_bytes, Search, SearchBytes};
+#[cfg(feature = "alloc")]
+use naive_opt::{SearchIn, SearchInBytes};
 
 // Test Unicode characters with varying lengths and positions
 #[test]
@@ -127,6 +126,7 @@ fn test_mixed_ascii_unicode() {
 }
 
 // Test SearchIn and SearchInBytes traits with empty patterns
+#[cfg(feature = "alloc")]
 #[test]
 fn test_search_in_empty_patterns() {
     let haystack = "test";
@@ -0,0 +1,144 @@
+#![cfg(feature = "alloc")]
+
+use naive_opt::{MultiSearch, Search};
+
+#[test]
+fn test_multi_empty() {
+    let ms = MultiSearch::new(Vec::<&str>::new());
+    assert_eq!(ms.search("abc"), None);
+    assert_eq!(ms.rsearch("abc"), None);
+    assert!(!ms.includes("abc"));
+    //
+    let ms = MultiSearch::new(["ab", "", "c"]);
+    assert_eq!(ms.search("abc"), Some((0, 0, "ab")));
+    assert_eq!(ms.search("xbc"), Some((0, 1, "")));
+    assert_eq!(ms.rsearch("abc"), Some((3, 1, "")));
+    assert_eq!(ms.search_indices("abc").next(), None);
+}
+
+#[test]
+fn test_multi_search() {
+    let ms = MultiSearch::new(["cat", "dog", "bird"]);
+    let haystack = "a dog and a cat and a bird";
+    assert_eq!(ms.search(haystack), Some((2, 1, "dog")));
+    assert_eq!(ms.rsearch(haystack), Some((22, 2, "bird")));
+    assert_eq!(ms.search("no animals"), None);
+    assert_eq!(ms.rsearch("no animals"), None);
+    assert!(ms.includes(haystack));
+    assert!(ms.includes_bytes(haystack.as_bytes()));
+    assert_eq!(
+        ms.search_bytes(haystack.as_bytes()),
+        Some((2, 1, &b"dog"[..]))
+    );
+    assert_eq!(
+        ms.rsearch_bytes(haystack.as_bytes()),
+        Some((22, 2, &b"bird"[..]))
+    );
+    let v: Vec<_> = ms.search_indices_bytes(haystack.as_bytes()).collect();
+    assert_eq!(
+        v,
+        [
+            (2, 1, &b"dog"[..]),
+            (12, 0, &b"cat"[..]),
+            (22, 2, &b"bird"[..])
+        ]
+    );
+}
+
+#[test]
+fn test_multi_same_position() {
+    // the needle given first wins
+    let ms = MultiSearch::new(["abcd", "ab", "bc"]);
+    assert_eq!(ms.search("xabcdx"), Some((1, 0, "abcd")));
+    assert_eq!(ms.search("xabcx"), Some((1, 1, "ab")));
+    assert_eq!(ms.rsearch("xabcx"), Some((2, 2, "bc")));
+    let ms = MultiSearch::new(["ab", "abcd"]);
+    assert_eq!(ms.search("xabcdx"), Some((1, 0, "ab")));
+    assert_eq!(ms.rsearch("xabcdx"), Some((1, 0, "ab")));
+}
+
+#[test]
+fn test_multi_pivot_offset() {
+    // "e" is frequent, so the last bytes are picked as pivots.
+    let ms = MultiSearch::new(["eeeez", "ez"]);
+    let haystack = "eeeeeez";
+    assert_eq!(ms.search(haystack), Some((2, 0, "eeeez")));
+    assert_eq!(ms.rsearch(haystack), Some((5, 1, "ez")));
+    let v: Vec<_> = ms.search_indices(haystack).collect();
+    assert_eq!(v, [(2, 0, "eeeez")]);
+}
+
+#[test]
+fn test_multi_many_needles() {
+    let needles = [
+        "alpha", "bravo", "charlie", "delta", "echo", "foxtrot", "golf", "hotel", "india",
+        "juliett", "kilo", "lima", "mike", "日本", "語",
+    ];
+    let ms = MultiSearch::new(needles);
+    let haystack = "the hotel in lima has a kilo of 日本語 echo and golf";
+    let mut expect = Vec::new();
+    for (id, needle) in needles.iter().enumerate() {
+        for (idx, s) in haystack.search_indices(*needle) {
+            expect.push((idx, id, s));
+        }
+    }
+    expect.sort();
+    let v: Vec<_> = ms.search_indices(haystack).collect();
+    assert_eq!(v, expect);
+    assert_eq!(ms.rsearch(haystack), expect.last().copied());
+}
+
+#[test]
+fn test_multi_bytes_needles_not_char_boundary() {
+    // "é" is "\xc3\xa9", the bytes needles match in the middle of the char.
+    let n1: &[u8] = b"\xa9";
+    let n2: &[u8] = b"\xc3";
+    let ms = MultiSearch::new([n1, n2]);
+    assert_eq!(ms.search("café"), None);
+    assert_eq!(ms.rsearch("café"), None);
+    assert!(!ms.includes("café"));
+    assert_eq!(ms.search_indices("café").next(), None);
+    assert_eq!(
+        ms.search_bytes("café".as_bytes()),
+        Some((3, 1, &b"\xc3"[..]))
+    );
+    //
+    let n3: &[u8] = b"caf";
+    let n4: &[u8] = b"\xa9 x";
+    let ms = MultiSearch::new([n4, n3, n2]);
+    let v: Vec<_> = ms.search_indices("café x café").collect();
+    assert_eq!(v, [(0, 1, "caf"), (8, 1, "caf")]);
+    assert_eq!(ms.rsearch("café x café"), Some((8, 1, "caf")));
+    //
+    let n5: &[u8] = b"a\xc3";
+    let ms = MultiSearch::new([n5, b"", b"x"]);
+    assert_eq!(ms.search("aé"), Some((0, 1, "")));
+}
+
+#[test]
+fn test_multi_more_picks_than_memchr() {
+    // many pick bytes, that are scanned by the groups.
+    let needles = [
+        "Zulu", "Quebec", "Xray", "Yankee", "Victor", "Whiskey", "Kilo", "Juliett", "Oscar",
+        "Papa", "Romeo", "Sierra", "Tango", "Uniform", "zz", "qq", "jj", "xx", "vv", "kk",
+    ];
+    let ms = MultiSearch::new(needles);
+    let mut haystack = String::new();
+    for i in 0..200 {
+        haystack.push_str(needles[i * 7 % needles.len()]);
+        haystack.push_str(" and the qz, jx or v. ");
+    }
+    let mut expect = Vec::new();
+    for (id, needle) in needles.iter().enumerate() {
+        for (idx, s) in haystack.search_indices(*needle) {
+            expect.push((idx, id, s));
+        }
+    }
+    expect.sort();
+    let v: Vec<_> = ms.search_indices(&haystack).collect();
+    assert_eq!(v, expect);
+    assert_eq!(ms.search(&haystack), expect.first().copied());
+    assert_eq!(ms.rsearch(&haystack), expect.last().copied());
+    assert_eq!(ms.search("no needle in it"), None);
+    assert_eq!(ms.rsearch("no needle in it"), None);
+}
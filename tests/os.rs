@@ -0,0 +1,92 @@
+#![cfg(all(feature = "std", unix))]
+
+use naive_opt::SearchOsStr;
+use std::ffi::{OsStr, OsString};
+use std::os::unix::ffi::{OsStrExt, OsStringExt};
+use std::path::{Path, PathBuf};
+
+#[test]
+fn test_os_str() {
+    let haystack = OsStr::new("PATH=/usr/bin:/bin:/usr/local/bin");
+    assert_eq!(haystack.search_os(":"), Some(13));
+    assert_eq!(haystack.rsearch_os(":"), Some(18));
+    assert_eq!(haystack.search_os("/sbin"), None);
+    assert!(haystack.includes_os(OsStr::new("/local")));
+    assert!(!haystack.includes_os("/sbin"));
+    let v: Vec<_> = haystack.search_indices_os("bin").collect();
+    assert_eq!(
+        v,
+        [
+            (10, OsStr::new("bin")),
+            (15, OsStr::new("bin")),
+            (30, OsStr::new("bin"))
+        ]
+    );
+    let v: Vec<_> = haystack.rsearch_indices_os("bin").map(|(i, _)| i).collect();
+    assert_eq!(v, [30, 15, 10]);
+    let v: Vec<_> = haystack
+        .search_indices_os('/')
+        .rev()
+        .map(|(i, _)| i)
+        .collect();
+    assert_eq!(v, [29, 23, 19, 14, 9, 5]);
+    assert_eq!(haystack.search_os(""), Some(0));
+    assert_eq!(haystack.search_indices_os("").next(), None);
+}
+
+#[test]
+fn test_non_utf8() {
+    // the bytes that are not UTF-8 are searched without the lossy conversion.
+    let haystack = OsString::from_vec(b"caf\xe9/na\xefve/caf\xe9.txt".to_vec());
+    assert!(haystack.to_str().is_none());
+    let needle = OsStr::from_bytes(b"caf\xe9");
+    assert_eq!(haystack.search_os(needle), Some(0));
+    assert_eq!(haystack.rsearch_os(needle), Some(11));
+    assert_eq!(haystack.search_os(&b"\xef"[..]), Some(7));
+    let v: Vec<_> = haystack.search_indices_os(needle).collect();
+    assert_eq!(v, [(0, needle), (11, needle)]);
+    let owned = needle.to_os_string();
+    assert_eq!(haystack.rsearch_os(&owned), Some(11));
+    assert_eq!(haystack.search_os_ignore_ascii_case("CAF\u{e9}"), None);
+    assert_eq!(
+        haystack.search_os_ignore_ascii_case(&b"CAF\xe9"[..]),
+        Some(0)
+    );
+}
+
+#[test]
+fn test_path() {
+    let path = Path::new("/home/user/Projects/naive_opt/src/lib.rs");
+    assert_eq!(path.search_os("/src/"), Some(29));
+    assert_eq!(path.rsearch_os('/'), Some(33));
+    assert!(path.includes_os(Path::new("naive_opt")));
+    let path_buf: PathBuf = path.to_path_buf();
+    assert_eq!(path_buf.search_os(&path_buf), Some(0));
+    assert_eq!(path_buf.search_os(path), Some(0));
+    let v: Vec<_> = path_buf.search_indices_os('/').map(|(i, _)| i).collect();
+    assert_eq!(v, [0, 5, 10, 19, 29, 33]);
+}
+
+#[test]
+fn test_ignore_ascii_case() {
+    let path = Path::new("/tmp/Makefile.MK");
+    assert_eq!(path.search_os("mk"), None);
+    assert_eq!(path.search_os_ignore_ascii_case("mk"), Some(14));
+    assert_eq!(path.rsearch_os_ignore_ascii_case("M"), Some(14));
+    assert!(path.includes_os_ignore_ascii_case("MAKEFILE"));
+    assert!(!path.includes_os_ignore_ascii_case("make_file"));
+    let v: Vec<_> = path.search_indices_os_ignore_ascii_case("m").collect();
+    assert_eq!(
+        v,
+        [
+            (2, OsStr::new("m")),
+            (5, OsStr::new("M")),
+            (14, OsStr::new("M"))
+        ]
+    );
+    let v: Vec<_> = path
+        .rsearch_indices_os_ignore_ascii_case("m")
+        .map(|(i, _)| i)
+        .collect();
+    assert_eq!(v, [14, 5, 2]);
+}
@@ -0,0 +1,100 @@
+use naive_opt::{string_rsearch_indices_overlapping, string_search_indices_overlapping};
+use naive_opt::{
+    string_rsearch_indices_overlapping_bytes, string_search_indices_overlapping_bytes,
+};
+use naive_opt::{
+    string_rsearch_indices_overlapping_ignore_ascii_case,
+    string_search_indices_overlapping_ignore_ascii_case,
+};
+use naive_opt::{Search, SearchBytes};
+
+#[test]
+fn test_empty_needle() {
+    assert_eq!("abc".search_indices_overlapping("").next(), None);
+    assert_eq!("abc".rsearch_indices_overlapping("").next(), None);
+    let hay: &[u8] = b"abc";
+    assert_eq!(hay.search_indices_overlapping_bytes("").next(), None);
+    assert_eq!(hay.rsearch_indices_overlapping_bytes("").next(), None);
+}
+
+#[test]
+fn test_overlapping() {
+    let v: Vec<_> = "ababa".search_indices_overlapping("aba").collect();
+    assert_eq!(v, [(0, "aba"), (2, "aba")]);
+    let v: Vec<_> = "ababa".rsearch_indices_overlapping("aba").collect();
+    assert_eq!(v, [(2, "aba"), (0, "aba")]);
+    //
+    let v: Vec<_> = string_search_indices_overlapping("aaaa", "aa").collect();
+    assert_eq!(v, [(0, "aa"), (1, "aa"), (2, "aa")]);
+    let v: Vec<_> = string_rsearch_indices_overlapping("aaaa", "aa").collect();
+    assert_eq!(v, [(2, "aa"), (1, "aa"), (0, "aa")]);
+    //
+    let v: Vec<_> = "abc".search_indices_overlapping('b').collect();
+    assert_eq!(v, [(1, "b")]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_overlapping_string() {
+    let haystack = "GATATATC".to_string();
+    let v: Vec<_> = haystack.search_indices_overlapping("ATA").collect();
+    assert_eq!(v, [(1, "ATA"), (3, "ATA")]);
+    let v: Vec<_> = haystack.rsearch_indices_overlapping("ATA").collect();
+    assert_eq!(v, [(3, "ATA"), (1, "ATA")]);
+}
+
+#[test]
+fn test_overlapping_bytes() {
+    let hay: &[u8] = b"ababa";
+    let v: Vec<_> = hay.search_indices_overlapping_bytes("aba").collect();
+    assert_eq!(v, [(0, &b"aba"[..]), (2, &b"aba"[..])]);
+    let v: Vec<_> = hay.rsearch_indices_overlapping_bytes("aba").collect();
+    assert_eq!(v, [(2, &b"aba"[..]), (0, &b"aba"[..])]);
+    //
+    let v: Vec<_> = string_search_indices_overlapping_bytes(b"aaa", &b"aa"[..]).collect();
+    assert_eq!(v, [(0, &b"aa"[..]), (1, &b"aa"[..])]);
+    let v: Vec<_> = string_rsearch_indices_overlapping_bytes(b"aaa", &b"aa"[..]).collect();
+    assert_eq!(v, [(1, &b"aa"[..]), (0, &b"aa"[..])]);
+}
+
+#[test]
+fn test_overlapping_unicode() {
+    let haystack = "ああああ";
+    let v: Vec<_> = haystack.search_indices_overlapping("ああ").collect();
+    assert_eq!(v, [(0, "ああ"), (3, "ああ"), (6, "ああ")]);
+    let v: Vec<_> = haystack.rsearch_indices_overlapping("ああ").collect();
+    assert_eq!(v, [(6, "ああ"), (3, "ああ"), (0, "ああ")]);
+    //
+    let haystack = "aéaéa";
+    let v: Vec<_> = haystack.search_indices_overlapping("aéa").collect();
+    assert_eq!(v, [(0, "aéa"), (3, "aéa")]);
+    let v: Vec<_> = haystack.rsearch_indices_overlapping("aéa").collect();
+    assert_eq!(v, [(3, "aéa"), (0, "aéa")]);
+}
+
+#[test]
+fn test_overlapping_ignore_ascii_case() {
+    let v: Vec<_> = "aBAba"
+        .search_indices_overlapping_ignore_ascii_case("aba")
+        .collect();
+    assert_eq!(v, [(0, "aBA"), (2, "Aba")]);
+    let v: Vec<_> = "aBAba"
+        .rsearch_indices_overlapping_ignore_ascii_case("aba")
+        .collect();
+    assert_eq!(v, [(2, "Aba"), (0, "aBA")]);
+    //
+    let v: Vec<_> = string_search_indices_overlapping_ignore_ascii_case("AaAa", "aa").collect();
+    assert_eq!(v, [(0, "Aa"), (1, "aA"), (2, "Aa")]);
+    let v: Vec<_> = string_rsearch_indices_overlapping_ignore_ascii_case("AaAa", "aa").collect();
+    assert_eq!(v, [(2, "Aa"), (1, "aA"), (0, "Aa")]);
+    //
+    let hay: &[u8] = b"aBAba";
+    let v: Vec<_> = hay
+        .search_indices_overlapping_bytes_ignore_ascii_case("aba")
+        .collect();
+    assert_eq!(v, [(0, &b"aBA"[..]), (2, &b"Aba"[..])]);
+    let v: Vec<_> = hay
+        .rsearch_indices_overlapping_bytes_ignore_ascii_case("aba")
+        .collect();
+    assert_eq!(v, [(2, &b"Aba"[..]), (0, &b"aBA"[..])]);
+}
@@ -0,0 +1,161 @@
+#![cfg(feature = "parallel")]
+
+use naive_opt::{par_includes, par_search, par_search_count, par_search_indices};
+use naive_opt::{par_search_count_ignore_ascii_case, par_search_ignore_ascii_case};
+use naive_opt::{par_search_indices_ignore_ascii_case, ParallelSearcher, SearchBytes};
+
+// compare the parallel search with the sequential search, over many chunkings.
+fn check_like_sequential(haystack: &[u8], needle: &[u8]) {
+    let indices: Vec<usize> = haystack
+        .search_indices_bytes(needle)
+        .map(|(idx, _)| idx)
+        .collect();
+    let indices_iac: Vec<usize> = haystack
+        .search_indices_bytes_ignore_ascii_case(needle)
+        .map(|(idx, _)| idx)
+        .collect();
+    for threads in 1..=8 {
+        for min_chunk_len in [1, 2, 3, 5, 8, 64] {
+            let searcher = ParallelSearcher::new(needle)
+                .with_threads(threads)
+                .with_min_chunk_len(min_chunk_len);
+            let msg = (threads, min_chunk_len, String::from_utf8_lossy(needle));
+            assert_eq!(
+                searcher.search(haystack),
+                haystack.search_bytes(needle),
+                "{:?}",
+                msg
+            );
+            assert_eq!(searcher.search_indices(haystack), indices, "{:?}", msg);
+            assert_eq!(searcher.search_count(haystack), indices.len(), "{:?}", msg);
+            let searcher = ParallelSearcher::new_ignore_ascii_case(needle)
+                .with_threads(threads)
+                .with_min_chunk_len(min_chunk_len);
+            assert_eq!(
+                searcher.search(haystack),
+                haystack.search_bytes_ignore_ascii_case(needle),
+                "{:?}",
+                msg
+            );
+            assert_eq!(searcher.search_indices(haystack), indices_iac, "{:?}", msg);
+            assert_eq!(
+                searcher.search_count(haystack),
+                indices_iac.len(),
+                "{:?}",
+                msg
+            );
+        }
+    }
+}
+
+#[test]
+fn test_empty() {
+    assert_eq!(par_search("", "abc"), None);
+    assert_eq!(par_search("abc", ""), Some(0));
+    assert!(par_includes("abc", ""));
+    assert_eq!(par_search_count("abc", ""), 0);
+    assert!(par_search_indices("abc", "").is_empty());
+    assert!(par_search_indices("", "abc").is_empty());
+    assert_eq!(par_search("ab", "abc"), None);
+}
+
+#[test]
+fn test_functions() {
+    let haystack = "abc345aBc901abc".repeat(100);
+    assert_eq!(par_search(&haystack, "901"), Some(9));
+    assert!(par_includes(&haystack, "c901a"));
+    assert!(!par_includes(&haystack, "xyz"));
+    assert_eq!(par_search_count(&haystack, "abc"), 200);
+    assert_eq!(par_search_indices(&haystack, "abc")[..3], [0, 12, 15]);
+    assert_eq!(par_search_ignore_ascii_case(&haystack, "BC9"), Some(7));
+    assert_eq!(par_search_count_ignore_ascii_case(&haystack, "ABC"), 300);
+    assert_eq!(
+        par_search_indices_ignore_ascii_case(&haystack, "ABC")[..3],
+        [0, 6, 12]
+    );
+    let searcher = ParallelSearcher::new("abc");
+    assert_eq!(searcher.needle(), b"abc");
+}
+
+#[test]
+fn test_overlapping_boundary() {
+    // the matches of a chunk overlap the last match of the previous chunk.
+    let haystack = b"aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+    for needle in ["a", "aa", "aaa", "aaaaaaa"] {
+        check_like_sequential(haystack, needle.as_bytes());
+    }
+    let haystack = b"abababababaababababbabababaabababababab";
+    for needle in ["ab", "aba", "abab", "ba", "bab", "aab", "bb"] {
+        check_like_sequential(haystack, needle.as_bytes());
+    }
+}
+
+#[test]
+fn test_like_sequential() {
+    let mut haystack = Vec::new();
+    for i in 0..200 {
+        haystack.extend_from_slice(format!("Line {} needle NEEDLE nee\n", i * 7 % 13).as_bytes());
+    }
+    for needle in ["needle", "NEEDLE", "e", "ee", "le n", "\nLine 1", "zzz"] {
+        check_like_sequential(&haystack, needle.as_bytes());
+    }
+}
+
+#[test]
+fn test_many_head_matches() {
+    // more matches than kept at the head of a chunk, before it meets.
+    let haystack = "a".repeat(5000);
+    for threads in [2, 3, 7] {
+        let searcher = ParallelSearcher::new("aa")
+            .with_threads(threads)
+            .with_min_chunk_len(1);
+        assert_eq!(searcher.search_count(&haystack), 2500);
+        let v = searcher.search_indices(&haystack);
+        assert_eq!(v.len(), 2500);
+        assert!(v.iter().enumerate().all(|(i, &idx)| idx == i * 2));
+    }
+}
+
+#[test]
+fn test_periodic_rescan() {
+    // the matches of the chunks at the odd boundaries never meet the previous
+    // chunk, and the chunks are scanned again from the end of the previous one.
+    let haystack = "a".repeat(100_003);
+    for needle in ["aa", "aaa", "aaaaaaa"] {
+        let v: Vec<usize> = haystack
+            .search_indices_bytes(needle)
+            .map(|(idx, _)| idx)
+            .collect();
+        for threads in 2..=8 {
+            let searcher = ParallelSearcher::new(needle)
+                .with_threads(threads)
+                .with_min_chunk_len(1);
+            assert_eq!(searcher.search_count(&haystack), v.len(), "{}", threads);
+            assert_eq!(searcher.search_indices(&haystack), v, "{}", threads);
+        }
+    }
+    // the periodic run in the middle of the chunks.
+    let haystack = "xyz".repeat(300) + &"ab".repeat(2_001) + "a" + &"xyz".repeat(300);
+    for needle in ["abab", "aba", "ba"] {
+        check_like_sequential(haystack.as_bytes(), needle.as_bytes());
+    }
+}
+
+#[test]
+fn test_search_stops_early() {
+    // each candidate fails at the end of the needle, so that a chunk is slow to scan.
+    let mut haystack = "ab".repeat(8 * 1024 * 1024).into_bytes();
+    let needle = b"ababababb";
+    let searcher = ParallelSearcher::new(needle)
+        .with_threads(4)
+        .with_min_chunk_len(1);
+    let tm = std::time::Instant::now();
+    assert_eq!(searcher.search(&haystack), None);
+    let full = tm.elapsed();
+    // the match in the 1st chunk stops the later chunks.
+    haystack[100..109].copy_from_slice(needle);
+    let tm = std::time::Instant::now();
+    assert_eq!(searcher.search(&haystack), Some(100));
+    let first = tm.elapsed();
+    assert!(first * 4 < full, "{:?} {:?}", first, full);
+}
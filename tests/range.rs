@@ -0,0 +1,89 @@
+use naive_opt::{Search, SearchBytes};
+
+#[test]
+fn test_search_from() {
+    let haystack = "abc345abc901abc";
+    assert_eq!(haystack.search_from("abc", 0), Some(0));
+    assert_eq!(haystack.search_from("abc", 1), Some(6));
+    assert_eq!(haystack.search_from("abc", 6), Some(6));
+    assert_eq!(haystack.search_from("abc", 7), Some(12));
+    assert_eq!(haystack.search_from("abc", 13), None);
+    assert_eq!(haystack.search_from("abc", 15), None);
+    assert_eq!(haystack.search_from("abc", 16), None);
+    assert_eq!(haystack.search_from('9', 3), Some(9));
+    //
+    assert_eq!(haystack.search_from("", 4), Some(4));
+    assert_eq!(haystack.search_from("", 15), Some(15));
+    assert_eq!(haystack.search_from("", 16), None);
+}
+
+#[test]
+fn test_rsearch_before() {
+    let haystack = "abc345abc901abc";
+    assert_eq!(haystack.rsearch_before("abc", 15), Some(12));
+    assert_eq!(haystack.rsearch_before("abc", 14), Some(6));
+    assert_eq!(haystack.rsearch_before("abc", 9), Some(6));
+    assert_eq!(haystack.rsearch_before("abc", 8), Some(0));
+    assert_eq!(haystack.rsearch_before("abc", 2), None);
+    assert_eq!(haystack.rsearch_before("abc", 0), None);
+    assert_eq!(haystack.rsearch_before("abc", 16), None);
+}
+
+#[test]
+fn test_search_in_range() {
+    let haystack = "abc345abc901abc";
+    assert_eq!(haystack.search_in_range("abc", ..), Some(0));
+    assert_eq!(haystack.search_in_range("abc", 1..12), Some(6));
+    assert_eq!(haystack.search_in_range("abc", 1..8), None);
+    assert_eq!(haystack.search_in_range("abc", 1..=8), Some(6));
+    assert_eq!(haystack.search_in_range("abc", 7..), Some(12));
+    assert_eq!(haystack.search_in_range("abc", ..=2), Some(0));
+    assert_eq!(haystack.search_in_range("abc", ..2), None);
+    //
+    assert_eq!(haystack.search_in_range("abc", 7..16), None);
+    assert_eq!(haystack.search_in_range("abc", 7..=15), None);
+    #[allow(clippy::reversed_empty_ranges)]
+    let r = haystack.search_in_range("abc", 8..7);
+    assert_eq!(r, None);
+    assert_eq!(haystack.search_in_range("", 7..7), Some(7));
+}
+
+#[test]
+fn test_char_boundary() {
+    let haystack = "αβγαβγ";
+    assert_eq!(haystack.search_from("γ", 0), Some(4));
+    assert_eq!(haystack.search_from("γ", 5), None);
+    assert_eq!(haystack.search_from("γ", 6), Some(10));
+    assert_eq!(haystack.rsearch_before("α", 7), None);
+    assert_eq!(haystack.rsearch_before("α", 8), Some(6));
+    assert_eq!(haystack.search_in_range("β", 1..12), None);
+    assert_eq!(haystack.search_in_range("β", 2..11), None);
+    assert_eq!(haystack.search_in_range("β", 4..12), Some(8));
+}
+
+#[test]
+fn test_bytes() {
+    let hay: &[u8] = b"abc\xff45abc901abc";
+    assert_eq!(hay.search_from_bytes("abc", 1), Some(6));
+    assert_eq!(hay.search_from_bytes("abc", 16), None);
+    assert_eq!(hay.rsearch_before_bytes("abc", 14), Some(6));
+    assert_eq!(hay.rsearch_before_bytes("abc", 16), None);
+    assert_eq!(hay.search_in_range_bytes("abc", 1..12), Some(6));
+    assert_eq!(hay.search_in_range_bytes("abc", 1..8), None);
+    assert_eq!(hay.search_in_range_bytes("abc", 7..99), None);
+    // no char boundary check with the bytes
+    let hay = "αβγαβγ";
+    assert_eq!(hay.search_from_bytes(&b"\xb3"[..], 5), Some(5));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_string() {
+    let haystack = "abc345abc901abc".to_string();
+    assert_eq!(haystack.search_from("abc", 1), Some(6));
+    assert_eq!(haystack.rsearch_before("abc", 14), Some(6));
+    assert_eq!(haystack.search_in_range("abc", 7..), Some(12));
+    assert_eq!(haystack.search_from_bytes("abc", 1), Some(6));
+    assert_eq!(haystack.rsearch_before_bytes("abc", 14), Some(6));
+    assert_eq!(haystack.search_in_range_bytes("abc", 7..), Some(12));
+}
@@ -0,0 +1,143 @@
+#![cfg(feature = "alloc")]
+
+use naive_opt::{Search, SearchBytes};
+use std::borrow::Cow;
+
+#[test]
+fn test_no_match() {
+    let r = "abc".search_replace("xyz", "X");
+    assert!(matches!(r, Cow::Borrowed("abc")));
+    let r = "abc".search_replace("", "X");
+    assert!(matches!(r, Cow::Borrowed("abc")));
+    let r = "abc".search_replacen("abc", "X", 0);
+    assert!(matches!(r, Cow::Borrowed("abc")));
+    let r = "abc".search_replace_ignore_ascii_case("xyz", "X");
+    assert!(matches!(r, Cow::Borrowed("abc")));
+    let hay: &[u8] = b"abc";
+    let r = hay.search_replace_bytes("xyz", b"X");
+    assert!(matches!(r, Cow::Borrowed(b"abc")));
+}
+
+#[test]
+fn test_replace() {
+    assert_eq!("abc345abc901".search_replace("abc", "X"), "X345X901");
+    assert_eq!("abc345abc901".search_replace("abc", ""), "345901");
+    assert_eq!("abcabc".search_replace("abc", "abcabc"), "abcabcabcabc");
+    assert_eq!("aaaa".search_replace("aa", "b"), "bb");
+    assert_eq!("αβγδβγε".search_replace("βγ", "-"), "α-δ-ε");
+    assert_eq!("a,b,c".search_replace(',', ";"), "a;b;c");
+    //
+    let haystack = "Hello {name}!".to_string();
+    assert_eq!(haystack.search_replace("{name}", "World"), "Hello World!");
+}
+
+#[test]
+fn test_replacen() {
+    assert_eq!(
+        "abc345abc901abc".search_replacen("abc", "X", 1),
+        "X345abc901abc"
+    );
+    assert_eq!(
+        "abc345abc901abc".search_replacen("abc", "X", 2),
+        "X345X901abc"
+    );
+    assert_eq!(
+        "abc345abc901abc".search_replacen("abc", "X", 9),
+        "X345X901X"
+    );
+}
+
+#[test]
+fn test_replace_into() {
+    let mut buf = String::from("> ");
+    "abc345abc901".search_replace_into("abc", "X", &mut buf);
+    assert_eq!(buf, "> X345X901");
+    "xyz".search_replace_into("abc", "X", &mut buf);
+    assert_eq!(buf, "> X345X901xyz");
+    //
+    let mut buf = String::new();
+    "ABC345abc901".search_replace_into_ignore_ascii_case("abc", "X", &mut buf);
+    assert_eq!(buf, "X345X901");
+}
+
+#[test]
+fn test_replace_ignore_ascii_case() {
+    assert_eq!(
+        "ABC345aBc901".search_replace_ignore_ascii_case("abc", "X"),
+        "X345X901"
+    );
+    assert_eq!(
+        "ABC345aBc901".search_replacen_ignore_ascii_case("abc", "X", 1),
+        "X345aBc901"
+    );
+}
+
+#[test]
+fn test_replace_bytes() {
+    let hay: &[u8] = b"abc\xff345abc901";
+    assert_eq!(hay.search_replace_bytes("abc", b"X"), &b"X\xff345X901"[..]);
+    assert_eq!(
+        hay.search_replacen_bytes("abc", b"X", 1),
+        &b"X\xff345abc901"[..]
+    );
+    let mut buf = b"> ".to_vec();
+    hay.search_replace_into_bytes("abc", b"X", &mut buf);
+    assert_eq!(buf, b"> X\xff345X901");
+    //
+    let hay: &[u8] = b"ABC\xff345aBc901";
+    assert_eq!(
+        hay.search_replace_bytes_ignore_ascii_case("abc", b"X"),
+        &b"X\xff345X901"[..]
+    );
+    assert_eq!(
+        hay.search_replacen_bytes_ignore_ascii_case("abc", b"X", 1),
+        &b"X\xff345aBc901"[..]
+    );
+    let mut buf = Vec::new();
+    hay.search_replace_into_bytes_ignore_ascii_case("abc", b"X", &mut buf);
+    assert_eq!(buf, b"X\xff345X901");
+    //
+    let haystack = "abc345abc901".to_string();
+    assert_eq!(haystack.search_replace_bytes("abc", b"X"), &b"X345X901"[..]);
+    assert_eq!(
+        "abc345abc901".search_replace_bytes("abc", b"X"),
+        &b"X345X901"[..]
+    );
+}
+
+#[test]
+fn test_replace_like_std() {
+    let cases = [
+        ("", "ab"),
+        ("ab", "ab"),
+        ("abab", "ab"),
+        ("xabyabz", "ab"),
+        ("aaaaa", "aa"),
+    ];
+    for (hay, nee) in cases {
+        assert_eq!(hay.search_replace(nee, "<>"), hay.replace(nee, "<>"));
+        for n in 0..4 {
+            assert_eq!(
+                hay.search_replacen(nee, "<>", n),
+                hay.replacen(nee, "<>", n)
+            );
+        }
+    }
+}
+
+#[test]
+fn test_empty_needle_unlike_std() {
+    // the empty needle matches nothing, unlike `str::replace()`.
+    assert_eq!("abc".replace("", "-"), "-a-b-c-");
+    let r = "abc".search_replace("", "-");
+    assert!(matches!(r, Cow::Borrowed("abc")));
+    assert_eq!("abc".replacen("", "-", 2), "-a-bc");
+    let r = "abc".search_replacen("", "-", 2);
+    assert!(matches!(r, Cow::Borrowed("abc")));
+    let mut buf = String::new();
+    "abc".search_replace_into("", "-", &mut buf);
+    assert_eq!(buf, "abc");
+    let hay: &[u8] = b"abc";
+    let r = hay.search_replace_bytes("", b"-");
+    assert!(matches!(r, Cow::Borrowed(b"abc")));
+}
@@ -0,0 +1,156 @@
+#![cfg(feature = "alloc")]
+
+use naive_opt::{ByteSet, Search, SearchBytes, SearchIn};
+
+#[test]
+fn test_char_slice() {
+    let haystack = "key=value;next|last";
+    let set: &[char] = &[',', ';', '|'];
+    assert_eq!(haystack.search(set), haystack.find(set));
+    assert_eq!(haystack.rsearch(set), haystack.rfind(set));
+    assert_eq!(haystack.search(set), Some(9));
+    assert_eq!(haystack.rsearch(set), Some(14));
+    assert!(haystack.includes(set));
+    assert!(!"abc".includes(set));
+    let v: Vec<_> = haystack.search_indices(set).collect();
+    assert_eq!(v, [(9, ";"), (14, "|")]);
+    let v: Vec<_> = haystack.rsearch_indices(set).collect();
+    assert_eq!(v, [(14, "|"), (9, ";")]);
+    let empty: &[char] = &[];
+    assert_eq!(haystack.search(empty), None);
+    assert_eq!(haystack.search_indices(empty).next(), None);
+}
+
+#[test]
+fn test_char_array() {
+    let haystack = "key=value;next|last";
+    assert_eq!(haystack.search([',', ';', '|']), Some(9));
+    assert_eq!(haystack.rsearch([',', ';', '|']), Some(14));
+    let set_ref = &[',', ';', '|'];
+    assert_eq!(haystack.search(set_ref), Some(9));
+    assert_eq!([',', ';', '|'].search_in(haystack), Some(9));
+    let v: Vec<_> = haystack.search_split([';', '|', '=']).collect();
+    assert_eq!(v, ["key", "value", "next", "last"]);
+    assert_eq!(haystack.search_count(['e', 'x']), 4);
+    assert_eq!(haystack.search_count_overlapping(['e', 'x']), 4);
+}
+
+#[test]
+fn test_char_array_len() {
+    // the needle traits do not shadow the length of the char array.
+    use naive_opt::SearchInBytes;
+    let set = ['a', 'b', 'c', 'd'];
+    assert_eq!(set.len(), 4);
+    assert!(!set.is_empty());
+    let set_ref = &['a', 'b', 'c', 'd'];
+    assert_eq!(set_ref.len(), 4);
+    assert_eq!(SearchIn::max_match_len(&set), 1);
+    assert_eq!(SearchInBytes::max_match_len(&set), 1);
+    assert_eq!(SearchIn::max_match_len(&['a', 'λ']), 2);
+    assert_eq!(SearchIn::max_match_len(&[]), 0);
+}
+
+#[test]
+fn test_many_chars() {
+    // more than 4 bytes are scanned by the table.
+    let set = ['a', 'e', 'i', 'o', 'u', 'y'];
+    let haystack = "The quick brown fox";
+    assert_eq!(haystack.search(set), haystack.find(set));
+    assert_eq!(haystack.rsearch(set), haystack.rfind(set));
+    let v: Vec<_> = haystack.search_indices(set).collect();
+    let w: Vec<_> = haystack.match_indices(set).collect();
+    assert_eq!(v, w);
+    assert_eq!(haystack.search_count(set), haystack.matches(set).count());
+}
+
+#[test]
+fn test_non_ascii_chars() {
+    let haystack = "価格: 100円, 税: 10円";
+    let set = ['円', '税'];
+    assert_eq!(haystack.search(set), haystack.find(set));
+    assert_eq!(haystack.rsearch(set), haystack.rfind(set));
+    let v: Vec<_> = haystack.search_indices(set).collect();
+    let w: Vec<_> = haystack.match_indices(set).collect();
+    assert_eq!(v, w);
+    let v: Vec<_> = haystack.rsearch_indices(set).collect();
+    let w: Vec<_> = haystack.rmatch_indices(set).collect();
+    assert_eq!(v, w);
+    let v: Vec<_> = haystack.search_indices_overlapping(set).collect();
+    let w: Vec<_> = haystack.match_indices(set).collect();
+    assert_eq!(v, w);
+    let v: Vec<_> = haystack.search_split(['円', ',']).collect();
+    let w: Vec<_> = haystack.split(['円', ',']).collect();
+    assert_eq!(v, w);
+    assert_eq!(
+        haystack.search_replace(set, "*"),
+        haystack.replace(set, "*")
+    );
+}
+
+#[test]
+fn test_ignore_ascii_case() {
+    let haystack = "Key=Value";
+    assert_eq!(haystack.search_ignore_ascii_case(['v', 'x']), Some(4));
+    assert_eq!(haystack.rsearch_ignore_ascii_case(['k', 'V']), Some(4));
+    assert_eq!(haystack.search(['v', 'x']), None);
+    let v: Vec<_> = haystack
+        .search_indices_ignore_ascii_case(['k', 'v', 'é'])
+        .collect();
+    assert_eq!(v, [(0, "K"), (4, "V")]);
+    assert_eq!(
+        haystack.search_ignore_ascii_case(|c: char| c == 'v'),
+        Some(4)
+    );
+    assert_eq!(haystack.search(|c: char| c == 'v'), None);
+}
+
+#[test]
+fn test_predicate() {
+    let haystack = "abc 123 αβγ";
+    assert_eq!(haystack.search(char::is_numeric), Some(4));
+    assert_eq!(haystack.rsearch(char::is_numeric), Some(6));
+    assert_eq!(haystack.search(|c: char| !c.is_ascii()), Some(8));
+    assert_eq!(haystack.rsearch(|c: char| !c.is_ascii()), Some(12));
+    let v: Vec<_> = haystack.search_indices(|c: char| c == 'β').collect();
+    assert_eq!(v, [(10, "β")]);
+    let v: Vec<_> = haystack.search_split(char::is_whitespace).collect();
+    assert_eq!(v, ["abc", "123", "αβγ"]);
+    assert_eq!(haystack.search_count(|c: char| !c.is_ascii()), 3);
+    assert_eq!(haystack.search_count_overlapping(char::is_alphabetic), 6);
+}
+
+#[test]
+fn test_bytes() {
+    let haystack: &[u8] = b"key=value;\xffnext|last";
+    assert_eq!(haystack.search_bytes(ByteSet(b";|")), Some(9));
+    assert_eq!(haystack.rsearch_bytes(ByteSet(b";|")), Some(15));
+    assert_eq!(haystack.search_bytes(ByteSet(b"\xff")), Some(10));
+    assert_eq!(haystack.search_bytes(ByteSet(b"")), None);
+    assert_eq!(haystack.search_bytes(ByteSet(b"|;=\xffx")), Some(3));
+    assert_eq!(haystack.rsearch_bytes(ByteSet(b"|;=\xffx")), Some(15));
+    assert_eq!(
+        haystack.search_bytes_ignore_ascii_case(ByteSet(b"VX")),
+        Some(4)
+    );
+    let v: Vec<_> = haystack.search_indices_bytes(ByteSet(b";|")).collect();
+    assert_eq!(v, [(9, &b";"[..]), (15, &b"|"[..])]);
+    assert_eq!(haystack.search_bytes(|b: u8| b >= 0x80), Some(10));
+    assert_eq!(haystack.rsearch_bytes(|b: u8| b == b'e'), Some(12));
+    assert_eq!(haystack.search_count_bytes(|b: u8| b == b'e'), 3);
+    //
+    assert_eq!(haystack.search_bytes([';', '|']), Some(9));
+    let set_ref = &[';', '|'];
+    assert_eq!(haystack.rsearch_bytes(set_ref), Some(15));
+    let hay: &[u8] = b"a\xce\xb1\xff\xff\xce\xb1b";
+    let set: &[char] = &['α', 'b'];
+    let v: Vec<_> = hay.search_indices_bytes(set).collect();
+    assert_eq!(
+        v,
+        [(1, "α".as_bytes()), (5, "α".as_bytes()), (7, &b"b"[..])]
+    );
+    let v: Vec<_> = hay.rsearch_indices_bytes(set).collect();
+    assert_eq!(
+        v,
+        [(7, &b"b"[..]), (5, "α".as_bytes()), (1, "α".as_bytes())]
+    );
+}
@@ -0,0 +1,161 @@
+use naive_opt::{Search, SearchBytes};
+
+#[test]
+fn test_empty_needle() {
+    let v: Vec<_> = "abc".search_split("").collect();
+    assert_eq!(v, ["abc"]);
+    let v: Vec<_> = "abc".search_rsplit("").collect();
+    assert_eq!(v, ["abc"]);
+    let v: Vec<_> = "abc".search_split_terminator("").collect();
+    assert_eq!(v, ["abc"]);
+    let hay: &[u8] = b"abc";
+    let v: Vec<_> = hay.search_split_bytes("").collect();
+    assert_eq!(v, [b"abc"]);
+}
+
+#[test]
+fn test_empty_haystack() {
+    let v: Vec<_> = "".search_split("::").collect();
+    assert_eq!(v, [""]);
+    let v: Vec<_> = "".search_rsplit("::").collect();
+    assert_eq!(v, [""]);
+    let v: Vec<_> = "".search_splitn(2, "::").collect();
+    assert_eq!(v, [""]);
+    let v: Vec<&str> = "".search_split_terminator("::").collect();
+    assert!(v.is_empty());
+}
+
+#[test]
+fn test_split() {
+    let v: Vec<_> = "abc::def::ghi".search_split("::").collect();
+    assert_eq!(v, ["abc", "def", "ghi"]);
+    let v: Vec<_> = "::abc::::def::".search_split("::").collect();
+    assert_eq!(v, ["", "abc", "", "def", ""]);
+    let v: Vec<_> = "abc".search_split("::").collect();
+    assert_eq!(v, ["abc"]);
+    let v: Vec<_> = "αβγδβγε".search_split("βγ").collect();
+    assert_eq!(v, ["α", "δ", "ε"]);
+    let v: Vec<_> = "a,b,c".search_split(',').collect();
+    assert_eq!(v, ["a", "b", "c"]);
+}
+
+#[test]
+fn test_rsplit() {
+    let v: Vec<_> = "abc::def::ghi".search_rsplit("::").collect();
+    assert_eq!(v, ["ghi", "def", "abc"]);
+    let v: Vec<_> = "::abc::::def::".search_rsplit("::").collect();
+    assert_eq!(v, ["", "def", "", "abc", ""]);
+}
+
+#[test]
+fn test_splitn() {
+    let v: Vec<_> = "abc::def::ghi".search_splitn(0, "::").collect();
+    assert!(v.is_empty());
+    let v: Vec<_> = "abc::def::ghi".search_splitn(1, "::").collect();
+    assert_eq!(v, ["abc::def::ghi"]);
+    let v: Vec<_> = "abc::def::ghi".search_splitn(2, "::").collect();
+    assert_eq!(v, ["abc", "def::ghi"]);
+    let v: Vec<_> = "abc::def::ghi".search_splitn(5, "::").collect();
+    assert_eq!(v, ["abc", "def", "ghi"]);
+    let v: Vec<_> = "abc::".search_splitn(5, "::").collect();
+    assert_eq!(v, ["abc", ""]);
+}
+
+#[test]
+fn test_split_terminator() {
+    let v: Vec<_> = "abc::def::".search_split_terminator("::").collect();
+    assert_eq!(v, ["abc", "def"]);
+    let v: Vec<_> = "abc::def::::".search_split_terminator("::").collect();
+    assert_eq!(v, ["abc", "def", ""]);
+    let v: Vec<_> = "::abc".search_split_terminator("::").collect();
+    assert_eq!(v, ["", "abc"]);
+}
+
+#[test]
+fn test_split_ignore_ascii_case() {
+    let v: Vec<_> = "abcSEPdefsepghi"
+        .search_split_ignore_ascii_case("sep")
+        .collect();
+    assert_eq!(v, ["abc", "def", "ghi"]);
+    let v: Vec<_> = "abcSEPdefsepghi"
+        .search_rsplit_ignore_ascii_case("Sep")
+        .collect();
+    assert_eq!(v, ["ghi", "def", "abc"]);
+    let v: Vec<_> = "abcSEPdefsepghi"
+        .search_splitn_ignore_ascii_case(2, "sep")
+        .collect();
+    assert_eq!(v, ["abc", "defsepghi"]);
+    let v: Vec<_> = "abcSEPdefsep"
+        .search_split_terminator_ignore_ascii_case("sep")
+        .collect();
+    assert_eq!(v, ["abc", "def"]);
+}
+
+#[test]
+fn test_split_bytes() {
+    let hay: &[u8] = b"abc\r\ndef\r\nghi\r\n";
+    let v: Vec<_> = hay.search_split_bytes("\r\n").collect();
+    assert_eq!(v, [&b"abc"[..], b"def", b"ghi", b""]);
+    let v: Vec<_> = hay.search_rsplit_bytes("\r\n").collect();
+    assert_eq!(v, [&b""[..], b"ghi", b"def", b"abc"]);
+    let v: Vec<_> = hay.search_splitn_bytes(2, "\r\n").collect();
+    assert_eq!(v, [&b"abc"[..], b"def\r\nghi\r\n"]);
+    let v: Vec<_> = hay.search_split_terminator_bytes("\r\n").collect();
+    assert_eq!(v, [&b"abc"[..], b"def", b"ghi"]);
+    //
+    let hay: &[u8] = b"a\xffSEP\xfeb";
+    let v: Vec<_> = hay.search_split_bytes_ignore_ascii_case("sep").collect();
+    assert_eq!(v, [&b"a\xff"[..], b"\xfeb"]);
+    let v: Vec<_> = hay.search_rsplit_bytes_ignore_ascii_case("sep").collect();
+    assert_eq!(v, [&b"\xfeb"[..], b"a\xff"]);
+    let v: Vec<_> = hay
+        .search_splitn_bytes_ignore_ascii_case(1, "sep")
+        .collect();
+    assert_eq!(v, [hay]);
+    let v: Vec<_> = hay
+        .search_split_terminator_bytes_ignore_ascii_case("sep")
+        .collect();
+    assert_eq!(v, [&b"a\xff"[..], b"\xfeb"]);
+    //
+    let v: Vec<_> = "abc::def".search_split_bytes("::").collect();
+    assert_eq!(v, [&b"abc"[..], b"def"]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_split_string() {
+    let haystack = "abc::def::ghi".to_string();
+    let v: Vec<_> = haystack.search_split("::").collect();
+    assert_eq!(v, ["abc", "def", "ghi"]);
+    let v: Vec<_> = haystack.search_split_bytes("::").collect();
+    assert_eq!(v, [&b"abc"[..], b"def", b"ghi"]);
+}
+
+#[test]
+fn test_split_like_std() {
+    let cases = [
+        ("", "ab"),
+        ("ab", "ab"),
+        ("abab", "ab"),
+        ("xabyabz", "ab"),
+        ("abxab", "ab"),
+        ("aaaa", "aa"),
+        ("aaa", "aa"),
+    ];
+    for (hay, nee) in cases {
+        let v1: Vec<_> = hay.search_split(nee).collect();
+        let v2: Vec<_> = hay.split(nee).collect();
+        assert_eq!(v1, v2, "split: {:?} {:?}", hay, nee);
+        let v1: Vec<_> = hay.search_rsplit(nee).collect();
+        let v2: Vec<_> = hay.rsplit(nee).collect();
+        assert_eq!(v1, v2, "rsplit: {:?} {:?}", hay, nee);
+        let v1: Vec<_> = hay.search_split_terminator(nee).collect();
+        let v2: Vec<_> = hay.split_terminator(nee).collect();
+        assert_eq!(v1, v2, "split_terminator: {:?} {:?}", hay, nee);
+        for n in 0..4 {
+            let v1: Vec<_> = hay.search_splitn(n, nee).collect();
+            let v2: Vec<_> = hay.splitn(n, nee).collect();
+            assert_eq!(v1, v2, "splitn: {} {:?} {:?}", n, hay, nee);
+        }
+    }
+}
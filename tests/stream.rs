@@ -0,0 +1,82 @@
+#![cfg(feature = "std")]
+
+use naive_opt::{SearchBytes, StreamSearcher};
+use std::io::{BufReader, Read};
+
+// A reader that returns a few bytes at a time.
+struct SlowReader<'a> {
+    data: &'a [u8],
+    step: usize,
+}
+impl Read for SlowReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = self.step.min(buf.len()).min(self.data.len());
+        buf[..n].copy_from_slice(&self.data[..n]);
+        self.data = &self.data[n..];
+        Ok(n)
+    }
+}
+
+fn collect_all<R: Read>(searcher: StreamSearcher<R>) -> Vec<u64> {
+    searcher.collect::<std::io::Result<_>>().unwrap()
+}
+
+#[test]
+fn test_stream_empty() {
+    let reader: &[u8] = b"abc";
+    assert_eq!(collect_all(StreamSearcher::new(reader, "")), []);
+    let reader: &[u8] = b"";
+    assert_eq!(collect_all(StreamSearcher::new(reader, "abc")), []);
+}
+
+#[test]
+fn test_stream_boundary() {
+    let haystack = "0123abcd89abcdabc_abcdabcd_ab".repeat(7);
+    let hay_bytes = haystack.as_bytes();
+    for needle in ["abcd", "d", "cda", "_ab", "d_abcdab"] {
+        let expect: Vec<u64> = hay_bytes
+            .search_indices_bytes(needle)
+            .map(|(i, _)| i as u64)
+            .collect();
+        for step in [1, 2, 3, 5, 7, 64] {
+            for capacity in [1, 4, 9, 1024] {
+                let reader = SlowReader {
+                    data: hay_bytes,
+                    step,
+                };
+                let searcher = StreamSearcher::with_capacity(capacity, reader, needle, false);
+                assert_eq!(collect_all(searcher), expect, "{needle} {step} {capacity}");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_stream_ignore_ascii_case() {
+    let reader = BufReader::new(&b"abc345aBc901ABC"[..]);
+    let searcher = StreamSearcher::new_ignore_ascii_case(reader, "abc");
+    assert_eq!(collect_all(searcher), [0, 6, 12]);
+    //
+    let reader = SlowReader {
+        data: b"xxAxBxxaBxx",
+        step: 1,
+    };
+    let searcher = StreamSearcher::with_capacity(2, reader, "Ab", true);
+    assert_eq!(collect_all(searcher), [7]);
+}
+
+#[test]
+fn test_stream_includes() {
+    let reader: &[u8] = b"the needle in the haystack";
+    let mut searcher = StreamSearcher::new(reader, "needle");
+    assert!(searcher.includes().unwrap());
+    assert!(!searcher.includes().unwrap());
+    let reader = SlowReader {
+        data: b"abcdef",
+        step: 1,
+    };
+    let mut searcher = StreamSearcher::new(reader, "cd");
+    assert!(searcher.includes().unwrap());
+    // the rest of the stream is not read.
+    assert_eq!(searcher.into_inner().data, b"ef");
+}
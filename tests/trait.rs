@@ -90,7 +90,7 @@ mod trait_str_str {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod trait_str_string {
     use naive_opt::{Search, SearchBytes};
     #[test]
@@ -145,7 +145,7 @@ mod trait_str_string {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod trait_string_str {
     use naive_opt::{Search, SearchBytes};
     #[test]
@@ -200,7 +200,7 @@ mod trait_string_str {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod trait_string_string {
     use naive_opt::{Search, SearchBytes};
     #[test]
@@ -480,6 +480,7 @@ mod std_from_test_std {
         //assert_eq!("ประเทศไทย中华Việt Nam".rsearch(|c: char| c == '华'), Some(30));
     }
     //
+    #[cfg(feature = "alloc")]
     #[test]
     fn test_find_str() {
         // byte positions
@@ -0,0 +1,123 @@
+#![cfg(feature = "alloc")]
+
+//
+// The pathological inputs, that the verification work exceeds the budget,
+// and the search falls back to the Two-Way search.
+//
+use naive_opt::{Search, SearchBytes};
+
+// a small linear congruential generator, to be deterministic.
+struct Lcg(u64);
+impl Lcg {
+    fn next(&mut self) -> u64 {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        self.0 >> 33
+    }
+    fn string(&mut self, alphabet: &[u8], len: usize) -> String {
+        (0..len)
+            .map(|_| alphabet[(self.next() % alphabet.len() as u64) as usize] as char)
+            .collect()
+    }
+}
+
+#[test]
+fn test_worst_case() {
+    // the 1st byte and the last byte of the needle are both frequent.
+    let nee = "a".repeat(500) + "b" + &"a".repeat(500);
+    let hay = "a".repeat(100_000) + &nee + &"a".repeat(100_000);
+    assert_eq!(hay.search(&nee), Some(100_000));
+    assert_eq!(hay.rsearch(&nee), Some(100_000));
+    let nee = "a".repeat(500) + "c" + &"a".repeat(500);
+    assert_eq!(hay.search(&nee), None);
+    assert_eq!(hay.rsearch(&nee), None);
+    //
+    let nee = "A".repeat(500) + "b" + &"a".repeat(500);
+    let hay = "a".repeat(100_000) + &nee + &"A".repeat(100_000);
+    assert_eq!(hay.search_ignore_ascii_case(&nee), Some(100_000));
+    assert_eq!(hay.rsearch_ignore_ascii_case(&nee), Some(100_000));
+    assert_eq!(hay.search_count_ignore_ascii_case(&nee), 1);
+    assert_eq!(hay.search_count_overlapping_ignore_ascii_case(&nee), 1);
+}
+
+#[test]
+fn test_periodic_needle() {
+    let hay = "ab".repeat(50_000) + "c" + &"ab".repeat(50_000);
+    for nee in [
+        "ab".repeat(250) + "c" + &"ab".repeat(250),
+        "ab".repeat(500) + "a",
+        "ba".repeat(500),
+        "ab".repeat(250) + "ac" + &"ab".repeat(250),
+    ] {
+        assert_eq!(hay.search(&nee), hay.find(&nee), "{:?}", nee);
+        assert_eq!(hay.rsearch(&nee), hay.rfind(&nee), "{:?}", nee);
+    }
+}
+
+#[test]
+fn test_like_std() {
+    let mut rng = Lcg(1);
+    for _ in 0..200 {
+        let hay = rng.string(b"ab", 4_000);
+        let nee_len = 20 + (rng.next() % 40) as usize;
+        let nee = rng.string(b"ab", nee_len);
+        assert_eq!(hay.search(&nee), hay.find(&nee), "{:?}", nee);
+        assert_eq!(hay.rsearch(&nee), hay.rfind(&nee), "{:?}", nee);
+        let v1: Vec<_> = hay.search_indices(&nee).collect();
+        let v2: Vec<_> = hay.match_indices(&nee).collect();
+        assert_eq!(v1, v2, "{:?}", nee);
+        let v1: Vec<_> = hay.rsearch_indices(&nee).collect();
+        let v2: Vec<_> = hay.rmatch_indices(&nee).collect();
+        assert_eq!(v1, v2, "{:?}", nee);
+        assert_eq!(hay.search_count(&nee), hay.matches(&nee).count());
+        let count = hay
+            .as_bytes()
+            .windows(nee_len)
+            .filter(|w| *w == nee.as_bytes())
+            .count();
+        assert_eq!(hay.search_count_overlapping(&nee), count, "{:?}", nee);
+        // the needle taken from the haystack is always found.
+        let st = (rng.next() % (4_000 - nee_len as u64)) as usize;
+        let nee = &hay[st..st + nee_len];
+        assert_eq!(hay.search(nee), hay.find(nee), "{:?}", nee);
+        assert_eq!(hay.rsearch(nee), hay.rfind(nee), "{:?}", nee);
+    }
+}
+
+#[test]
+fn test_like_std_ignore_ascii_case() {
+    let mut rng = Lcg(2);
+    for _ in 0..200 {
+        let hay = rng.string(b"aAbB", 4_000);
+        let nee_len = 20 + (rng.next() % 40) as usize;
+        let st = (rng.next() % (4_000 - nee_len as u64)) as usize;
+        let nee = hay[st..st + nee_len].to_ascii_uppercase();
+        let hay_lc = hay.to_ascii_lowercase();
+        let nee_lc = nee.to_ascii_lowercase();
+        assert_eq!(
+            hay.search_ignore_ascii_case(&nee),
+            hay_lc.find(&nee_lc),
+            "{:?}",
+            nee
+        );
+        assert_eq!(
+            hay.rsearch_ignore_ascii_case(&nee),
+            hay_lc.rfind(&nee_lc),
+            "{:?}",
+            nee
+        );
+    }
+}
+
+#[test]
+fn test_bytes() {
+    let mut hay = vec![0u8; 100_000];
+    hay.extend_from_slice(b"\x01");
+    let mut nee = vec![0u8; 1_000];
+    nee.extend_from_slice(b"\x01");
+    let hay = &hay[..];
+    assert_eq!(hay.search_bytes(&nee[..]), Some(99_000));
+    assert_eq!(hay.rsearch_bytes(&nee[..]), Some(99_000));
+}
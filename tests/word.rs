@@ -0,0 +1,181 @@
+#![cfg(feature = "alloc")]
+
+use naive_opt::{Search, SearchBytes, WordChars};
+
+// the whole word matches by checking every index.
+fn naive_words(haystack: &str, needle: &str, word: WordChars) -> Vec<usize> {
+    let mut v = Vec::new();
+    let mut next_st = 0;
+    for (st, _) in haystack.char_indices() {
+        if st < next_st || !haystack[st..].starts_with(needle) {
+            continue;
+        }
+        let ed = st + needle.len();
+        let before = haystack[..st].chars().next_back();
+        let after = haystack[ed..].chars().next();
+        let is_word = |c: Option<char>| c.map(|c| word.is_word_char(c)).unwrap_or(false);
+        if !is_word(before) && !is_word(after) {
+            v.push(st);
+            next_st = ed;
+        }
+    }
+    v
+}
+
+#[test]
+fn test_empty() {
+    assert_eq!("".search_word("id", WordChars::Ascii), None);
+    assert_eq!("id".search_word("", WordChars::Ascii), None);
+    assert_eq!("id".rsearch_word("", WordChars::Ascii), None);
+    assert_eq!("id".search_indices_word("", WordChars::Ascii).next(), None);
+}
+
+#[test]
+fn test_search_word() {
+    let haystack = "width id_x (id) idx,id";
+    assert_eq!(haystack.search_word("id", WordChars::Ascii), Some(12));
+    assert_eq!(haystack.rsearch_word("id", WordChars::Ascii), Some(20));
+    assert_eq!(haystack.search_word("id_x", WordChars::Ascii), Some(6));
+    assert_eq!(haystack.search_word("(id)", WordChars::Ascii), Some(11));
+    assert_eq!(haystack.search_word("dth", WordChars::Ascii), None);
+    let v: Vec<_> = haystack
+        .search_indices_word("id", WordChars::Ascii)
+        .collect();
+    assert_eq!(v, [(12, "id"), (20, "id")]);
+}
+
+#[test]
+fn test_ignore_ascii_case() {
+    let haystack = "Width ID iD";
+    assert_eq!(
+        haystack.search_word_ignore_ascii_case("id", WordChars::Ascii),
+        Some(6)
+    );
+    assert_eq!(
+        haystack.rsearch_word_ignore_ascii_case("id", WordChars::Ascii),
+        Some(9)
+    );
+    let v: Vec<_> = haystack
+        .search_indices_word_ignore_ascii_case("id", WordChars::Ascii)
+        .collect();
+    assert_eq!(v, [(6, "ID"), (9, "iD")]);
+    assert_eq!(haystack.search_word("id", WordChars::Ascii), None);
+}
+
+#[test]
+fn test_unicode() {
+    let haystack = "élan lan λlan lan_ lan";
+    assert_eq!(haystack.search_word("lan", WordChars::Ascii), Some(2));
+    assert_eq!(haystack.search_word("lan", WordChars::Unicode), Some(6));
+    assert_eq!(haystack.rsearch_word("lan", WordChars::Unicode), Some(21));
+    let v: Vec<_> = haystack
+        .search_indices_word("lan", WordChars::Unicode)
+        .map(|(i, _)| i)
+        .collect();
+    assert_eq!(v, [6, 21]);
+    assert!(WordChars::Unicode.is_word_char('λ'));
+    assert!(!WordChars::Ascii.is_word_char('λ'));
+    assert_eq!(WordChars::default(), WordChars::Ascii);
+}
+
+#[test]
+fn test_bytes() {
+    let hay: &[u8] = b"width\xffid id_ \xce\xbbid";
+    assert_eq!(hay.search_word_bytes(b"id", WordChars::Ascii), Some(6));
+    assert_eq!(hay.rsearch_word_bytes(b"id", WordChars::Ascii), Some(15));
+    assert_eq!(hay.rsearch_word_bytes(b"id", WordChars::Unicode), Some(6));
+    assert_eq!(
+        hay.search_word_bytes_ignore_ascii_case(b"ID", WordChars::Ascii),
+        Some(6)
+    );
+    assert_eq!(
+        hay.rsearch_word_bytes_ignore_ascii_case(b"ID", WordChars::Ascii),
+        Some(15)
+    );
+    let v: Vec<_> = hay
+        .search_indices_word_bytes(b"id", WordChars::Ascii)
+        .map(|(i, _)| i)
+        .collect();
+    assert_eq!(v, [6, 15]);
+    let v: Vec<_> = hay
+        .search_indices_word_bytes_ignore_ascii_case(b"iD", WordChars::Unicode)
+        .map(|(i, _)| i)
+        .collect();
+    assert_eq!(v, [6]);
+    let v: Vec<_> = "id-id"
+        .search_indices_word_bytes(b"id", WordChars::Ascii)
+        .collect();
+    assert_eq!(v, [(0, &b"id"[..]), (3, &b"id"[..])]);
+}
+
+#[test]
+fn test_two_way_fallback() {
+    // the pivot is frequent and the verification fails, so that the search
+    // falls back to the Two-Way search, and then it rejects the first match.
+    let needle = format!("{}t{}", "e".repeat(10), "e".repeat(10));
+    let haystack = format!("{}{} {} ", "e".repeat(5000), needle, needle);
+    assert_eq!(
+        haystack.search_word(&needle, WordChars::Ascii),
+        Some(5000 + needle.len() + 1)
+    );
+    let upper = needle.to_ascii_uppercase();
+    assert_eq!(
+        haystack.search_word_ignore_ascii_case(&upper, WordChars::Ascii),
+        Some(5000 + needle.len() + 1)
+    );
+    let haystack = format!(" {} {}{}", needle, needle, "e".repeat(5000));
+    assert_eq!(haystack.rsearch_word(&needle, WordChars::Ascii), Some(1));
+    assert_eq!(
+        haystack.rsearch_word_ignore_ascii_case(&upper, WordChars::Ascii),
+        Some(1)
+    );
+}
+
+#[test]
+fn test_like_naive() {
+    let haystack = "id idid id_id (id) ïd id\nid-idλid idé id";
+    for word in [WordChars::Ascii, WordChars::Unicode] {
+        for needle in ["id", "d", "i", " id", "id ", "idid", "-", "λ"] {
+            let v: Vec<_> = haystack
+                .search_indices_word(needle, word)
+                .map(|(i, _)| i)
+                .collect();
+            let w = naive_words(haystack, needle, word);
+            assert_eq!(v, w, "{:?} {:?}", word, needle);
+            assert_eq!(
+                haystack.search_word(needle, word),
+                w.first().copied(),
+                "{:?} {:?}",
+                word,
+                needle
+            );
+            let v: Vec<_> = haystack
+                .search_indices_word_bytes(needle.as_bytes(), word)
+                .map(|(i, _)| i)
+                .collect();
+            assert_eq!(v, w, "{:?} {:?}", word, needle);
+        }
+    }
+}
+
+#[test]
+fn test_rejected_matches() {
+    // all the matches are rejected, the rejected verifications are charged
+    // to the budget and the Two-Way search does not restart on them.
+    let needle = "a".repeat(20_000);
+    let haystack = "a".repeat(1_000_000);
+    let start = std::time::Instant::now();
+    assert_eq!(haystack.search_word(&needle, WordChars::Ascii), None);
+    assert_eq!(haystack.rsearch_word(&needle, WordChars::Ascii), None);
+    let upper = needle.to_ascii_uppercase();
+    assert_eq!(
+        haystack.search_word_ignore_ascii_case(&upper, WordChars::Ascii),
+        None
+    );
+    assert_eq!(
+        haystack.rsearch_word_ignore_ascii_case(&upper, WordChars::Ascii),
+        None
+    );
+    assert_eq!(haystack.search_count_overlapping(&needle), 980_001);
+    assert!(start.elapsed() < std::time::Duration::from_secs(5));
+}
@@ -0,0 +1,51 @@
+use anyhow::Context;
+
+//
+// The byte stochastics of 0x80..=0xFF in `_BYTE_STOCHAS` of `src/lib.rs`.
+//
+// The bytes of the corpus files are counted, except the markup tags `<...>`
+// of the html files, and the count of each byte is scaled by the count of 'e',
+// that is 69 in the ascii stochastics.
+// The byte that may appear in UTF-8 is 1 at least, and the bytes that never
+// appear in UTF-8 (0xC0, 0xC1 and 0xF5..=0xFF) are 0.
+//
+// The output is the 128 weights, to be pasted after the ascii stochastics.
+//
+pub fn run(program: &str, args: &[&str]) -> anyhow::Result<()> {
+    if args.is_empty() {
+        eprintln!("[usage] {program} <corpus files>...");
+        std::process::exit(1);
+    }
+    let mut counts = [0u64; 256];
+    for path in args {
+        let bytes = std::fs::read(path).with_context(|| format!("can not read: {path}"))?;
+        let mut in_tag = false;
+        for byte in bytes {
+            match byte {
+                b'<' => in_tag = true,
+                b'>' if in_tag => in_tag = false,
+                _ if !in_tag => counts[byte as usize] += 1,
+                _ => (),
+            }
+        }
+    }
+    let e_count = counts[b'e' as usize];
+    anyhow::ensure!(e_count > 0, "the corpus has no 'e'");
+    let weights: Vec<String> = (0x80..=0xFFu8)
+        .map(|byte| weight(byte, counts[byte as usize], e_count).to_string())
+        .collect();
+    println!("{},", weights.join(", "));
+    Ok(())
+}
+
+// the weight of 'e' in the ascii stochastics.
+const E_WEIGHT: u128 = 69;
+
+fn weight(byte: u8, count: u64, e_count: u64) -> u8 {
+    if matches!(byte, 0xC0 | 0xC1 | 0xF5..=0xFF) {
+        return 0;
+    }
+    // round up
+    let scaled = (count as u128 * E_WEIGHT).div_ceil(e_count as u128);
+    scaled.clamp(1, 255) as u8
+}
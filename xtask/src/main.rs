@@ -2,6 +2,7 @@
 // ref)
 //   https://github.com/matklad/cargo-xtask
 //
+mod byte_stochastics;
 mod shape_benchmark_results;
 
 fn main() -> anyhow::Result<()> {
@@ -16,6 +17,7 @@ fn main() -> anyhow::Result<()> {
     #[rustfmt::skip]
     match cmd {
         "shape_benchmark_results" => shape_benchmark_results::run(&format!("{program} {cmd}"), &env_args)?,
+        "byte_stochastics" => byte_stochastics::run(&format!("{program} {cmd}"), &env_args)?,
         //
         "--help" | "-h" | "-H" | "help" => print_help_and_exit(program),
         "--version" | "-V" | "-v" => print_version_and_exit(program),
@@ -34,6 +36,6 @@ fn print_version_and_exit(_program: &str) {
 }
 
 fn print_help_and_exit(program: &str) {
-    println!("[usage] {program} {{ shape_benchmark_results }}");
+    println!("[usage] {program} {{ shape_benchmark_results | byte_stochastics }}");
     std::process::exit(0);
 }